@@ -1,55 +1,8 @@
+extern crate utilities;
 
-use std::collections::HashMap;
-
-fn normality(frequency_map: &HashMap<u8, u8>) -> f32 {
-  let mut sorted_vec: Vec<(&u8, &u8)> = frequency_map.iter().collect();
-  sorted_vec.sort_by(|lhs, rhs| rhs.1.cmp(lhs.1));
-  let sorted_vec = sorted_vec.iter().map(|(val, _)| val);
-
-  let expected = "etaoinshrdlcumwfgypbvkjxqz".as_bytes();
-
-  let mut sum_similar: f32 = 0.0;
-
-  for (i, actual) in sorted_vec.enumerate() {
-    if let Some(exp_position) = expected.iter().position(|&s| s == **actual) {
-      sum_similar += 1.0 - ((exp_position as f32 - (i as f32)).abs() / expected.len() as f32)
-    }
-  }
-  sum_similar / (expected.len() as f32)
-}
-
-fn frequency(input: &str) -> HashMap<u8, u8> {
-  let input = input.to_lowercase();
-  let mut map: HashMap<u8, u8> = HashMap::new();
-  for byte in input.as_bytes().iter() {
-    if *byte != 32 { 
-      // skip spaces
-      *map.entry(*byte).or_insert(0) += 1;
-    }
-  }
-  map
-}
-
-// 0123456789abcdef
-static HEX_CONVERSION_TABLE: &'static [u8] = 
-  &[48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 97, 98, 99, 100, 101, 102];
-
-fn decode_hex_char(value: &u8) -> u8 {
-  match HEX_CONVERSION_TABLE.iter().position(|&s| s == *value) {
-    Some(output) => output as u8,
-    None => 0
-  }
-}
-
-fn decode_hex_buffer(buffer: &[u8]) -> Vec<u8> {
-  buffer.chunks(2).map(|c| {
-    (decode_hex_char(&c[0]) << 4) | decode_hex_char(&c[1])
-  }).collect()
-}
-
-fn xor_buffer(buffer: &[u8], rhs: &u8) -> Vec<u8> {
-  buffer.iter().map(|c| c ^ rhs).collect()
-}
+use utilities::attack;
+use utilities::bytes::Bytes;
+use utilities::ngram;
 
 fn main() {
   // This buffer was originally utf8 bytes. These bytes were broken up into 4-bit chunks and
@@ -383,33 +336,15 @@ e03555453d1e31775f37331823164c341c09e310463438481019fb0b12fa
 4c071a57e9356ee415103c5c53e254063f2019340969e30a2e381d5b2555
 32042f46431d2c44607934ed180c1028136a5f2b26092e3b2c4e2930585a";
 
-  let mut max_normality: f32 = 0.0;
-  let mut max_xor_key = 0;
-  let mut max_utf8 = "".to_string();
-  let mut max_line = "".to_string();
-
-  for line in input.lines() {
-    let decoded_bin = decode_hex_buffer(line.as_bytes());
-    // We only need to go up to 256, since each hex char is at most 8 bits, aka 2^8 options.
-    for i in 1..256 {
-      // First, we need to xor the buffer with i
-      let xored = xor_buffer(&decoded_bin, &(i as u8));
-      // At this point we have a buffer of xored bytes. Let's decode it into utf8 string for analysis/printing.
-      let utf8 = match std::str::from_utf8(&xored) {
-        Ok(string) => string,
-        Err(_) => ""
-      };
-      let freq = frequency(&utf8);
-      let norm = normality(&freq);
-      
-      if norm > max_normality {
-        max_normality = norm;
-        max_xor_key = i;
-        max_utf8 = utf8.to_string();
-        max_line = line.to_string();
-      }
-    }
-  }
+  // Only one line was encrypted, so break every line and keep whichever
+  // plaintext the scorer likes best. The lines are all the same length, so
+  // their scores compare fairly.
+  let scorer = &ngram::ENGLISH_QUADGRAMS;
+  let guesses = input.lines().map(|line| {
+    let decoded_bin = Bytes::from_hex(line.as_bytes()).expect("challenge input should be valid hex");
+    attack::single_byte_xor(&decoded_bin, scorer)
+  });
+  let guess = attack::best(guesses, scorer).expect("challenge input has lines");
 
-  println!("Best guess is xor key {}: {}", max_xor_key, max_utf8);
+  println!("Best guess is xor key {}: {}", guess.key[0], String::from_utf8_lossy(&guess.plaintext));
 }
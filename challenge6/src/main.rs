@@ -1,31 +1,23 @@
 extern crate utilities;
 
+use utilities::attack;
 use utilities::b64;
+use utilities::bytes::Bytes;
+use utilities::score;
 use utilities::strings;
-use utilities::Encodable;
-use utilities::StringConstructible;
-use utilities::Xorable;
 
+// Rank key sizes by how far apart their blocks are on average: blocks XORed
+// with the right key length differ only as much as English does. Every block
+// counts, not just the first few, so the right size stands out clearly.
 fn guessed_key_length(buffer: &[u8]) -> Vec<usize> {
-  if buffer.len() < 80 {
+  let mut normalized_values: Vec<(usize, f32)> = (2..=40)
+    .filter_map(|keysize| strings::average_pairwise_hamming(buffer, keysize).map(|d| (keysize, d)))
+    .collect();
+  if normalized_values.is_empty() {
     panic!("buffer not long enough to guess.");
   }
-  let mut normalized_values: Vec<(usize, f32)> = Vec::new();
-  for keysize in 1..40 {
-    let first = &buffer[..keysize];
-    let second = &buffer[keysize..keysize*2];
-
-    let third = &buffer[keysize*2..keysize*3];
-    let fourth = &buffer[keysize*3..keysize*4];
-
-    normalized_values.push((keysize, (
-        (strings::hamming_distance(first, second) as f32) / (keysize as f32)
-        + (strings::hamming_distance(second, third) as f32) / (keysize as f32)
-        + (strings::hamming_distance(third, fourth) as f32) / (keysize as f32)
-      ) / 3.0));
-  }
   normalized_values.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
-  normalized_values[..5].iter().map(|v| v.0 ).collect()
+  normalized_values.iter().take(5).map(|v| v.0).collect()
 }
 
 fn main() {
@@ -93,76 +85,21 @@ DBBOFRwOBgA+T04pC0kDElMdC0VXBgYdFkU2CgtNEAEUVBwTWXhTVG5SGg8e
 AB0cRSo+AwgKRSANExlJCBQaBAsANU9TKxFJL0dMHRwRTAtPBRwQMAAATQcB
 FlRlIkw5QwA2GggaR0YBBg5ZTgIcAAw3SVIaAQcVEU8QTyEaYy0fDE4ITlhI
 Jk8DCkkcC3hFMQIEC0EbAVIqCFZBO1IdBgZUVA4QTgUWSR4QJwwRTWM=".to_string();
-  let stripped = input.replace("\n", "");
-  let buffer = b64::B64Buffer::from_encoded_utf8_buffer(&stripped.as_bytes());
-  let decoded = buffer.decode_to_bin();
+  let decoded = Bytes::from_b64_with_config(input.as_bytes(), &b64::MIME)
+    .expect("challenge input should be valid base64");
   let guessed = guessed_key_length(&decoded);
 
-  let mut best_guess = "".to_string();
-  let mut best_normalized = 0.0;
-  let mut best_key: Vec<u8> = Vec::new();
-
   println!("Guessing key length is {:?}", guessed);
 
-  for guess in guessed {
+  // Columns are short, so measure how far the byte counts stray from English
+  // rather than just their rank order.
+  let scorer = &score::ENGLISH_CHI_SQUARED;
+  let guesses = guessed.into_iter().map(|guess| {
     println!("Testing key length: {:?}", guess);
-    let chunkified = decoded.chunks(guess);
-
-    // The transpose step maps 
-    let mut transposed: Vec<Vec<u8>> = Vec::new();
-    for _ in 0..guess {
-      transposed.push(Vec::new());
-    }
-
-    for chunk in chunkified {
-      for (j, val) in chunk.iter().enumerate() {
-        transposed[j].push(*val);
-      }
-    }
-
-    let mut keys: Vec<u8> = Vec::new();
-
-    for transposed_bin in transposed {
-      let transposed_bin = b64::B64Buffer { raw_bytes: transposed_bin };
-
-      let mut max: f32 = 0.0;
-      let mut index = 0;
-
-      // We only need to go up to 256, since each hex char is at most 8 bits, aka 2^8 options.
-      for i in 1..255 {
-        // First, we need to xor the buffer with i
-        let xored = transposed_bin.xor_value(&i).decode_to_bin();
-
-        // At this point we have a buffer of xored bytes. Let's decode it into utf8 string for analysis/printing.
-        let utf8 = match std::str::from_utf8(&xored) {
-          Ok(string) => string,
-          Err(_) => ""
-        };
-
-        let freq = strings::frequency(&utf8);
-        let norm = strings::normality(&freq);
-        
-        if norm > max {
-          max = norm;
-          index = i;
-        }
-      }
-
-      keys.push(index);
-    }
-
-    let decrypted = buffer.xor_sequential(&keys);
-    let decrypted_bin = decrypted.decode_to_bin();
-    let string = std::str::from_utf8(&decrypted_bin).unwrap_or("");
-    let freq = strings::frequency(&string);
-    let norm = strings::normality(&freq);
-    if norm > best_normalized {
-      best_normalized = norm;
-      best_guess = string.to_string();
-      best_key = keys.to_vec();
-    }
-  }
+    attack::repeating_key_xor(&decoded, guess, scorer)
+  });
+  let best = attack::best(guesses, scorer).expect("some key length was guessed");
 
-  println!("My best guess at the decryption key is: {:?}", std::str::from_utf8(&best_key).unwrap_or(""));
-  println!("Which decodes the buffer to: {:?}", best_guess);
+  println!("My best guess at the decryption key is: {:?}", String::from_utf8_lossy(&best.key));
+  println!("Which decodes the buffer to: {:?}", String::from_utf8_lossy(&best.plaintext));
 }
@@ -0,0 +1,139 @@
+extern crate utilities;
+
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+use utilities::b64;
+use utilities::hex;
+
+// The codecs as they were before the lookup tables, kept here so there's
+// something to measure against. Run with `cargo run --release --bin codec_bench`.
+mod legacy {
+  static HEX_CONVERSION_TABLE: &[u8; 16] = b"0123456789abcdef";
+  static B64_CONVERSION_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+  fn decode_hex_byte(byte: &u8) -> u8 {
+    match HEX_CONVERSION_TABLE.iter().position(|&s| s == *byte) {
+      Some(output) => output as u8,
+      None => 0
+    }
+  }
+
+  pub fn decode_hex_buffer(buffer: &[u8]) -> Vec<u8> {
+    buffer.chunks(2).map(|c| {
+      (decode_hex_byte(&c[0]) << 4) | decode_hex_byte(&c[1])
+    }).collect()
+  }
+
+  pub fn encode_hex_buffer(buffer: &[u8]) -> Vec<u8> {
+    let mut output: Vec<u8> = Vec::new();
+    for c in buffer {
+      output.push(HEX_CONVERSION_TABLE[(c >> 4) as usize]);
+      output.push(HEX_CONVERSION_TABLE[(c & 0b1111) as usize]);
+    }
+    output
+  }
+
+  fn decode_b64_byte(byte: &u8) -> u8 {
+    match B64_CONVERSION_TABLE.iter().position(|&s| s == *byte) {
+      Some(output) => output as u8,
+      None => 0
+    }
+  }
+
+  pub fn encode_b64_buffer(buffer: &[u8]) -> Vec<u8> {
+    let mut output: Vec<u8> = Vec::new();
+    for c in buffer.chunks(3) {
+      match *c {
+        [x] => {
+          output.push(B64_CONVERSION_TABLE[(x >> 2) as usize]);
+          output.push(B64_CONVERSION_TABLE[((x & 0b11) << 4) as usize]);
+          output.push(61);
+          output.push(61);
+        },
+        [x, y] => {
+          output.push(B64_CONVERSION_TABLE[(x >> 2) as usize]);
+          output.push(B64_CONVERSION_TABLE[(((x & 0b11) << 4) | (y >> 4)) as usize]);
+          output.push(B64_CONVERSION_TABLE[((y & 0b1111) << 2) as usize]);
+          output.push(61);
+        },
+        [x, y, z] => {
+          output.push(B64_CONVERSION_TABLE[(x >> 2) as usize]);
+          output.push(B64_CONVERSION_TABLE[(((x & 0b11) << 4) | (y >> 4)) as usize]);
+          output.push(B64_CONVERSION_TABLE[(((y & 0b1111) << 2) | (z >> 6)) as usize]);
+          output.push(B64_CONVERSION_TABLE[(z & 0b111111) as usize]);
+        },
+        _ => {}
+      }
+    }
+    output
+  }
+
+  pub fn decode_b64_buffer(buffer: &[u8]) -> Vec<u8> {
+    let mut output: Vec<u8> = Vec::new();
+    for c in buffer.chunks(4) {
+      match *c {
+        [x, y, 61, 61] => {
+          output.push((decode_b64_byte(&x) << 2) | (decode_b64_byte(&y) >> 4));
+        },
+        [x, y, z, 61] => {
+          output.push((decode_b64_byte(&x) << 2) | (decode_b64_byte(&y) >> 4));
+          output.push(((decode_b64_byte(&y) & 0b1111) << 4) | (decode_b64_byte(&z) >> 2));
+        },
+        _ => {
+          output.push((decode_b64_byte(&c[0]) << 2) | (decode_b64_byte(&c[1]) >> 4));
+          output.push(((decode_b64_byte(&c[1]) & 0b1111) << 4) | (decode_b64_byte(&c[2]) >> 2));
+          output.push(((decode_b64_byte(&c[2]) & 0b11) << 6) | decode_b64_byte(&c[3]));
+        }
+      }
+    }
+    output
+  }
+}
+
+// Run `f` repeatedly for about half a second and return the throughput in
+// MB/s of `bytes` processed per call.
+fn throughput<F: FnMut()>(bytes: usize, mut f: F) -> f64 {
+  let start = Instant::now();
+  let mut iterations = 0;
+  while start.elapsed() < Duration::from_millis(500) {
+    f();
+    iterations += 1;
+  }
+  (bytes * iterations) as f64 / start.elapsed().as_secs_f64() / 1_000_000.0
+}
+
+fn report(name: &str, old: f64, new: f64) {
+  println!("{:<12} {:>10.1} MB/s {:>10.1} MB/s {:>8.1}x", name, old, new, new / old);
+}
+
+fn main() {
+  // Pseudo-random input so the branch predictor can't learn the data.
+  let mut state: u32 = 0x2545f491;
+  let data: Vec<u8> = (0..1 << 20).map(|_| {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    state as u8
+  }).collect();
+
+  let hex_encoded = legacy::encode_hex_buffer(&data);
+  let b64_encoded = legacy::encode_b64_buffer(&data);
+  let mut encoded = vec![0; hex::encoded_len(data.len()).max(b64::encoded_len(data.len(), &b64::STANDARD))];
+  let mut decoded = vec![0; data.len() + 3];
+
+  println!("{:<12} {:>15} {:>15} {:>9}", "codec", "legacy", "current", "speedup");
+
+  report("hex encode",
+    throughput(data.len(), || { black_box(legacy::encode_hex_buffer(black_box(&data))); }),
+    throughput(data.len(), || { black_box(hex::encode_into(black_box(&data), &mut encoded)); }));
+  report("hex decode",
+    throughput(data.len(), || { black_box(legacy::decode_hex_buffer(black_box(&hex_encoded))); }),
+    throughput(data.len(), || { black_box(hex::decode_into(black_box(&hex_encoded), &mut decoded).unwrap()); }));
+  report("b64 encode",
+    throughput(data.len(), || { black_box(legacy::encode_b64_buffer(black_box(&data))); }),
+    throughput(data.len(), || { black_box(b64::encode_into(black_box(&data), &mut encoded, &b64::STANDARD)); }));
+  report("b64 decode",
+    throughput(data.len(), || { black_box(legacy::decode_b64_buffer(black_box(&b64_encoded))); }),
+    throughput(data.len(), || { black_box(b64::decode_into(black_box(&b64_encoded), &mut decoded, &b64::STANDARD).unwrap()); }));
+}
@@ -0,0 +1,84 @@
+extern crate utilities;
+
+use std::env;
+use std::fs::File;
+use std::io;
+use std::io::Read;
+use std::io::Write;
+use std::process;
+use utilities::profile::Trainer;
+
+// Train a profile from a corpus and write it to stdout, e.g.
+//
+//   cargo run --bin train_profile -- --name access-log --order 3 access.log > access-log.profile
+//
+// The corpus is the named files one after another, or stdin if there are
+// none. Load the result with Profile::parse.
+const USAGE: &str = "usage: train_profile [--name NAME] [--order N] [--grams N] [FILE...]";
+
+fn usage() -> ! {
+  eprintln!("{}", USAGE);
+  process::exit(2);
+}
+
+fn number(value: Option<String>) -> usize {
+  value.and_then(|v| v.parse().ok()).unwrap_or_else(|| usage())
+}
+
+fn feed<R: Read>(trainer: &mut Trainer, mut reader: R) -> io::Result<()> {
+  let mut buffer = [0; 64 * 1024];
+  loop {
+    match reader.read(&mut buffer) {
+      Ok(0) => return Ok(()),
+      Ok(read) => trainer.feed(&buffer[..read]),
+      Err(ref e) if e.kind() == io::ErrorKind::Interrupted => {}
+      Err(e) => return Err(e),
+    }
+  }
+}
+
+fn main() {
+  let mut name = String::from("corpus");
+  let mut order = 4;
+  let mut max_grams = 4096;
+  let mut files = Vec::new();
+
+  let mut args = env::args().skip(1);
+  while let Some(arg) = args.next() {
+    match arg.as_str() {
+      "--name" => name = args.next().unwrap_or_else(|| usage()),
+      "--order" => order = number(args.next()),
+      "--grams" => max_grams = number(args.next()),
+      "-h" | "--help" => {
+        println!("{}", USAGE);
+        return;
+      }
+      _ if arg.starts_with("--") => usage(),
+      _ => files.push(arg),
+    }
+  }
+  if order == 0 || order > utilities::ngram::MAX_ORDER {
+    eprintln!("order must be between 1 and {}", utilities::ngram::MAX_ORDER);
+    process::exit(2);
+  }
+
+  let mut trainer = Trainer::new(order);
+  let result = if files.is_empty() {
+    feed(&mut trainer, io::stdin().lock())
+  } else {
+    files.iter().try_for_each(|path| {
+      File::open(path).and_then(|file| feed(&mut trainer, file))
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path, e)))
+    })
+  };
+  if let Err(e) = result {
+    eprintln!("train_profile: {}", e);
+    process::exit(1);
+  }
+
+  let profile = trainer.finish(&name, max_grams);
+  if let Err(e) = io::stdout().write_all(&profile.encode()) {
+    eprintln!("train_profile: {}", e);
+    process::exit(1);
+  }
+}
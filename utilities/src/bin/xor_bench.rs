@@ -0,0 +1,84 @@
+extern crate utilities;
+
+use std::hint::black_box;
+use std::time::Duration;
+use std::time::Instant;
+use utilities::xor;
+
+// The xor functions as they were before the lane kernels, kept here so there's
+// something to measure against. Run with `cargo run --release --bin xor_bench`.
+mod legacy {
+  pub fn xor_buffer(buffer: &[u8], rhs: &u8) -> Vec<u8> {
+    buffer.iter().map(|c| c ^ rhs).collect()
+  }
+
+  pub fn xor_sequential(buffer: &[u8], key: &[u8]) -> Vec<u8> {
+    assert!(!key.is_empty());
+    let mut output: Vec<u8> = Vec::new();
+
+    for i in 0..buffer.len() {
+      let b = buffer[i];
+      let k = key[i % key.len()];
+      output.push(b ^ k);
+    }
+
+    output
+  }
+}
+
+// Run `f` repeatedly for about half a second and return the throughput in
+// MB/s of `bytes` processed per call.
+fn throughput<F: FnMut()>(bytes: usize, mut f: F) -> f64 {
+  let start = Instant::now();
+  let mut iterations = 0;
+  while start.elapsed() < Duration::from_millis(500) {
+    f();
+    iterations += 1;
+  }
+  (bytes * iterations) as f64 / start.elapsed().as_secs_f64() / 1_000_000.0
+}
+
+fn report(name: &str, old: f64, new: f64) {
+  println!("{:<22} {:>10.1} MB/s {:>10.1} MB/s {:>8.1}x", name, old, new, new / old);
+}
+
+fn main() {
+  // Pseudo-random input so the branch predictor can't learn the data.
+  let mut state: u32 = 0x2545f491;
+  let data: Vec<u8> = (0..1 << 20).map(|_| {
+    state ^= state << 13;
+    state ^= state >> 17;
+    state ^= state << 5;
+    state as u8
+  }).collect();
+  let key = b"Terminator X: Bring the noise";
+  // A transposed column as the single-byte brute force sees it in challenge6.
+  let column = &data[..96];
+  let mut output = vec![0; data.len()];
+  let mut in_place = data.clone();
+  let mut column_output = vec![0; column.len()];
+
+  println!("{:<22} {:>15} {:>15} {:>9}", "operation", "legacy", "current", "speedup");
+
+  report("single byte",
+    throughput(data.len(), || { black_box(legacy::xor_buffer(black_box(&data), &0x5a)); }),
+    throughput(data.len(), || { xor::xor_into(black_box(&data), &[0x5a], &mut output); black_box(&output); }));
+  report("repeating key",
+    throughput(data.len(), || { black_box(legacy::xor_sequential(black_box(&data), key)); }),
+    throughput(data.len(), || { xor::xor_into(black_box(&data), key, &mut output); black_box(&output); }));
+  report("repeating key in place",
+    throughput(data.len(), || { black_box(legacy::xor_sequential(black_box(&data), key)); }),
+    throughput(data.len(), || { xor::xor_in_place(black_box(&mut in_place), key); }));
+  report("column, 256 keys",
+    throughput(column.len() * 256, || {
+      for k in 0..=255u8 {
+        black_box(legacy::xor_buffer(black_box(column), &k));
+      }
+    }),
+    throughput(column.len() * 256, || {
+      for k in 0..=255u8 {
+        xor::xor_into(black_box(column), &[k], &mut column_output);
+        black_box(&column_output);
+      }
+    }));
+}
@@ -1,3 +1,19 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+#[cfg(not(any(feature = "std", test)))]
+#[macro_use]
+extern crate alloc;
+
+// Without std, core and alloc stand in for it, so the modules below can use
+// std:: paths either way.
+#[cfg(not(any(feature = "std", test)))]
+mod std {
+  pub use core::*;
+  pub use alloc::{collections, fmt, slice, str, string, vec};
+}
+
+
+use std::vec::Vec;
 
 // The basic trait for conversion to/from string and binary.
 pub trait Encodable {
@@ -5,351 +21,6336 @@ pub trait Encodable {
   fn encode_to_utf8(&self) -> Vec<u8>;
 }
 
+// Construction from an encoded buffer. Panics if the buffer is not valid for
+// the encoding; use TryStringConstructible when the input is untrusted.
 pub trait StringConstructible {
   fn from_encoded_utf8_buffer(buffer: &[u8]) -> Self;
 }
 
+// Fallible construction from an encoded buffer.
+pub trait TryStringConstructible: Sized {
+  fn try_from_encoded_utf8_buffer(buffer: &[u8]) -> Result<Self, DecodeError>;
+}
+
+// A buffer of decoded bytes tied to one encoding. The codec buffers only say
+// how their encoding maps to and from bytes; Encodable, StringConstructible,
+// TryStringConstructible and Xorable are implemented once for all of them.
+pub trait Codec: Sized + AsRef<[u8]> + From<Vec<u8>> {
+  // Names the encoding in StringConstructible's panic message.
+  const NAME: &'static str;
+  fn encode(raw: &[u8]) -> Vec<u8>;
+  fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError>;
+}
+
+impl<T: Codec> Encodable for T {
+  fn decode_to_bin(&self) -> Vec<u8> {
+    self.as_ref().to_vec()
+  }
+  fn encode_to_utf8(&self) -> Vec<u8> {
+    T::encode(self.as_ref())
+  }
+}
+
+impl<T: Codec> StringConstructible for T {
+  fn from_encoded_utf8_buffer(buffer: &[u8]) -> Self {
+    T::try_from_encoded_utf8_buffer(buffer).unwrap_or_else(|e| panic!("invalid {} input: {}", T::NAME, e))
+  }
+}
+
+impl<T: Codec> TryStringConstructible for T {
+  fn try_from_encoded_utf8_buffer(buffer: &[u8]) -> Result<Self, DecodeError> {
+    T::decode(buffer).map(T::from)
+  }
+}
+
+// The ways an encoded buffer can fail to decode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeErrorKind {
+  // A byte that is not part of the encoding's alphabet.
+  InvalidSymbol,
+  // Hex digits come in pairs, so the input length must be even.
+  OddLength,
+  // Padding is missing, misplaced, or the wrong length.
+  InvalidPadding,
+  // More encoded data follows the padding that should have ended the input.
+  TrailingData,
+  // A group of symbols encodes a value too large for the bytes it stands
+  // for, or a hexdump offset lies beyond what the dump can reach.
+  Overflow,
+  // The decoded data doesn't match the checksum it carries.
+  InvalidChecksum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DecodeError {
+  pub kind: DecodeErrorKind,
+  // The byte that triggered the error, if there is one to blame. Missing
+  // padding at the end of the input has no offending byte.
+  pub byte: Option<u8>,
+  // Offset of the problem in the encoded input.
+  pub offset: usize,
+}
+
+impl DecodeError {
+  pub fn new(kind: DecodeErrorKind, byte: Option<u8>, offset: usize) -> DecodeError {
+    DecodeError { kind, byte, offset }
+  }
+}
+
+impl std::fmt::Display for DecodeError {
+  fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+    let description = match self.kind {
+      DecodeErrorKind::InvalidSymbol => "invalid symbol",
+      DecodeErrorKind::OddLength => "odd number of hex digits",
+      DecodeErrorKind::InvalidPadding => "invalid padding",
+      DecodeErrorKind::TrailingData => "trailing data after padding",
+      DecodeErrorKind::Overflow => "value out of range",
+      DecodeErrorKind::InvalidChecksum => "checksum mismatch",
+    };
+    match self.byte {
+      Some(byte) => write!(f, "{} {:?} (0x{:02x}) at offset {}", description, byte as char, byte, self.offset),
+      None => write!(f, "{} at offset {}", description, self.offset),
+    }
+  }
+}
+
+impl std::error::Error for DecodeError {}
+
+// Lets the streaming decoders report bad input through io::Read.
+#[cfg(feature = "std")]
+impl From<DecodeError> for std::io::Error {
+  fn from(error: DecodeError) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+  }
+}
+
+// Marks bytes that aren't part of an alphabet in a reverse_table.
+const INVALID_SYMBOL: u8 = 0xff;
+
+// Build a 256-entry lookup from input byte to symbol value, so decoders don't
+// have to search the alphabet for every byte. Each alphabet maps its i-th
+// symbol to i; passing several lets e.g. both cases of hex decode alike.
+const fn reverse_table(alphabets: &[&[u8]]) -> [u8; 256] {
+  let mut table = [INVALID_SYMBOL; 256];
+  let mut a = 0;
+  while a < alphabets.len() {
+    let mut i = 0;
+    while i < alphabets[a].len() {
+      table[alphabets[a][i] as usize] = i as u8;
+      i += 1;
+    }
+    a += 1;
+  }
+  table
+}
+
+// The float functions the scoring code needs. core doesn't have them, so
+// without std they're computed here instead.
+mod float {
+  #[cfg(not(feature = "std"))]
+  use std::f64::consts::{LN_2, SQRT_2};
+
+  #[cfg(feature = "std")]
+  pub fn ln(x: f32) -> f32 {
+    x.ln()
+  }
+
+  #[cfg(feature = "std")]
+  pub fn sqrt(x: f32) -> f32 {
+    x.sqrt()
+  }
+
+  #[cfg(feature = "std")]
+  pub fn exp(x: f32) -> f32 {
+    x.exp()
+  }
+
+  // Split x into 2^e * m with m in [sqrt(1/2), sqrt(2)), then sum the series
+  // for ln(m) = 2 * atanh((m - 1) / (m + 1)), which converges quickly there.
+  #[cfg(not(feature = "std"))]
+  pub fn ln(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+      return f32::NAN;
+    }
+    if x == 0.0 {
+      return f32::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+      return x;
+    }
+    let mut x = x as f64;
+    let mut exponent = 0;
+    if x < f64::MIN_POSITIVE {
+      x *= (1u64 << 54) as f64;
+      exponent -= 54;
+    }
+    let bits = x.to_bits();
+    exponent += ((bits >> 52) & 0x7ff) as i32 - 1023;
+    let mut mantissa = f64::from_bits((bits & ((1 << 52) - 1)) | (1023 << 52));
+    if mantissa > SQRT_2 {
+      mantissa /= 2.0;
+      exponent += 1;
+    }
+    let s = (mantissa - 1.0) / (mantissa + 1.0);
+    let s2 = s * s;
+    let mut term = s;
+    let mut sum = 0.0;
+    let mut k = 1.0;
+    while k < 16.0 {
+      sum += term / k;
+      term *= s2;
+      k += 2.0;
+    }
+    (exponent as f64 * LN_2 + 2.0 * sum) as f32
+  }
+
+  // Newton's method from a guess that halves the exponent.
+  #[cfg(not(feature = "std"))]
+  pub fn sqrt(x: f32) -> f32 {
+    if x.is_nan() || x < 0.0 {
+      return f32::NAN;
+    }
+    if x == 0.0 || x.is_infinite() {
+      return x;
+    }
+    let x = x as f64;
+    let mut guess = f64::from_bits((x.to_bits() >> 1) + (1023 << 51));
+    for _ in 0..6 {
+      guess = (guess + x / guess) / 2.0;
+    }
+    guess as f32
+  }
+
+  // Split x into k * ln(2) + r with |r| <= ln(2) / 2, sum the series for
+  // e^r, then scale by 2^k.
+  #[cfg(not(feature = "std"))]
+  pub fn exp(x: f32) -> f32 {
+    if x.is_nan() {
+      return x;
+    }
+    if x > 89.0 {
+      return f32::INFINITY;
+    }
+    if x < -104.0 {
+      return 0.0;
+    }
+    let x = x as f64;
+    let k = (x / LN_2 + if x < 0.0 { -0.5 } else { 0.5 }) as i64;
+    let r = x - k as f64 * LN_2;
+    let mut term = 1.0;
+    let mut sum = 1.0;
+    for i in 1..14 {
+      term *= r / i as f64;
+      sum += term;
+    }
+    (sum * f64::from_bits(((k + 1023) as u64) << 52)) as f32
+  }
+}
+
 pub trait Xorable {
   fn xor_value(&self, value: &u8) -> Self;
   fn xor_sequential(&self, sequence: &[u8]) -> Self;
+
+  // XOR with successive bytes of `keystream`. Any type that lends out its
+  // bytes and can be rebuilt from a Vec gets this for free, so
+  // implementations written before it was added keep compiling.
+  fn xor_keystream<K: xor::Keystream + ?Sized>(&self, keystream: &mut K) -> Self
+    where Self: Sized + AsRef<[u8]> + From<Vec<u8>> {
+    Self::from(xor::xor_keystream(self.as_ref(), keystream))
+  }
+}
+
+// A single byte and a repeating key are just the simplest keystreams.
+impl<T: Codec> Xorable for T {
+  fn xor_value(&self, value: &u8) -> Self {
+    self.xor_keystream(&mut { *value })
+  }
+
+  fn xor_sequential(&self, sequence: &[u8]) -> Self {
+    self.xor_keystream(&mut xor::RepeatingKeystream::new(sequence))
+  }
 }
 
 pub mod xor {
+  use std::convert::TryInto;
+  use std::error;
+  use std::fmt;
+  use std::vec::Vec;
+
   pub fn xor_buffer(buffer: &[u8], rhs: &u8) -> Vec<u8> {
-    buffer.iter().map(|c| c ^ rhs).collect()
+    xor_sequential(buffer, &[*rhs])
   }
 
   pub fn xor_sequential(buffer: &[u8], key: &[u8]) -> Vec<u8> {
-    assert!(key.len() > 0);
-    let mut output: Vec<u8> = Vec::new();
+    let mut output = vec![0; buffer.len()];
+    xor_into(buffer, key, &mut output);
+    output
+  }
 
-    for i in 0..buffer.len() {
-      let b = buffer[i];
-      let k = key[i % key.len()];
-      output.push(b ^ k);
+  // XOR `buffer` with `key` repeated across its whole length, in place.
+  // Panics if the key is empty.
+  pub fn xor_in_place(buffer: &mut [u8], key: &[u8]) {
+    if let Some(key) = key_lane(key) {
+      let mut lanes = buffer.chunks_exact_mut(16);
+      for b in &mut lanes {
+        let lane = lane(b) ^ key;
+        b.copy_from_slice(&lane.to_ne_bytes());
+      }
+      for (b, k) in lanes.into_remainder().iter_mut().zip(key.to_ne_bytes().iter()) {
+        *b ^= k;
+      }
+      return;
     }
+    let mut expanded = [0; EXPANDED_KEY_LEN];
+    let pattern = expand_key(key, buffer.len(), &mut expanded);
+    for chunk in buffer.chunks_mut(pattern.len()) {
+      let len = chunk.len();
+      xor_lanes_in_place(chunk, &pattern[..len]);
+    }
+  }
 
-    output
+  // Write `src` XOR-ed with `key` repeated across its whole length to the
+  // front of `dst`. Panics if the key is empty or dst is shorter than src.
+  pub fn xor_into(src: &[u8], key: &[u8], dst: &mut [u8]) {
+    assert!(dst.len() >= src.len(), "output too small to hold xor result");
+    let dst = &mut dst[..src.len()];
+    if let Some(key) = key_lane(key) {
+      let mut src_lanes = src.chunks_exact(16);
+      let mut dst_lanes = dst.chunks_exact_mut(16);
+      for (s, d) in (&mut src_lanes).zip(&mut dst_lanes) {
+        let lane = lane(s) ^ key;
+        d.copy_from_slice(&lane.to_ne_bytes());
+      }
+      for ((s, d), k) in src_lanes.remainder().iter().zip(dst_lanes.into_remainder()).zip(key.to_ne_bytes().iter()) {
+        *d = s ^ k;
+      }
+      return;
+    }
+    let mut expanded = [0; EXPANDED_KEY_LEN];
+    let pattern = expand_key(key, src.len(), &mut expanded);
+    for (s, d) in src.chunks(pattern.len()).zip(dst.chunks_mut(pattern.len())) {
+      xor_lanes(s, &pattern[..s.len()], d);
+    }
   }
-}
 
-pub mod hex {
-  use Encodable;
-  use StringConstructible;
-  use Xorable;
-  use xor;
+  // Short keys are repeated out to at least this many bytes, so the work is
+  // done in long runs of 16-byte lanes instead of a modulo per byte.
+  const MIN_PATTERN_LEN: usize = 256;
+  const EXPANDED_KEY_LEN: usize = 2 * MIN_PATTERN_LEN;
 
-  #[derive(Debug)]
-  pub struct HexBuffer {
-    // Internal representation is a decoded binary buffer.
-    pub raw_bytes: Vec<u8>
+  // A key whose length divides 16 repeats identically in every lane, so it
+  // can be expanded once into a single u128.
+  fn key_lane(key: &[u8]) -> Option<u128> {
+    assert!(!key.is_empty());
+    if !16usize.is_multiple_of(key.len()) {
+      return None;
+    }
+    let mut lane = key.iter().rev().fold(0u128, |lane, &k| lane << 8 | k as u128);
+    let mut width = 8 * key.len();
+    while width < 128 {
+      lane |= lane << width;
+      width *= 2;
+    }
+    Some(u128::from_ne_bytes(lane.to_le_bytes()))
+  }
+
+  // Return the key repeated a whole number of times out to MIN_PATTERN_LEN
+  // bytes, or just far enough to cover `len` bytes of input. Because the
+  // pattern is a multiple of the key length, every chunk of that size starts
+  // at the beginning of the key.
+  fn expand_key<'a>(key: &'a [u8], len: usize, expanded: &'a mut [u8; EXPANDED_KEY_LEN]) -> &'a [u8] {
+    assert!(!key.is_empty());
+    if key.len() >= MIN_PATTERN_LEN || len <= key.len() {
+      return key;
+    }
+    let target = MIN_PATTERN_LEN.min(len).div_ceil(key.len()) * key.len();
+    expanded[..key.len()].copy_from_slice(key);
+    let mut filled = key.len();
+    while filled < target {
+      let copy = filled.min(target - filled);
+      expanded.copy_within(..copy, filled);
+      filled += copy;
+    }
+    &expanded[..target]
+  }
+
+  // XOR equal-length `a` and `b` into `out` a u128 at a time.
+  fn xor_lanes(a: &[u8], b: &[u8], out: &mut [u8]) {
+    let mut a_lanes = a.chunks_exact(16);
+    let mut b_lanes = b.chunks_exact(16);
+    let mut out_lanes = out.chunks_exact_mut(16);
+    for ((a, b), out) in (&mut a_lanes).zip(&mut b_lanes).zip(&mut out_lanes) {
+      let lane = lane(a) ^ lane(b);
+      out.copy_from_slice(&lane.to_ne_bytes());
+    }
+    for ((a, b), out) in a_lanes.remainder().iter().zip(b_lanes.remainder()).zip(out_lanes.into_remainder()) {
+      *out = a ^ b;
+    }
   }
 
-  impl Encodable for HexBuffer {
-    fn decode_to_bin(&self) -> Vec<u8> {
-      self.raw_bytes.to_vec()
+  fn xor_lanes_in_place(buffer: &mut [u8], key: &[u8]) {
+    let mut buffer_lanes = buffer.chunks_exact_mut(16);
+    let mut key_lanes = key.chunks_exact(16);
+    for (b, k) in (&mut buffer_lanes).zip(&mut key_lanes) {
+      let lane = lane(b) ^ lane(k);
+      b.copy_from_slice(&lane.to_ne_bytes());
     }
-    fn encode_to_utf8(&self) -> Vec<u8> {
-      encode_hex_buffer(&self.raw_bytes)
+    for (b, k) in buffer_lanes.into_remainder().iter_mut().zip(key_lanes.remainder()) {
+      *b ^= k;
     }
   }
 
-  impl StringConstructible for HexBuffer {
-    fn from_encoded_utf8_buffer(buffer: &[u8]) -> Self {
-      HexBuffer { raw_bytes: decode_hex_buffer(buffer) }
+  fn lane(bytes: &[u8]) -> u128 {
+    u128::from_ne_bytes(bytes.try_into().expect("lanes are 16 bytes"))
+  }
+
+  // An endless run of key bytes that can be repositioned. Positions count
+  // bytes from the start of the stream, so seeking to 0 rewinds it and a
+  // stream cipher can pick up decryption in the middle of a message.
+  pub trait Keystream {
+    fn next_byte(&mut self) -> u8;
+    fn seek(&mut self, position: u64);
+
+    // Fill `buffer` with the next buffer.len() bytes of the stream. Streams
+    // that produce whole blocks at a time should override this.
+    fn fill(&mut self, buffer: &mut [u8]) {
+      for byte in buffer.iter_mut() {
+        *byte = self.next_byte();
+      }
     }
   }
 
-  impl Xorable for HexBuffer {
-    fn xor_value(&self, value: &u8) -> Self {
-      let xored = xor::xor_buffer(&self.raw_bytes, value);
-      HexBuffer { raw_bytes: xored }
+  impl<K: Keystream + ?Sized> Keystream for &mut K {
+    fn next_byte(&mut self) -> u8 {
+      (**self).next_byte()
+    }
+
+    fn seek(&mut self, position: u64) {
+      (**self).seek(position)
     }
 
-    fn xor_sequential(&self, sequence: &[u8]) -> Self {
-      let xored = xor::xor_sequential(&self.raw_bytes, sequence);
-      HexBuffer { raw_bytes: xored }
+    fn fill(&mut self, buffer: &mut [u8]) {
+      (**self).fill(buffer)
     }
   }
 
-  // 0123456789abcdef
-  static HEX_CONVERSION_TABLE: &'static [u8; 16] = b"0123456789abcdef";
+  // A single byte key is that byte forever.
+  impl Keystream for u8 {
+    fn next_byte(&mut self) -> u8 {
+      *self
+    }
+
+    fn seek(&mut self, _position: u64) {}
 
-  // Take a hex-encoded utf8 byte and convert to a binary byte.
-  fn decode_hex_byte(byte: &u8) -> u8 {
-    match HEX_CONVERSION_TABLE.iter().position(|&s| s == *byte) {
-      Some(output) => output as u8,
-      // TODO: consider panicking or returning an error instead of silently failing.
-      None => 0
+    fn fill(&mut self, buffer: &mut [u8]) {
+      buffer.fill(*self);
     }
   }
 
-  // Take a binary byte and convert to a hex-encoded utf8 byte.
-  fn encode_hex_byte(byte: &u8) -> u8 {
-    HEX_CONVERSION_TABLE[*byte as usize]
+  // A RepeatingKey along with how far through the key the stream has got.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct RepeatingKeystream<'a> {
+    key: &'a [u8],
+    offset: usize,
   }
 
-  // Take a hex-encoded utf8 buffer and convert to a binary buffer.
-  fn decode_hex_buffer(buffer: &[u8]) -> Vec<u8> {
-    // Each hex byte holds 4 bits of information for the binary buffer.
-    // Thus we just have to combine each pair of 2 values in the original
-    // buffer, decode their values, and we've got a binary buffer.
-    buffer.chunks(2).map(|c| {
-      (decode_hex_byte(&c[0]) << 4) | decode_hex_byte(&c[1])
-    }).collect()
+  impl<'a> RepeatingKeystream<'a> {
+    // Panics if the key is empty.
+    pub fn new(key: &'a [u8]) -> RepeatingKeystream<'a> {
+      assert!(!key.is_empty());
+      RepeatingKeystream { key, offset: 0 }
+    }
   }
 
-  // Take a binary buffer and encode to utf8 hex bytes.
-  fn encode_hex_buffer(buffer: &[u8]) -> Vec<u8> {
-    // Each binary byte contains 8 bits of information. We need to map
-    // this to twice that number of hex bytes, which each contain 4 bits.
-    let mut output: Vec<u8> = Vec::new();
+  impl<'a> From<RepeatingKey<'a>> for RepeatingKeystream<'a> {
+    fn from(key: RepeatingKey<'a>) -> Self {
+      RepeatingKeystream::new(key.0)
+    }
+  }
+
+  impl<'a> Keystream for RepeatingKeystream<'a> {
+    fn next_byte(&mut self) -> u8 {
+      let byte = self.key[self.offset];
+      self.offset += 1;
+      if self.offset == self.key.len() {
+        self.offset = 0;
+      }
+      byte
+    }
 
-    for c in buffer {
-      output.push(encode_hex_byte(&(c >> 4)));
-      output.push(encode_hex_byte(&(c & 0b1111)));
+    fn seek(&mut self, position: u64) {
+      self.offset = (position % self.key.len() as u64) as usize;
     }
 
-    output
+    fn fill(&mut self, buffer: &mut [u8]) {
+      let mut filled = 0;
+      while filled < buffer.len() {
+        let run = (self.key.len() - self.offset).min(buffer.len() - filled);
+        buffer[filled..filled + run].copy_from_slice(&self.key[self.offset..self.offset + run]);
+        filled += run;
+        self.offset = (self.offset + run) % self.key.len();
+      }
+    }
   }
-}
 
-pub mod b64 {
-  use Encodable;
-  use StringConstructible;
-  use Xorable;
-  use xor;
+  // A keystream computing each byte from its position, as a counter mode
+  // does when byte n comes from encrypting block n / 16. Generators that can
+  // only run forwards, like most PRNGs, should implement Keystream directly
+  // and re-seed and skip ahead in seek.
+  #[derive(Debug, Clone, Copy)]
+  pub struct FromFn<F> {
+    generator: F,
+    position: u64,
+  }
 
-  #[derive(Debug)]
-  pub struct B64Buffer {
-    // Internal representation is a decoded binary buffer.
-    pub raw_bytes: Vec<u8>
+  pub fn from_fn<F: FnMut(u64) -> u8>(generator: F) -> FromFn<F> {
+    FromFn { generator, position: 0 }
   }
 
-  impl Encodable for B64Buffer {
-    fn decode_to_bin(&self) -> Vec<u8> {
-      self.raw_bytes.to_vec()
+  impl<F: FnMut(u64) -> u8> Keystream for FromFn<F> {
+    fn next_byte(&mut self) -> u8 {
+      let byte = (self.generator)(self.position);
+      self.position += 1;
+      byte
     }
-    fn encode_to_utf8(&self) -> Vec<u8> {
-      encode_b64_buffer(&self.raw_bytes)
+
+    fn seek(&mut self, position: u64) {
+      self.position = position;
     }
   }
 
-  impl StringConstructible for B64Buffer {
-    fn from_encoded_utf8_buffer(buffer: &[u8]) -> Self {
-      B64Buffer { raw_bytes: decode_b64_buffer(buffer) }
+  // XOR the next buffer.len() bytes of `keystream` into `buffer`, leaving
+  // the stream positioned just after them.
+  pub fn xor_keystream_in_place<K: Keystream + ?Sized>(buffer: &mut [u8], keystream: &mut K) {
+    let mut block = [0; MIN_PATTERN_LEN];
+    for chunk in buffer.chunks_mut(MIN_PATTERN_LEN) {
+      let key = &mut block[..chunk.len()];
+      keystream.fill(key);
+      xor_lanes_in_place(chunk, key);
     }
   }
 
-  impl Xorable for B64Buffer {
-    fn xor_value(&self, value: &u8) -> Self {
-      let xored = xor::xor_buffer(&self.raw_bytes, value);
-      B64Buffer { raw_bytes: xored }
-    }
+  pub fn xor_keystream<K: Keystream + ?Sized>(buffer: &[u8], keystream: &mut K) -> Vec<u8> {
+    let mut output = buffer.to_vec();
+    xor_keystream_in_place(&mut output, keystream);
+    output
+  }
 
-    fn xor_sequential(&self, sequence: &[u8]) -> Self {
-      let xored = xor::xor_sequential(&self.raw_bytes, sequence);
-      B64Buffer { raw_bytes: xored }
-    }
+  // What to do when XOR-ing two buffers of different lengths.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum LengthPolicy {
+    // Refuse with a LengthMismatch.
+    Error,
+    // Stop at the end of the shorter buffer, like zip does. This is what the
+    // ^ operator on Bytes does.
+    Truncate,
+    // Repeat rhs as often as needed to cover lhs, as with a repeating key.
+    Cycle,
   }
 
-  // ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/
-  static B64_CONVERSION_TABLE: &'static [u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct LengthMismatch {
+    pub lhs: usize,
+    pub rhs: usize,
+  }
 
-  // Take a utf8 base64 representation and convert to its binary value.
-  fn decode_b64_byte(byte: &u8) -> u8 {
-    match B64_CONVERSION_TABLE.iter().position(|&s| s == *byte) {
-      Some(output) => output as u8,
-      // TODO: consider panicking or returning an error instead of silently failing.
-      None => 0
+  impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "cannot xor {} bytes with {} bytes", self.lhs, self.rhs)
     }
   }
 
-  // Take a binary value and encode it to a base-64 mapped utf8 byte.
-  fn encode_b64_byte(byte: &u8) -> u8 {
-    B64_CONVERSION_TABLE[*byte as usize]
+  impl error::Error for LengthMismatch {}
+
+  // A key to be repeated across the whole of the buffer it's XOR-ed with,
+  // so `buffer ^ RepeatingKey(b"ICE")` reads like the cipher it implements.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct RepeatingKey<'a>(pub &'a [u8]);
+
+  // XOR two buffers, resolving any difference in length with `policy`.
+  // Cycling an empty rhs is a mismatch whatever lhs is, since there's
+  // nothing to repeat.
+  pub fn xor_buffers(lhs: &[u8], rhs: &[u8], policy: LengthPolicy) -> Result<Vec<u8>, LengthMismatch> {
+    let mismatch = LengthMismatch { lhs: lhs.len(), rhs: rhs.len() };
+    match policy {
+      LengthPolicy::Error if lhs.len() != rhs.len() => Err(mismatch),
+      LengthPolicy::Error | LengthPolicy::Truncate => Ok(lhs.iter().zip(rhs.iter()).map(|(l, r)| l ^ r).collect()),
+      LengthPolicy::Cycle if rhs.is_empty() => Err(mismatch),
+      LengthPolicy::Cycle => Ok(xor_sequential(lhs, rhs)),
+    }
   }
+}
 
-  // Take a binary buffer and translate it to its Base-64 encoded binary representation.
-  fn encode_b64_buffer(buffer: &[u8]) -> Vec<u8> {
-    let mut output: Vec<u8> = Vec::new();
+pub mod radix {
+  use std::vec::Vec;
+  use DecodeError;
+  use DecodeErrorKind;
+  use INVALID_SYMBOL;
 
-    for (_, c) in buffer.chunks(3).enumerate() {
-      // Each binary byte is formed of 8 bits, which needs to be translated into
-      // groupings of 6 bits which can be represented in Base64. Thus, we chunk
-      // our input bytes into groups of 3 8-bit bytes. From these 24 bits we can
-      // create 4 6-bit Base-64 values.
+  // A base with a power-of-two number of symbols, each standing for a fixed
+  // number of bits. Bytes are packed into symbols most significant bit first,
+  // and the last symbol is filled out with zero bits. With padding, output
+  // is padded to a whole group: the fewest symbols that hold a whole number
+  // of bytes, e.g. 4 for base64 and 8 for base32 and octal.
+  //
+  // A letter whose other case isn't in the alphabet decodes the same as the
+  // symbol, so hex and base32 decode in either case while base64 stays
+  // case-sensitive. Like the other decoders, non-zero bits left over in the
+  // last symbol are ignored.
+  //
+  // A leftover hex digit is an OddLength error, as from hex::decode_into;
+  // any other symbol count that can't end the input is InvalidPadding.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct Radix<'a> {
+    alphabet: &'a [u8],
+    padding: Option<u8>,
+    bits: u32,
+    decode_table: [u8; 256],
+  }
 
-      match c {
-        [x] => {
-          output.push(encode_b64_byte(&(x >> 2)));
-          output.push(encode_b64_byte(&((x & 0b11) << 4)));
-          output.push(61); // =
-          output.push(61); // =
-        },
-        [x, y] => {
-          output.push(encode_b64_byte(&(x >> 2)));
-          output.push(encode_b64_byte(&(((x & 0b11) << 4) | (y >> 4))));
-          output.push(encode_b64_byte(&((y & 0b1111) << 2)));
-          output.push(61); // =
-        },
-        [x, y, z] => {
-          output.push(encode_b64_byte(&(x >> 2)));
-          output.push(encode_b64_byte(&(((x & 0b11) << 4) | (y >> 4))));
-          output.push(encode_b64_byte(&(((y & 0b1111) << 2) | (z >> 6))));
-          output.push(encode_b64_byte(&(z & 0b111111)));
-        },
-        _ => {}
+  impl<'a> Radix<'a> {
+    // Panics unless the alphabet has 2, 4, 8, 16, 32 or 64 distinct symbols
+    // and the padding byte isn't one of them.
+    pub const fn new(alphabet: &'a [u8], padding: Option<u8>) -> Radix<'a> {
+      assert!(alphabet.len().is_power_of_two() && alphabet.len() >= 2 && alphabet.len() <= 64,
+        "a radix alphabet needs 2, 4, 8, 16, 32 or 64 symbols");
+      let mut decode_table = [INVALID_SYMBOL; 256];
+      let mut i = 0;
+      while i < alphabet.len() {
+        assert!(decode_table[alphabet[i] as usize] == INVALID_SYMBOL, "radix alphabet symbols must be distinct");
+        decode_table[alphabet[i] as usize] = i as u8;
+        i += 1;
       }
+      if let Some(padding) = padding {
+        assert!(decode_table[padding as usize] == INVALID_SYMBOL, "radix padding can't be an alphabet symbol");
+      }
+      let mut i = 0;
+      while i < alphabet.len() {
+        let other = if alphabet[i].is_ascii_lowercase() {
+          alphabet[i].to_ascii_uppercase()
+        } else {
+          alphabet[i].to_ascii_lowercase()
+        };
+        let is_padding = match padding {
+          Some(padding) => padding == other,
+          None => false,
+        };
+        if decode_table[other as usize] == INVALID_SYMBOL && !is_padding {
+          decode_table[other as usize] = i as u8;
+        }
+        i += 1;
+      }
+      Radix { alphabet, padding, bits: alphabet.len().trailing_zeros(), decode_table }
     }
-    output
-  }
 
-  // Take a Base-64 utf8 encoded buffer and decode it to its binary representation.
-  fn decode_b64_buffer(buffer: &[u8]) -> Vec<u8> {
-    let mut output: Vec<u8> = Vec::new();
-    for (_, c) in buffer.chunks(4).enumerate() {
-      // Each b64 value encompasses 6 bits. Thus a group of 4 b64-encoded chars
-      // maps to three decoded values.
+    // Also decode `alias`, in either case, as `symbol`. Panics if either
+    // case of the alias already means something.
+    pub const fn with_alias(mut self, alias: u8, symbol: u8) -> Radix<'a> {
+      let value = self.decode_table[symbol as usize];
+      assert!(value != INVALID_SYMBOL && value != SKIPPED, "a radix alias must stand for a symbol");
+      let lower = alias.to_ascii_lowercase() as usize;
+      let upper = alias.to_ascii_uppercase() as usize;
+      assert!(self.decode_table[lower] == INVALID_SYMBOL && self.decode_table[upper] == INVALID_SYMBOL,
+        "a radix alias can't already be a symbol");
+      assert!(!self.is_padding(alias.to_ascii_lowercase()) && !self.is_padding(alias.to_ascii_uppercase()),
+        "a radix alias can't be the padding");
+      self.decode_table[lower] = value;
+      self.decode_table[upper] = value;
+      self
+    }
 
-      match c {
-        [x, y, 61, 61] => {
-          output.push((decode_b64_byte(&x) << 2) | (decode_b64_byte(&y) >> 4));
-        },
-        [x, y, z, 61] => {
-          output.push((decode_b64_byte(&x) << 2) | (decode_b64_byte(&y) >> 4));
-          output.push(((decode_b64_byte(&y) & 0b1111) << 4) | (decode_b64_byte(&z) >> 2));
-        }
-        _ => {
-          output.push((decode_b64_byte(&c[0]) << 2) | (decode_b64_byte(&c[1]) >> 4));
-          output.push(((decode_b64_byte(&c[1]) & 0b1111) << 4) | (decode_b64_byte(&c[2]) >> 2));
-          output.push(((decode_b64_byte(&c[2]) & 0b11) << 6) | decode_b64_byte(&c[3]));
-        }
+    // Skip `byte` wherever it appears before any padding when decoding, as
+    // with the hyphens people add to long codes. Panics if it already means
+    // something.
+    pub const fn ignoring(mut self, byte: u8) -> Radix<'a> {
+      assert!(self.decode_table[byte as usize] == INVALID_SYMBOL && !self.is_padding(byte),
+        "a radix can only ignore bytes that aren't symbols");
+      self.decode_table[byte as usize] = SKIPPED;
+      self
+    }
+
+    // The same radix padded with `padding`. Panics if it already means
+    // something.
+    pub const fn with_padding(mut self, padding: u8) -> Radix<'a> {
+      assert!(self.decode_table[padding as usize] == INVALID_SYMBOL, "radix padding can't be an alphabet symbol");
+      self.padding = Some(padding);
+      self
+    }
+
+    pub const fn without_padding(mut self) -> Radix<'a> {
+      self.padding = None;
+      self
+    }
+
+    const fn is_padding(&self, byte: u8) -> bool {
+      match self.padding {
+        Some(padding) => padding == byte,
+        None => false,
       }
     }
-    output
-  }
-}
 
-pub mod strings {
-  use std::collections::HashMap;
+    pub fn alphabet(&self) -> &'a [u8] {
+      self.alphabet
+    }
 
-  pub fn normality(frequency_map: &HashMap<u8, usize>) -> f32 {
-    let mut sorted_vec: Vec<(&u8, &usize)> = frequency_map.iter().collect();
-    sorted_vec.sort_by(|lhs, rhs| rhs.1.cmp(lhs.1));
-    let sorted_vec: Vec<u8> = sorted_vec.iter().map(|(val, _)| **val).collect();
+    pub fn padding(&self) -> Option<u8> {
+      self.padding
+    }
 
-    let expected = b"et aoinshrdlcumwfgypbvkjxqz";
+    pub fn bits_per_symbol(&self) -> u32 {
+      self.bits
+    }
 
-    let mut sum_similar: f32 = 0.0;
+    // The fewest symbols that hold a whole number of bytes.
+    pub fn group_symbols(&self) -> usize {
+      8 >> self.bits.trailing_zeros().min(3)
+    }
 
-    for (i, actual) in sorted_vec.iter().enumerate() {
-      if let Some(exp_position) = expected.iter().position(|&s| s == *actual) {
-        sum_similar += 1.0 - ((exp_position as f32 - (i as f32)).abs() / expected.len() as f32)
+    // The number of symbols `len` bytes encode to, including padding.
+    pub fn encoded_len(&self, len: usize) -> usize {
+      let symbols = (len * 8).div_ceil(self.bits as usize);
+      match self.padding {
+        Some(_) => symbols.next_multiple_of(self.group_symbols()),
+        None => symbols,
       }
     }
-    sum_similar / (expected.len() as f32)
   }
 
-  pub fn frequency(input: &str) -> HashMap<u8, usize> {
-    let input = input.to_lowercase();
-    let mut map: HashMap<u8, usize> = HashMap::new();
-    for byte in input.as_bytes().iter() {
-      // skip spaces
-      *map.entry(*byte).or_insert(0) += 1;
+  // One bit per byte of output, as in "01001000 01101001" without the spaces.
+  pub const BINARY: Radix = Radix::new(b"01", None);
+  // Octal packed three bits to a symbol across byte boundaries, not one
+  // number per byte.
+  pub const OCTAL: Radix = Radix::new(b"01234567", None);
+  pub const HEX: Radix = Radix::new(b"0123456789abcdef", None);
+  pub const HEX_UPPER: Radix = Radix::new(b"0123456789ABCDEF", None);
+  // RFC 4648 base32 and base32hex.
+  pub const BASE32: Radix = Radix::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", Some(b'='));
+  pub const BASE32_HEX: Radix = Radix::new(b"0123456789ABCDEFGHIJKLMNOPQRSTUV", Some(b'='));
+  // Douglas Crockford's base32, which leaves out I, L, O and U. Decoding
+  // reads O as 0, I and L as 1, and skips hyphens.
+  pub const BASE32_CROCKFORD: Radix = Radix::new(b"0123456789ABCDEFGHJKMNPQRSTVWXYZ", None)
+    .with_alias(b'O', b'0')
+    .with_alias(b'I', b'1')
+    .with_alias(b'L', b'1')
+    .ignoring(b'-');
+  // RFC 4648 base64, and the URL-safe alphabet unpadded as it's usually used.
+  pub const BASE64: Radix =
+    Radix::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/", Some(b'='));
+  pub const BASE64_URL: Radix =
+    Radix::new(b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_", None);
+
+  pub fn encode(input: &[u8], radix: &Radix) -> Vec<u8> {
+    let mut output = Vec::with_capacity(radix.encoded_len(input.len()));
+    let mut bits = BitQueue::default();
+    for byte in input {
+      bits.push(u32::from(*byte), 8);
+      while bits.len >= radix.bits {
+        output.push(radix.alphabet[bits.pop(radix.bits) as usize]);
+      }
     }
-    map
+    finish_symbols(&mut output, bits, radix);
+    output
   }
 
-  pub fn hamming_distance(lhs: &[u8], rhs: &[u8]) -> u32 {
-    let mut dist = 0;
-    for (l, r) in lhs.iter().zip(rhs.iter()) {
-      // By taking the xor of l and r, we get the bits set to 1 that were different
-      // between l and r's binary representation.
-      let different_bits = l ^ r;
-      for shift in 0..8 {
-        // Bit shift this comparison bit through all the fields that could have
-        // values so we can find out what bits are set in different_bits.
-        let comparison = 0b1 << shift;
-        if (different_bits & comparison) != 0 {
-          dist += 1
-        }
+  pub fn decode(input: &[u8], radix: &Radix) -> Result<Vec<u8>, DecodeError> {
+    let mut output = Vec::with_capacity(input.len() * radix.bits as usize / 8);
+    let mut bits = BitQueue::default();
+    read_symbols(input, radix, |value| {
+      bits.push(u32::from(value), radix.bits);
+      if bits.len >= 8 {
+        output.push(bits.pop(8) as u8);
       }
-    }
-    // Not mentioned in the problem spec, but we consider differences of length
-    // to be different for every bit, not just 1's. This is an impl detail.
-    (dist + (lhs.len() as i32 - rhs.len() as i32).abs() * 8) as u32
+    })?;
+    Ok(output)
   }
-}
 
-#[cfg(test)]
-mod tests {
-  use b64::B64Buffer;
-  use hex::HexBuffer;
-  use Encodable;
-  use StringConstructible;
-  use Xorable;
-  use std;
-  use strings;
-    #[test]
-    fn hex_buffer_converts_string() {
-      let buf = HexBuffer { raw_bytes: "hey! my name is Oliver, what's yours?".as_bytes().to_vec() };
-      assert_eq!(std::str::from_utf8(&buf.encode_to_utf8()).unwrap(), "68657921206d79206e616d65206973204f6c697665722c2077686174277320796f7572733f");
+  // Re-encode `input` from one radix to another, streaming bits from symbol
+  // to symbol without decoding to bytes first. The result is the same as
+  // encode(&decode(input, from)?, to).
+  pub fn transcode(input: &[u8], from: &Radix, to: &Radix) -> Result<Vec<u8>, DecodeError> {
+    let mut output = Vec::new();
+    let mut bits = BitQueue::default();
+    // The last up to 7 bits read may turn out to be the zero fill of the
+    // final symbol rather than data, so they're held back until the end.
+    let symbols = read_symbols(input, from, |value| {
+      bits.push(u32::from(value), from.bits);
+      while bits.len >= to.bits + 7 {
+        output.push(to.alphabet[bits.pop(to.bits) as usize]);
+      }
+    })?;
+    let fill = (symbols * from.bits as usize % 8) as u32;
+    bits.drop_last(fill);
+    while bits.len >= to.bits {
+      output.push(to.alphabet[bits.pop(to.bits) as usize]);
     }
+    finish_symbols(&mut output, bits, to);
+    Ok(output)
+  }
 
-    #[test]
-    fn hex_buffer_converts_string_and_back() {
-      let string = "hey! my name is Oliver, what's yours?";
-      let raw_bytes = string.as_bytes().to_vec();
-      let buf = HexBuffer { raw_bytes };
-      let encoded = buf.encode_to_utf8();
-      let new_buf = HexBuffer::from_encoded_utf8_buffer(&encoded);
-      let decoded_bin = new_buf.decode_to_bin();
-      let decoded = std::str::from_utf8(&decoded_bin).unwrap();
-      assert_eq!(decoded, string);
-    }
+  // Marks bytes that decoding skips in a decode table.
+  const SKIPPED: u8 = 0xfe;
 
-    #[test]
-    fn b64_buffer_converts_string() {
-      let buf = B64Buffer { raw_bytes: "hey! my name is Oliver, what's yours?".as_bytes().to_vec() };
-      assert_eq!(std::str::from_utf8(&buf.encode_to_utf8()).unwrap(), "aGV5ISBteSBuYW1lIGlzIE9saXZlciwgd2hhdCdzIHlvdXJzPw==");
-    }
+  // A first-in first-out queue of up to 32 bits.
+  #[derive(Default)]
+  struct BitQueue {
+    value: u64,
+    len: u32,
+  }
 
-    #[test]
-    fn b64_buffer_converts_string_and_back() {
-      let string = "hey! my name is Oliver, what's yours?";
-      let raw_bytes = string.as_bytes().to_vec();
-      let buf = B64Buffer { raw_bytes };
-      let encoded = buf.encode_to_utf8();
-      let new_buf = B64Buffer::from_encoded_utf8_buffer(&encoded);
-      let decoded_bin = new_buf.decode_to_bin();
-      let decoded = std::str::from_utf8(&decoded_bin).unwrap();
-      assert_eq!(decoded, string);
+  impl BitQueue {
+    fn push(&mut self, value: u32, bits: u32) {
+      self.value = self.value << bits | u64::from(value);
+      self.len += bits;
     }
 
-    #[test]
-    fn hamming_distance_wokka_wokka() {
-      assert_eq!(strings::hamming_distance(b"this is a test", b"wokka wokka!!!"), 37);
+    fn pop(&mut self, bits: u32) -> u32 {
+      self.len -= bits;
+      let value = (self.value >> self.len) as u32 & ((1 << bits) - 1);
+      self.value &= (1 << self.len) - 1;
+      value
     }
 
-    #[test]
-    fn frequency_count_single_char_works() {
-      assert_eq!(*strings::frequency("eeeeeeeeee").get(&101).unwrap(), 10);
+    fn drop_last(&mut self, bits: u32) {
+      self.value >>= bits;
+      self.len -= bits;
     }
+  }
 
-    #[test]
-    fn frequency_count_multiple_char_works() {
-      let freq = strings::frequency("eeeeeeeeeeaaaaa");
-      assert_eq!(*freq.get(&101).unwrap(), 10);
-      assert_eq!(*freq.get(&97).unwrap(), 5);
+  // Write out any bits still queued as a last zero-filled symbol, then pad.
+  fn finish_symbols(output: &mut Vec<u8>, mut bits: BitQueue, radix: &Radix) {
+    if bits.len > 0 {
+      let fill = radix.bits - bits.len;
+      bits.push(0, fill);
+      output.push(radix.alphabet[bits.pop(radix.bits) as usize]);
     }
+    if let Some(padding) = radix.padding {
+      let len = output.len().next_multiple_of(radix.group_symbols());
+      output.resize(len, padding);
+    }
+  }
 
-    #[test]
-    fn single_xor_buffer() {
+  // Pass the value of each symbol to `sink`, checking the input against the
+  // alphabet and padding rule, and return how many symbols there were.
+  fn read_symbols<F: FnMut(u8)>(input: &[u8], radix: &Radix, mut sink: F) -> Result<usize, DecodeError> {
+    let mut symbols = 0;
+    // Offset and byte of the last symbol, to blame for an odd hex digit.
+    let mut last = (0, 0);
+    // Offset of the first padding byte, once one has been seen.
+    let mut padding_start: Option<usize> = None;
+
+    for (i, byte) in input.iter().enumerate() {
+      if Some(*byte) == radix.padding {
+        padding_start.get_or_insert(i);
+        continue;
+      }
+      if padding_start.is_some() {
+        // Padding can only end the input.
+        return Err(DecodeError::new(DecodeErrorKind::TrailingData, Some(*byte), i));
+      }
+      match radix.decode_table[*byte as usize] {
+        INVALID_SYMBOL => return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(*byte), i)),
+        SKIPPED => continue,
+        value => sink(value),
+      }
+      symbols += 1;
+      last = (i, *byte);
+    }
+
+    // The final symbol can only carry fill bits short of a whole symbol;
+    // any more and a symbol was added that no byte needed.
+    let end = padding_start.unwrap_or(input.len());
+    if symbols * radix.bits as usize % 8 >= radix.bits as usize {
+      if radix.bits == 4 {
+        return Err(DecodeError::new(DecodeErrorKind::OddLength, Some(last.1), last.0));
+      }
+      return Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, end));
+    }
+    // With padding, the symbols and padding together make whole groups.
+    let padding = input.len() - end;
+    if radix.padding.is_some() && symbols + padding != symbols.next_multiple_of(radix.group_symbols()) {
+      return Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, end));
+    }
+    Ok(symbols)
+  }
+}
+
+pub mod hex {
+  #[cfg(feature = "std")]
+  use std::io::Read;
+  #[cfg(feature = "std")]
+  use std::io::Write;
+  #[cfg(feature = "std")]
+  use std::io;
+  use std::string::String;
+  use std::vec::Vec;
+  use reverse_table;
+  use hexdump;
+  use hexdump::HexdumpConfig;
+  use Codec;
+  use DecodeError;
+  use DecodeErrorKind;
+  use INVALID_SYMBOL;
+
+  #[derive(Debug)]
+  pub struct HexBuffer {
+    // Internal representation is a decoded binary buffer.
+    pub raw_bytes: Vec<u8>
+  }
+
+  // How to lay out encoded hex. Bytes are collected into groups of
+  // `group_size`, each group is preceded by `prefix`, and groups are joined
+  // with `separator`.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct HexFormat {
+    pub uppercase: bool,
+    // Zero puts all bytes in a single group.
+    pub group_size: usize,
+    pub separator: &'static [u8],
+    pub prefix: &'static [u8],
+  }
+
+  // deadbeef
+  pub const LOWER: HexFormat = HexFormat { uppercase: false, group_size: 0, separator: b"", prefix: b"" };
+  // DEADBEEF
+  pub const UPPER: HexFormat = HexFormat { uppercase: true, group_size: 0, separator: b"", prefix: b"" };
+  // de:ad:be:ef, as printed by openssl and Wireshark's "copy as hex".
+  pub const COLON: HexFormat = HexFormat { uppercase: false, group_size: 1, separator: b":", prefix: b"" };
+  // de ad be ef
+  pub const SPACED: HexFormat = HexFormat { uppercase: false, group_size: 1, separator: b" ", prefix: b"" };
+  // \xde\xad\xbe\xef, for pasting into string literals.
+  pub const ESCAPED: HexFormat = HexFormat { uppercase: false, group_size: 1, separator: b"", prefix: b"\\x" };
+  // 0xde, 0xad, 0xbe, 0xef, for pasting into array literals.
+  pub const C_ARRAY: HexFormat = HexFormat { uppercase: false, group_size: 1, separator: b", ", prefix: b"0x" };
+
+  // Encodes as lowercase hex and decodes a bare run of digits in either case.
+  impl Codec for HexBuffer {
+    const NAME: &'static str = "hex";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      encode(raw)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      decode(encoded)
+    }
+  }
+
+  impl HexBuffer {
+    // Decodes a bare run of hex digits in either case.
+    pub fn try_from_encoded(buffer: &[u8]) -> Result<HexBuffer, DecodeError> {
+      Ok(HexBuffer { raw_bytes: decode(buffer)? })
+    }
+
+    // Decodes hex as it's usually pasted from other tools. On top of what
+    // try_from_encoded accepts, this skips whitespace and the ':', '-', ',' and
+    // '_' separators, and drops "0x", "0X" and "\x" prefixes. Separators and
+    // prefixes may only appear between bytes, never between the two digits of
+    // one byte.
+    pub fn try_from_formatted(buffer: &[u8]) -> Result<HexBuffer, DecodeError> {
+      Ok(HexBuffer { raw_bytes: decode_formatted(buffer)? })
+    }
+
+    pub fn encode_with_format(&self, format: &HexFormat) -> Vec<u8> {
+      encode_formatted(&self.raw_bytes, format)
+    }
+
+    // Decodes an xxd-style dump, as produced by hexdump() or `xxd` itself.
+    pub fn try_from_hexdump(buffer: &[u8]) -> Result<HexBuffer, DecodeError> {
+      Ok(HexBuffer { raw_bytes: hexdump::parse(buffer)? })
+    }
+
+    pub fn hexdump(&self, config: &HexdumpConfig) -> String {
+      hexdump::format(&self.raw_bytes, config)
+    }
+  }
+
+  // 0123456789abcdef
+  const HEX_CONVERSION_TABLE: &[u8; 16] = b"0123456789abcdef";
+  // 0123456789ABCDEF
+  const HEX_UPPER_CONVERSION_TABLE: &[u8; 16] = b"0123456789ABCDEF";
+
+  // Symbol value for every possible input byte, in either case. Percent
+  // escapes are hex too, so url shares this and the pair tables.
+  pub(crate) static HEX_DECODE_TABLE: [u8; 256] = reverse_table(&[HEX_CONVERSION_TABLE, HEX_UPPER_CONVERSION_TABLE]);
+  // Both hex digits for every possible byte value.
+  pub(crate) static HEX_PAIR_TABLE: [[u8; 2]; 256] = pair_table(HEX_CONVERSION_TABLE);
+  pub(crate) static HEX_UPPER_PAIR_TABLE: [[u8; 2]; 256] = pair_table(HEX_UPPER_CONVERSION_TABLE);
+
+  const fn pair_table(alphabet: &[u8; 16]) -> [[u8; 2]; 256] {
+    let mut table = [[0; 2]; 256];
+    let mut i = 0;
+    while i < 256 {
+      table[i] = [alphabet[i >> 4], alphabet[i & 0b1111]];
+      i += 1;
+    }
+    table
+  }
+
+  // The number of hex digits needed to encode `len` bytes.
+  pub fn encoded_len(len: usize) -> usize {
+    len * 2
+  }
+
+  // The number of bytes `len` hex digits decode to.
+  pub fn decoded_len(len: usize) -> usize {
+    len / 2
+  }
+
+  // Encode `input` as lowercase hex into the front of `output` and return the
+  // number of bytes written. Panics if output is shorter than
+  // encoded_len(input.len()).
+  pub fn encode_into(input: &[u8], output: &mut [u8]) -> usize {
+    encode_pairs_into(input, output, &HEX_PAIR_TABLE)
+  }
+
+  // Decode hex of either case into the front of `output` and return the number
+  // of bytes written. Panics if output is shorter than decoded_len(input.len()).
+  pub fn decode_into(input: &[u8], output: &mut [u8]) -> Result<usize, DecodeError> {
+    // Each hex byte holds 4 bits of information for the binary buffer.
+    // Thus we just have to combine each pair of 2 values in the original
+    // buffer, decode their values, and we've got a binary buffer.
+    if !input.len().is_multiple_of(2) {
+      let last = input.len() - 1;
+      return Err(DecodeError::new(DecodeErrorKind::OddLength, Some(input[last]), last));
+    }
+    let len = decoded_len(input.len());
+    assert!(output.len() >= len, "output too small to hold decoded hex");
+
+    // Decode 16 digits into a u64 at a time. Invalid symbols look up as
+    // INVALID_SYMBOL, so OR-ing all the lookups together lets us check the
+    // whole word with one branch and only go looking for the culprit on error.
+    let mut inputs = input.chunks_exact(16);
+    let mut outputs = output[..len].chunks_exact_mut(8);
+    for (i, (src, dst)) in (&mut inputs).zip(&mut outputs).enumerate() {
+      let mut word: u64 = 0;
+      let mut seen: u8 = 0;
+      for byte in src {
+        let value = HEX_DECODE_TABLE[*byte as usize];
+        seen |= value;
+        word = (word << 4) | u64::from(value & 0b1111);
+      }
+      if seen == INVALID_SYMBOL {
+        return Err(first_invalid_symbol(src, i * 16));
+      }
+      dst.copy_from_slice(&word.to_be_bytes());
+    }
+
+    let offset = input.len() - inputs.remainder().len();
+    for (i, (src, dst)) in inputs.remainder().chunks(2).zip(outputs.into_remainder()).enumerate() {
+      *dst = (decode_hex_byte(&src[0], offset + i * 2)? << 4) | decode_hex_byte(&src[1], offset + i * 2 + 1)?;
+    }
+    Ok(len)
+  }
+
+  fn first_invalid_symbol(input: &[u8], offset: usize) -> DecodeError {
+    let position = input.iter().position(|b| HEX_DECODE_TABLE[*b as usize] == INVALID_SYMBOL)
+      .expect("caller found an invalid symbol");
+    DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(input[position]), offset + position)
+  }
+
+  fn encode_pairs_into(input: &[u8], output: &mut [u8], table: &[[u8; 2]; 256]) -> usize {
+    // Each binary byte contains 8 bits of information. We need to map
+    // this to twice that number of hex bytes, which each contain 4 bits.
+    let len = encoded_len(input.len());
+    assert!(output.len() >= len, "output too small to hold encoded hex");
+
+    // Build 16 digits at a time in a u128 so each group is a single store.
+    let mut inputs = input.chunks_exact(8);
+    let mut outputs = output[..len].chunks_exact_mut(16);
+    for (src, dst) in (&mut inputs).zip(&mut outputs) {
+      let mut word: u128 = 0;
+      for byte in src {
+        word = (word << 16) | u128::from(u16::from_be_bytes(table[*byte as usize]));
+      }
+      dst.copy_from_slice(&word.to_be_bytes());
+    }
+    for (src, dst) in inputs.remainder().iter().zip(outputs.into_remainder().chunks_exact_mut(2)) {
+      dst.copy_from_slice(&table[*src as usize]);
+    }
+    len
+  }
+
+  // Take a hex-encoded utf8 byte of either case and convert to a binary byte.
+  // The offset is only used to report where an invalid byte was found.
+  fn decode_hex_byte(byte: &u8, offset: usize) -> Result<u8, DecodeError> {
+    match HEX_DECODE_TABLE[*byte as usize] {
+      INVALID_SYMBOL => Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(*byte), offset)),
+      value => Ok(value)
+    }
+  }
+
+  // Decode a bare run of hex digits in either case.
+  pub fn decode(buffer: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut output = vec![0; decoded_len(buffer.len())];
+    decode_into(buffer, &mut output)?;
+    Ok(output)
+  }
+
+  fn is_separator(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\r' | b'\n' | b':' | b'-' | b',' | b'_')
+  }
+
+  // Decode hex with the separators and prefixes that
+  // HexBuffer::try_from_formatted accepts.
+  pub fn decode_formatted(buffer: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut output: Vec<u8> = Vec::with_capacity(buffer.len() / 2);
+    // The high nibble of the byte being decoded, with the offset and symbol
+    // it came from in case it turns out to be the last one.
+    let mut pending: Option<(usize, u8, u8)> = None;
+    let mut i = 0;
+
+    while i < buffer.len() {
+      let byte = buffer[i];
+      if pending.is_none() {
+        match (byte, buffer.get(i + 1)) {
+          (b'0', Some(&b'x')) | (b'0', Some(&b'X')) | (b'\\', Some(&b'x')) => {
+            i += 2;
+            continue;
+          },
+          (b, _) if is_separator(b) => {
+            i += 1;
+            continue;
+          },
+          _ => {}
+        }
+      }
+
+      let nibble = decode_hex_byte(&byte, i)?;
+      match pending.take() {
+        Some((_, _, high)) => output.push((high << 4) | nibble),
+        None => pending = Some((i, byte, nibble))
+      }
+      i += 1;
+    }
+
+    match pending {
+      Some((offset, byte, _)) => Err(DecodeError::new(DecodeErrorKind::OddLength, Some(byte), offset)),
+      None => Ok(output)
+    }
+  }
+
+  // Encode `buffer` as lowercase hex.
+  pub fn encode(buffer: &[u8]) -> Vec<u8> {
+    let mut output = vec![0; encoded_len(buffer.len())];
+    encode_into(buffer, &mut output);
+    output
+  }
+
+  // Encode `buffer` as hex laid out per the format.
+  pub fn encode_formatted(buffer: &[u8], format: &HexFormat) -> Vec<u8> {
+    let table = if format.uppercase { &HEX_UPPER_PAIR_TABLE } else { &HEX_PAIR_TABLE };
+    let group_size = if format.group_size == 0 { buffer.len().max(1) } else { format.group_size };
+    let groups = buffer.len().div_ceil(group_size);
+    let mut output: Vec<u8> = Vec::with_capacity(
+      encoded_len(buffer.len()) + groups * (format.prefix.len() + format.separator.len()));
+
+    for (i, group) in buffer.chunks(group_size).enumerate() {
+      if i > 0 {
+        output.extend_from_slice(format.separator);
+      }
+      output.extend_from_slice(format.prefix);
+      for c in group {
+        output.extend_from_slice(&table[*c as usize]);
+      }
+    }
+
+    output
+  }
+
+  // Hex-encodes everything written to it and passes the result on to the
+  // wrapped writer.
+  #[cfg(feature = "std")]
+  pub struct Encoder<W: Write> {
+    inner: W,
+  }
+
+  #[cfg(feature = "std")]
+  impl<W: Write> Encoder<W> {
+    pub fn new(inner: W) -> Encoder<W> {
+      Encoder { inner }
+    }
+
+    pub fn into_inner(self) -> W {
+      self.inner
+    }
+  }
+
+  #[cfg(feature = "std")]
+  impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+      // Hex has no partial groups, so every write can go straight through.
+      let mut encoded = [0; 8192];
+      for chunk in buf.chunks(encoded.len() / 2) {
+        let len = encode_into(chunk, &mut encoded);
+        self.inner.write_all(&encoded[..len])?;
+      }
+      Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      self.inner.flush()
+    }
+  }
+
+  // Reads hex of either case from the wrapped reader and yields the decoded
+  // bytes. Invalid input surfaces as an io::ErrorKind::InvalidData error
+  // wrapping a DecodeError, with offsets counted from the start of the stream.
+  // Everything before the bad symbol is yielded first, and every read after
+  // the error repeats it.
+  #[cfg(feature = "std")]
+  pub struct Decoder<R: Read> {
+    inner: R,
+    skip_whitespace: bool,
+    // Offset in the encoded stream of the next byte read from inner.
+    offset: usize,
+    // The high nibble of a byte split across two reads, with the offset and
+    // symbol it came from.
+    pending: Option<(usize, u8, u8)>,
+    // Bad input found after some bytes of a read had already decoded.
+    error: Option<DecodeError>,
+    input: Vec<u8>,
+  }
+
+  #[cfg(feature = "std")]
+  impl<R: Read> Decoder<R> {
+    pub fn new(inner: R) -> Decoder<R> {
+      Decoder { inner, skip_whitespace: false, offset: 0, pending: None, error: None, input: vec![0; 8192] }
+    }
+
+    // Like new, but ignores whitespace anywhere in the input, so line-wrapped
+    // hex files can be streamed.
+    pub fn skipping_whitespace(inner: R) -> Decoder<R> {
+      Decoder { skip_whitespace: true, ..Decoder::new(inner) }
+    }
+
+    pub fn into_inner(self) -> R {
+      self.inner
+    }
+  }
+
+  #[cfg(feature = "std")]
+  impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+      if let Some(error) = self.error {
+        return Err(error.into());
+      }
+      if buf.is_empty() {
+        return Ok(0);
+      }
+
+      loop {
+        // Never read more digits than fit in buf once decoded.
+        let limit = (buf.len() * 2 - self.pending.map_or(0, |_| 1)).min(self.input.len());
+        let count = self.inner.read(&mut self.input[..limit])?;
+        if count == 0 {
+          return match self.pending {
+            Some((offset, byte, _)) => Err(DecodeError::new(DecodeErrorKind::OddLength, Some(byte), offset).into()),
+            None => Ok(0)
+          };
+        }
+
+        let mut written = 0;
+        for (i, byte) in self.input[..count].iter().enumerate() {
+          if self.skip_whitespace && (*byte as char).is_ascii_whitespace() {
+            continue;
+          }
+          let nibble = match decode_hex_byte(byte, self.offset + i) {
+            Ok(nibble) => nibble,
+            Err(error) => {
+              self.error = Some(error);
+              break;
+            }
+          };
+          match self.pending.take() {
+            Some((_, _, high)) => {
+              buf[written] = (high << 4) | nibble;
+              written += 1;
+            },
+            None => self.pending = Some((self.offset + i, *byte, nibble))
+          }
+        }
+        self.offset += count;
+
+        // Hand over what decoded before the bad symbol; the error comes on
+        // the next read.
+        if let Some(error) = self.error {
+          return if written > 0 { Ok(written) } else { Err(error.into()) };
+        }
+
+        // A read that only produced half a byte (or only whitespace) isn't
+        // the end of the stream, so keep going.
+        if written > 0 {
+          return Ok(written);
+        }
+      }
+    }
+  }
+}
+
+pub mod hexdump {
+  use std::fmt::Write;
+  use std::ops::Range;
+  use std::string::String;
+  use std::vec::Vec;
+  use DecodeError;
+  use DecodeErrorKind;
+
+  // How to mark highlighted bytes. Ansi wraps them in reverse video escapes
+  // for a terminal; Marker puts a line of carets under them, which survives
+  // being pasted into a log or an issue.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum Highlight {
+    Ansi,
+    Marker,
+  }
+
+  // The layout of an xxd-style dump: an offset, `width` bytes of hex split
+  // into groups of `group_size`, and optionally the same bytes as ASCII.
+  // With a `block_size` each block boundary inside a line is drawn as a '|'
+  // instead of the group separator, and a boundary falling between lines of
+  // a block longer than a line gets a rule of dashes.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct HexdumpConfig {
+    pub width: usize,
+    // Zero puts a whole line in a single group.
+    pub group_size: usize,
+    pub uppercase: bool,
+    pub ascii: bool,
+    pub block_size: Option<usize>,
+    pub highlight: Highlight,
+  }
+
+  // 00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a       Hello, world!.
+  pub const XXD: HexdumpConfig =
+    HexdumpConfig { width: 16, group_size: 2, uppercase: false, ascii: true, block_size: None, highlight: Highlight::Marker };
+  // 00000000: 48 65 6c 6c 6f 2c 20 77 6f 72 6c 64 21 0a        Hello, world!.
+  pub const BYTES: HexdumpConfig =
+    HexdumpConfig { width: 16, group_size: 1, uppercase: false, ascii: true, block_size: None, highlight: Highlight::Marker };
+  // Two 16-byte cipher blocks a line, split by a '|'.
+  pub const BLOCKS: HexdumpConfig =
+    HexdumpConfig { width: 32, group_size: 4, uppercase: false, ascii: true, block_size: Some(16), highlight: Highlight::Marker };
+
+  // The most zeros parse will fill gaps with, over a whole dump.
+  pub const MAX_FILL: usize = 1 << 20;
+
+  const HIGHLIGHT_START: &str = "\x1b[7m";
+  const HIGHLIGHT_END: &str = "\x1b[0m";
+
+  pub fn format(buffer: &[u8], config: &HexdumpConfig) -> String {
+    format_highlighted(buffer, config, &[])
+  }
+
+  // Dump `buffer` with every byte whose offset falls in one of `highlights`
+  // marked in both the hex and the ASCII columns.
+  pub fn format_highlighted(buffer: &[u8], config: &HexdumpConfig, highlights: &[Range<usize>]) -> String {
+    let highlighted = |offset: usize| highlights.iter().any(|range| range.contains(&offset));
+    lines(buffer, config, &highlighted).concat()
+  }
+
+  // Dump two buffers line by line, marking the bytes that differ. Lines that
+  // are the same in both are shown once; otherwise the line from `lhs` comes
+  // first. Bytes past the end of the shorter buffer count as different.
+  pub fn diff(lhs: &[u8], rhs: &[u8], config: &HexdumpConfig) -> String {
+    let changed = |offset: usize| lhs.get(offset) != rhs.get(offset);
+    let lhs_lines = lines(lhs, config, &changed);
+    let rhs_lines = lines(rhs, config, &changed);
+    let mut output = String::new();
+    for i in 0..lhs_lines.len().max(rhs_lines.len()) {
+      match (lhs_lines.get(i), rhs_lines.get(i)) {
+        (Some(l), Some(r)) if l == r => output.push_str(l),
+        (l, r) => {
+          output.push_str(l.map_or("", |l| l));
+          output.push_str(r.map_or("", |r| r));
+        }
+      }
+    }
+    output
+  }
+
+  // Read a dump back into bytes. Only lines that start with a hex offset and
+  // a ':' are data; anything else, such as rules and marker lines, is
+  // skipped. The hex column ends at the first double space, so the ASCII
+  // column is never read, and '|' block separators and ANSI escapes are
+  // ignored. As with `xxd -r`, each line is written at its offset and any
+  // gap before it is filled with zeros, but since the output is built in
+  // memory, gaps may only add up to MAX_FILL bytes: an offset past that is
+  // an Overflow error rather than a huge allocation.
+  pub fn parse(text: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut output = Vec::new();
+    let mut filled = 0;
+    let mut line_start = 0;
+    for line in text.split(|&b| b == b'\n') {
+      parse_line(line, line_start, &mut output, &mut filled)?;
+      line_start += line.len() + 1;
+    }
+    Ok(output)
+  }
+
+  // Collects a line and, for Marker highlighting, the carets that go under
+  // it. The two stay the same visible width as long as nothing is escaped.
+  struct LineWriter<'a> {
+    config: &'a HexdumpConfig,
+    line: String,
+    marker: String,
+    marked: bool,
+  }
+
+  impl<'a> LineWriter<'a> {
+    fn push(&mut self, text: &str, highlighted: bool) {
+      match (highlighted, self.config.highlight) {
+        (true, Highlight::Ansi) => {
+          self.line.push_str(HIGHLIGHT_START);
+          self.line.push_str(text);
+          self.line.push_str(HIGHLIGHT_END);
+        }
+        _ => self.line.push_str(text),
+      }
+      let mark = if highlighted { '^' } else { ' ' };
+      self.marker.extend(text.chars().map(|_| mark));
+      self.marked |= highlighted;
+    }
+
+    fn finish(self) -> String {
+      // Padding after a short last line is only trailing junk when there's
+      // no ASCII column; with one, a trailing space may be a space byte.
+      let mut output = if self.config.ascii { self.line } else { String::from(self.line.trim_end()) };
+      output.push('\n');
+      if self.marked && self.config.highlight == Highlight::Marker {
+        output.push_str(self.marker.trim_end());
+        output.push('\n');
+      }
+      output
+    }
+  }
+
+  // One entry per line of the dump, each ending in a newline and carrying
+  // its rule and marker lines with it.
+  fn lines(buffer: &[u8], config: &HexdumpConfig, highlighted: &dyn Fn(usize) -> bool) -> Vec<String> {
+    assert!(config.width > 0);
+    (0..buffer.len()).step_by(config.width).map(|offset| {
+      let mut line = String::new();
+      if let Some(block_size) = config.block_size {
+        if block_size > config.width && offset > 0 && offset.is_multiple_of(block_size) {
+          line.push_str(&"-".repeat(line_width(config)));
+          line.push('\n');
+        }
+      }
+      line.push_str(&format_line(buffer, offset, config, highlighted));
+      line
+    }).collect()
+  }
+
+  fn format_line(buffer: &[u8], offset: usize, config: &HexdumpConfig, highlighted: &dyn Fn(usize) -> bool) -> String {
+    let mut writer = LineWriter { config, line: String::new(), marker: String::new(), marked: false };
+    writer.push(&format!("{:08x}: ", offset), false);
+    let mut hex = String::with_capacity(2);
+    for column in 0..config.width {
+      let position = offset + column;
+      if column > 0 {
+        writer.push(separator(position, column, config), false);
+      }
+      match buffer.get(position) {
+        Some(byte) => {
+          hex.clear();
+          if config.uppercase {
+            write!(hex, "{:02X}", byte).unwrap();
+          } else {
+            write!(hex, "{:02x}", byte).unwrap();
+          }
+          writer.push(&hex, highlighted(position));
+        }
+        None => writer.push("  ", false),
+      }
+    }
+    if config.ascii {
+      writer.push("  ", false);
+      let end = buffer.len().min(offset + config.width);
+      for (position, byte) in buffer[offset..end].iter().enumerate().map(|(i, b)| (offset + i, b)) {
+        let c = if byte.is_ascii_graphic() || *byte == b' ' { *byte as char } else { '.' };
+        writer.push(c.encode_utf8(&mut [0; 4]), highlighted(position));
+      }
+    }
+    writer.finish()
+  }
+
+  // What goes between the byte at `position` and the one before it.
+  fn separator(position: usize, column: usize, config: &HexdumpConfig) -> &'static str {
+    let block_boundary = config.block_size.is_some_and(|size| position.is_multiple_of(size));
+    let group_boundary = config.group_size > 0 && column.is_multiple_of(config.group_size);
+    match (block_boundary, group_boundary) {
+      (true, _) => "|",
+      (false, true) => " ",
+      (false, false) => "",
+    }
+  }
+
+  // The width of a full line without the ASCII column, for rules.
+  fn line_width(config: &HexdumpConfig) -> usize {
+    let separators: usize = (1..config.width).map(|column| separator(column, column, config).len()).sum();
+    "00000000: ".len() + 2 * config.width + separators
+  }
+
+  // Decode one line onto output. `filled` counts the zeros gaps have
+  // taken so far.
+  fn parse_line(line: &[u8], line_start: usize, output: &mut Vec<u8>, filled: &mut usize) -> Result<(), DecodeError> {
+    let colon = match line.iter().position(|&b| b == b':') {
+      Some(colon) => colon,
+      None => return Ok(()),
+    };
+    let label: Vec<u8> = strip_escapes(&line[..colon]).map(|(_, b)| b).collect();
+    if label.is_empty() || !label.iter().all(u8::is_ascii_hexdigit) {
+      return Ok(());
+    }
+    let overflow = DecodeError::new(DecodeErrorKind::Overflow, None, line_start);
+    let mut offset = 0usize;
+    for digit in label {
+      offset = offset.checked_mul(16).and_then(|o| o.checked_add(nibble(digit) as usize)).ok_or(overflow)?;
+    }
+
+    let mut bytes = Vec::new();
+    let mut high: Option<(usize, u8)> = None;
+    let mut previous_space = false;
+    for (index, byte) in strip_escapes(&line[colon + 1..]).map(|(i, b)| (colon + 1 + i, b)) {
+      let at = line_start + index;
+      match (byte, high) {
+        (b' ', None) if previous_space => break,
+        (b' ', None) | (b'|', None) | (b'\r', None) => {}
+        (digit, None) if digit.is_ascii_hexdigit() => high = Some((at, nibble(digit))),
+        (digit, Some((_, h))) if digit.is_ascii_hexdigit() => {
+          bytes.push(h << 4 | nibble(digit));
+          high = None;
+        }
+        (byte, _) => return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(byte), at)),
+      }
+      previous_space = byte == b' ';
+    }
+    if let Some((at, _)) = high {
+      return Err(DecodeError::new(DecodeErrorKind::OddLength, None, at));
+    }
+
+    let end = offset.checked_add(bytes.len()).ok_or(overflow)?;
+    if output.len() < offset {
+      *filled += offset - output.len();
+      if *filled > MAX_FILL {
+        return Err(overflow);
+      }
+    }
+    if output.len() < end {
+      output.resize(end, 0);
+    }
+    output[offset..end].copy_from_slice(&bytes);
+    Ok(())
+  }
+
+  // The bytes of `text` with their indices, minus any `ESC [ ... m` sequences.
+  fn strip_escapes(text: &[u8]) -> impl Iterator<Item = (usize, u8)> + '_ {
+    let mut in_escape = false;
+    text.iter().cloned().enumerate().filter(move |&(_, byte)| {
+      if byte == 0x1b {
+        in_escape = true;
+      } else if in_escape && byte == b'm' {
+        in_escape = false;
+        return false;
+      }
+      !in_escape
+    })
+  }
+
+  fn nibble(digit: u8) -> u8 {
+    (digit as char).to_digit(16).expect("checked to be a hex digit") as u8
+  }
+}
+
+pub mod b64 {
+  #[cfg(feature = "std")]
+  use std::io::Read;
+  #[cfg(feature = "std")]
+  use std::io::Write;
+  #[cfg(feature = "std")]
+  use std::io;
+  use std::vec::Vec;
+  use reverse_table;
+  use Codec;
+  use DecodeError;
+  use DecodeErrorKind;
+  use INVALID_SYMBOL;
+
+  #[derive(Debug)]
+  pub struct B64Buffer {
+    // Internal representation is a decoded binary buffer.
+    pub raw_bytes: Vec<u8>
+  }
+
+  // Which 64 symbols encode the 6-bit values. They only differ in the last two.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum Alphabet {
+    // RFC 4648 section 4: A-Z a-z 0-9 + /
+    Standard,
+    // RFC 4648 section 5: A-Z a-z 0-9 - _, safe for URLs and filenames.
+    UrlSafe,
+  }
+
+  // How the decoder treats whitespace in the input.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum Whitespace {
+    // Whitespace is an invalid symbol like any other.
+    Strict,
+    // Spaces, tabs, CR and LF are skipped wherever they appear.
+    Lenient,
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct B64Config {
+    pub alphabet: Alphabet,
+    // Whether encoding emits '=' padding and decoding requires it. Unpadded
+    // configs reject '=' entirely.
+    pub padding: bool,
+    pub whitespace: Whitespace,
+    // Wrap encoded output after this many symbols. Never wraps when None.
+    pub line_width: Option<usize>,
+    // Inserted between wrapped lines.
+    pub line_ending: &'static [u8],
+  }
+
+  // Plain RFC 4648 base64. This is what Encodable and StringConstructible use.
+  pub const STANDARD: B64Config = B64Config {
+    alphabet: Alphabet::Standard,
+    padding: true,
+    whitespace: Whitespace::Strict,
+    line_width: None,
+    line_ending: b"\n",
+  };
+
+  pub const URL_SAFE: B64Config = B64Config {
+    alphabet: Alphabet::UrlSafe,
+    padding: true,
+    whitespace: Whitespace::Strict,
+    line_width: None,
+    line_ending: b"\n",
+  };
+
+  // The variant used by JWTs and most cookies.
+  pub const URL_SAFE_NO_PAD: B64Config = B64Config {
+    alphabet: Alphabet::UrlSafe,
+    padding: false,
+    whitespace: Whitespace::Strict,
+    line_width: None,
+    line_ending: b"\n",
+  };
+
+  // RFC 2045 MIME bodies: 76 columns and CRLF line endings.
+  pub const MIME: B64Config = B64Config {
+    alphabet: Alphabet::Standard,
+    padding: true,
+    whitespace: Whitespace::Lenient,
+    line_width: Some(76),
+    line_ending: b"\r\n",
+  };
+
+  // RFC 7468 PEM bodies: 64 columns and LF line endings.
+  pub const PEM: B64Config = B64Config {
+    alphabet: Alphabet::Standard,
+    padding: true,
+    whitespace: Whitespace::Lenient,
+    line_width: Some(64),
+    line_ending: b"\n",
+  };
+
+  impl Codec for B64Buffer {
+    const NAME: &'static str = "base64";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      encode(raw, &STANDARD)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      decode(encoded, &STANDARD)
+    }
+  }
+
+  impl B64Buffer {
+    pub fn try_from_encoded(buffer: &[u8]) -> Result<B64Buffer, DecodeError> {
+      B64Buffer::try_from_encoded_with_config(buffer, &STANDARD)
+    }
+
+    pub fn try_from_encoded_with_config(buffer: &[u8], config: &B64Config) -> Result<B64Buffer, DecodeError> {
+      Ok(B64Buffer { raw_bytes: decode(buffer, config)? })
+    }
+
+    pub fn encode_with_config(&self, config: &B64Config) -> Vec<u8> {
+      encode(&self.raw_bytes, config)
+    }
+  }
+
+  // ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/
+  const B64_CONVERSION_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+  // ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_
+  const B64_URL_CONVERSION_TABLE: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+  static B64_DECODE_TABLE: [u8; 256] = reverse_table(&[B64_CONVERSION_TABLE]);
+  static B64_URL_DECODE_TABLE: [u8; 256] = reverse_table(&[B64_URL_CONVERSION_TABLE]);
+
+  fn conversion_table(alphabet: Alphabet) -> &'static [u8; 64] {
+    match alphabet {
+      Alphabet::Standard => B64_CONVERSION_TABLE,
+      Alphabet::UrlSafe => B64_URL_CONVERSION_TABLE,
+    }
+  }
+
+  fn decode_table(alphabet: Alphabet) -> &'static [u8; 256] {
+    match alphabet {
+      Alphabet::Standard => &B64_DECODE_TABLE,
+      Alphabet::UrlSafe => &B64_URL_DECODE_TABLE,
+    }
+  }
+
+  fn is_whitespace(byte: u8) -> bool {
+    byte == b' ' || byte == b'\t' || byte == b'\r' || byte == b'\n'
+  }
+
+  // The number of bytes encoding `len` bytes produces, including padding and
+  // line endings.
+  pub fn encoded_len(len: usize, config: &B64Config) -> usize {
+    let symbols = if config.padding { len.div_ceil(3) * 4 } else { (len * 4).div_ceil(3) };
+    match config.line_width {
+      Some(width) if width > 0 && symbols > 0 => symbols + (symbols - 1) / width * config.line_ending.len(),
+      _ => symbols
+    }
+  }
+
+  // An upper bound on the number of bytes `len` encoded bytes decode to.
+  pub fn decoded_max_len(len: usize) -> usize {
+    len.div_ceil(4) * 3
+  }
+
+  // Encode `input` into the front of `output` and return the number of bytes
+  // written. Panics if output is shorter than encoded_len(input.len(), config).
+  pub fn encode_into(input: &[u8], output: &mut [u8], config: &B64Config) -> usize {
+    let len = encoded_len(input.len(), config);
+    assert!(output.len() >= len, "output too small to hold encoded base64");
+
+    let symbols = encode_symbols_into(input, output, conversion_table(config.alphabet), config.padding);
+    if let Some(width) = config.line_width {
+      if width > 0 {
+        spread_lines(&mut output[..len], symbols, width, config.line_ending);
+      }
+    }
+    len
+  }
+
+  // Decode `input` into the front of `output` and return the number of bytes
+  // written. Panics if output is shorter than decoded_max_len(input.len()).
+  pub fn decode_into(input: &[u8], output: &mut [u8], config: &B64Config) -> Result<usize, DecodeError> {
+    assert!(output.len() >= decoded_max_len(input.len()), "output too small to hold decoded base64");
+    let mut sink = SliceSink { buffer: output, len: 0 };
+    let mut state = GroupDecoder::new(config);
+    state.push_slice(0, input, &mut sink)?;
+    state.finish(input.len(), &mut sink)?;
+    Ok(sink.len)
+  }
+
+  // Take a utf8 base64 representation and convert to its binary value. The
+  // offset is only used to report where an invalid byte was found.
+  fn decode_b64_byte(byte: &u8, offset: usize, table: &[u8; 256]) -> Result<u8, DecodeError> {
+    match table[*byte as usize] {
+      INVALID_SYMBOL if *byte == 61 => Err(DecodeError::new(DecodeErrorKind::InvalidPadding, Some(*byte), offset)),
+      INVALID_SYMBOL => Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(*byte), offset)),
+      value => Ok(value)
+    }
+  }
+
+  // Take a binary buffer and translate it to its Base-64 encoded binary representation.
+  pub fn encode(buffer: &[u8], config: &B64Config) -> Vec<u8> {
+    let mut output = vec![0; encoded_len(buffer.len(), config)];
+    encode_into(buffer, &mut output, config);
+    output
+  }
+
+  // Write the unwrapped symbols for `input` to the front of `output`,
+  // returning how many were written.
+  fn encode_symbols_into(input: &[u8], output: &mut [u8], table: &[u8; 64], padding: bool) -> usize {
+    // Each binary byte is formed of 8 bits, which needs to be translated into
+    // groupings of 6 bits which can be represented in Base64. Thus, we chunk
+    // our input bytes into groups of 3 8-bit bytes. From these 24 bits we can
+    // create 4 6-bit Base-64 values. Two groups fit in a u64, so the bulk of
+    // the input is done 6 bytes at a time.
+    let mut inputs = input.chunks_exact(6);
+    let mut written = 0;
+    for src in &mut inputs {
+      let word = src.iter().fold(0u64, |word, byte| (word << 8) | u64::from(*byte));
+      let dst = &mut output[written..written + 8];
+      for (i, symbol) in dst.iter_mut().enumerate() {
+        *symbol = table[((word >> (42 - i * 6)) & 0b111111) as usize];
+      }
+      written += 8;
+    }
+
+    for c in inputs.remainder().chunks(3) {
+      match *c {
+        [x] => {
+          output[written] = table[(x >> 2) as usize];
+          output[written + 1] = table[((x & 0b11) << 4) as usize];
+          written += 2;
+          if padding {
+            output[written] = 61; // =
+            output[written + 1] = 61; // =
+            written += 2;
+          }
+        },
+        [x, y] => {
+          output[written] = table[(x >> 2) as usize];
+          output[written + 1] = table[(((x & 0b11) << 4) | (y >> 4)) as usize];
+          output[written + 2] = table[((y & 0b1111) << 2) as usize];
+          written += 3;
+          if padding {
+            output[written] = 61; // =
+            written += 1;
+          }
+        },
+        [x, y, z] => {
+          output[written] = table[(x >> 2) as usize];
+          output[written + 1] = table[(((x & 0b11) << 4) | (y >> 4)) as usize];
+          output[written + 2] = table[(((y & 0b1111) << 2) | (z >> 6)) as usize];
+          output[written + 3] = table[(z & 0b111111) as usize];
+          written += 4;
+        },
+        _ => {}
+      }
+    }
+    written
+  }
+
+  // Insert line endings into the first `symbols` bytes of `output` in place,
+  // which must have room for them. Lines are moved back-to-front so nothing is
+  // overwritten before it has been moved.
+  fn spread_lines(output: &mut [u8], symbols: usize, width: usize, line_ending: &[u8]) {
+    let lines = symbols.div_ceil(width);
+    for line in (1..lines).rev() {
+      let start = line * width;
+      let end = (start + width).min(symbols);
+      let destination = line * (width + line_ending.len());
+      output.copy_within(start..end, destination);
+      output[destination - line_ending.len()..destination].copy_from_slice(line_ending);
+    }
+  }
+
+  // Take a Base-64 utf8 encoded buffer and decode it to its binary representation.
+  pub fn decode(buffer: &[u8], config: &B64Config) -> Result<Vec<u8>, DecodeError> {
+    let mut output: Vec<u8> = Vec::with_capacity(decoded_max_len(buffer.len()));
+    let mut state = GroupDecoder::new(config);
+    state.push_slice(0, buffer, &mut output)?;
+    state.finish(buffer.len(), &mut output)?;
+    Ok(output)
+  }
+
+  // Where the group decoder puts decoded bytes, so the same code can fill
+  // vectors and caller-provided slices.
+  trait Sink {
+    fn put(&mut self, bytes: &[u8]);
+  }
+
+  impl Sink for Vec<u8> {
+    fn put(&mut self, bytes: &[u8]) {
+      self.extend_from_slice(bytes);
+    }
+  }
+
+  struct SliceSink<'a> {
+    buffer: &'a mut [u8],
+    len: usize,
+  }
+
+  impl<'a> Sink for SliceSink<'a> {
+    fn put(&mut self, bytes: &[u8]) {
+      self.buffer[self.len..self.len + bytes.len()].copy_from_slice(bytes);
+      self.len += bytes.len();
+    }
+  }
+
+  // Decodes base64 incrementally so that whole buffers and streams share the
+  // same validation. Offsets are positions in the encoded input.
+  struct GroupDecoder {
+    config: B64Config,
+    table: &'static [u8; 256],
+    group: [(usize, u8); 4],
+    len: usize,
+    // Set once a padded group is decoded. Only whitespace may follow it.
+    padded: bool,
+  }
+
+  impl GroupDecoder {
+    fn new(config: &B64Config) -> GroupDecoder {
+      GroupDecoder { config: *config, table: decode_table(config.alphabet), group: [(0, 0); 4], len: 0, padded: false }
+    }
+
+    // Decode `input`, which starts at `offset` in the encoded stream.
+    fn push_slice<S: Sink>(&mut self, offset: usize, input: &[u8], sink: &mut S) -> Result<(), DecodeError> {
+      let mut i = 0;
+      while i < input.len() {
+        // Between groups, decode whole words at a time for as long as the
+        // input is clean. Anything unusual (padding, whitespace, bad symbols)
+        // drops down to the symbol-at-a-time path, which knows how to report
+        // it, and we come back here once it has finished a group.
+        if self.len == 0 && !self.padded {
+          if let Some(word) = input.get(i..i + 8).and_then(|s| self.decode_word(s)) {
+            sink.put(&word.to_be_bytes()[2..]);
+            i += 8;
+            continue;
+          }
+          if let Some(word) = input.get(i..i + 4).and_then(|s| self.decode_word(s)) {
+            sink.put(&word.to_be_bytes()[5..]);
+            i += 4;
+            continue;
+          }
+        }
+        self.push(offset + i, input[i], sink)?;
+        i += 1;
+      }
+      Ok(())
+    }
+
+    // Decode a run of symbols with no padding or invalid bytes into a word,
+    // or None if there's anything the fast path can't handle.
+    fn decode_word(&self, symbols: &[u8]) -> Option<u64> {
+      let mut word: u64 = 0;
+      let mut seen: u8 = 0;
+      for symbol in symbols {
+        let value = self.table[*symbol as usize];
+        seen |= value;
+        word = (word << 6) | u64::from(value & 0b111111);
+      }
+      if seen == INVALID_SYMBOL { None } else { Some(word) }
+    }
+
+    fn push<S: Sink>(&mut self, offset: usize, byte: u8, sink: &mut S) -> Result<(), DecodeError> {
+      if self.config.whitespace == Whitespace::Lenient && is_whitespace(byte) {
+        return Ok(());
+      }
+      if self.padded {
+        // Padding can only end the input.
+        return Err(DecodeError::new(DecodeErrorKind::TrailingData, Some(byte), offset));
+      }
+      if byte == 61 && !self.config.padding {
+        return Err(DecodeError::new(DecodeErrorKind::InvalidPadding, Some(byte), offset));
+      }
+      if byte != 61 && self.table[byte as usize] == INVALID_SYMBOL {
+        return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(byte), offset));
+      }
+
+      self.group[self.len] = (offset, byte);
+      self.len += 1;
+      if self.len == 4 {
+        self.decode_group(sink)?;
+        self.len = 0;
+      }
+      Ok(())
+    }
+
+    // Called with the length of the input once it's exhausted.
+    fn finish<S: Sink>(&mut self, offset: usize, sink: &mut S) -> Result<(), DecodeError> {
+      match self.len {
+        0 => Ok(()),
+        // Padded input always comes in whole groups, and a lone trailing
+        // symbol only carries 6 bits, which isn't a whole byte.
+        1 => Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, offset)),
+        _ if self.config.padding => Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, offset)),
+        _ => {
+          self.decode_group(sink)?;
+          self.len = 0;
+          Ok(())
+        }
+      }
+    }
+
+    // Decode the symbols collected so far, which may end in padding.
+    fn decode_group<S: Sink>(&mut self, sink: &mut S) -> Result<(), DecodeError> {
+      // Each b64 value encompasses 6 bits. Thus a group of 4 b64-encoded chars
+      // maps to three decoded values.
+      let table = self.table;
+      let c = &self.group[..self.len];
+
+      let x = decode_b64_byte(&c[0].1, c[0].0, table)?;
+      let y = decode_b64_byte(&c[1].1, c[1].0, table)?;
+      if c.len() == 2 {
+        sink.put(&[(x << 2) | (y >> 4)]);
+        return Ok(());
+      }
+
+      if c[2].1 == 61 {
+        // "xy==" is the only group that can have padding in the third slot.
+        if c.len() == 4 && c[3].1 == 61 {
+          sink.put(&[(x << 2) | (y >> 4)]);
+          self.padded = true;
+          return Ok(());
+        }
+        return Err(DecodeError::new(DecodeErrorKind::InvalidPadding, Some(61), c[2].0));
+      }
+      let z = decode_b64_byte(&c[2].1, c[2].0, table)?;
+      if c.len() == 3 || c[3].1 == 61 {
+        sink.put(&[(x << 2) | (y >> 4), ((y & 0b1111) << 4) | (z >> 2)]);
+        self.padded = c.len() == 4;
+        return Ok(());
+      }
+
+      let w = decode_b64_byte(&c[3].1, c[3].0, table)?;
+      sink.put(&[(x << 2) | (y >> 4), ((y & 0b1111) << 4) | (z >> 2), ((z & 0b11) << 6) | w]);
+      Ok(())
+    }
+  }
+
+  // Base64-encodes everything written to it and passes the result on to the
+  // wrapped writer. The last partial group can only be written once we know
+  // no more input is coming, so call finish() when done. Dropping the encoder
+  // also finishes it, but any error is lost.
+  #[cfg(feature = "std")]
+  pub struct Encoder<W: Write> {
+    inner: Option<W>,
+    config: B64Config,
+    // Bytes waiting for the rest of their 3-byte group.
+    leftover: [u8; 3],
+    leftover_len: usize,
+    // Symbols written to the current line, for wrapping.
+    column: usize,
+  }
+
+  #[cfg(feature = "std")]
+  impl<W: Write> Encoder<W> {
+    pub fn new(inner: W, config: &B64Config) -> Encoder<W> {
+      Encoder { inner: Some(inner), config: *config, leftover: [0; 3], leftover_len: 0, column: 0 }
+    }
+
+    // Write out the final group and hand back the wrapped writer.
+    pub fn finish(mut self) -> io::Result<W> {
+      self.write_final_group()?;
+      Ok(self.inner.take().expect("encoder is only finished once"))
+    }
+
+    fn write_final_group(&mut self) -> io::Result<()> {
+      if self.leftover_len > 0 {
+        let leftover = self.leftover;
+        let mut symbols = [0; 4];
+        let len = encode_into(&leftover[..self.leftover_len], &mut symbols, &self.unwrapped_config());
+        self.leftover_len = 0;
+        self.write_symbols(&symbols[..len])?;
+      }
+      Ok(())
+    }
+
+    // Wrapping is done as symbols are written, since lines span writes.
+    fn unwrapped_config(&self) -> B64Config {
+      B64Config { line_width: None, ..self.config }
+    }
+
+    fn write_symbols(&mut self, symbols: &[u8]) -> io::Result<()> {
+      let inner = self.inner.as_mut().expect("encoder used after finish");
+      let width = match self.config.line_width {
+        Some(width) if width > 0 => width,
+        _ => return inner.write_all(symbols)
+      };
+
+      let mut symbols = symbols;
+      while !symbols.is_empty() {
+        if self.column == width {
+          inner.write_all(self.config.line_ending)?;
+          self.column = 0;
+        }
+        let count = (width - self.column).min(symbols.len());
+        inner.write_all(&symbols[..count])?;
+        self.column += count;
+        symbols = &symbols[count..];
+      }
+      Ok(())
+    }
+  }
+
+  #[cfg(feature = "std")]
+  impl<W: Write> Write for Encoder<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+      let config = self.unwrapped_config();
+      let mut symbols = [0; 4096];
+      let mut input = buf;
+
+      // Top up a partial group left over from the last write first.
+      if self.leftover_len > 0 {
+        let count = (3 - self.leftover_len).min(input.len());
+        self.leftover[self.leftover_len..self.leftover_len + count].copy_from_slice(&input[..count]);
+        self.leftover_len += count;
+        input = &input[count..];
+        if self.leftover_len < 3 {
+          return Ok(buf.len());
+        }
+        let leftover = self.leftover;
+        self.leftover_len = 0;
+        let len = encode_into(&leftover, &mut symbols, &config);
+        self.write_symbols(&symbols[..len])?;
+      }
+
+      let whole = input.len() / 3 * 3;
+      for chunk in input[..whole].chunks(symbols.len() / 4 * 3) {
+        let len = encode_into(chunk, &mut symbols, &config);
+        self.write_symbols(&symbols[..len])?;
+      }
+
+      let rest = &input[whole..];
+      self.leftover[..rest.len()].copy_from_slice(rest);
+      self.leftover_len = rest.len();
+      Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      self.inner.as_mut().expect("encoder used after finish").flush()
+    }
+  }
+
+  #[cfg(feature = "std")]
+  impl<W: Write> Drop for Encoder<W> {
+    fn drop(&mut self) {
+      if self.inner.is_some() {
+        let _ = self.write_final_group();
+      }
+    }
+  }
+
+  // Reads base64 from the wrapped reader and yields the decoded bytes. Invalid
+  // input surfaces as an io::ErrorKind::InvalidData error wrapping a
+  // DecodeError, with offsets counted from the start of the stream.
+  // Everything before the bad symbol is yielded first, and every read after
+  // the error repeats it.
+  #[cfg(feature = "std")]
+  pub struct Decoder<R: Read> {
+    inner: R,
+    state: GroupDecoder,
+    // Offset in the encoded stream of the next byte read from inner.
+    offset: usize,
+    input: Vec<u8>,
+    // Decoded bytes not yet handed out, starting at `position`.
+    output: Vec<u8>,
+    position: usize,
+    done: bool,
+    // Bad input, reported once the bytes decoded ahead of it are handed out.
+    error: Option<DecodeError>,
+  }
+
+  #[cfg(feature = "std")]
+  impl<R: Read> Decoder<R> {
+    pub fn new(inner: R, config: &B64Config) -> Decoder<R> {
+      Decoder {
+        inner,
+        state: GroupDecoder::new(config),
+        offset: 0,
+        input: vec![0; 4096],
+        output: Vec::new(),
+        position: 0,
+        done: false,
+        error: None,
+      }
+    }
+
+    pub fn into_inner(self) -> R {
+      self.inner
+    }
+  }
+
+  #[cfg(feature = "std")]
+  impl<R: Read> Read for Decoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+      while self.position == self.output.len() {
+        if let Some(error) = self.error {
+          return Err(error.into());
+        }
+        if self.done || buf.is_empty() {
+          return Ok(0);
+        }
+        self.output.clear();
+        self.position = 0;
+
+        let count = self.inner.read(&mut self.input)?;
+        let result = if count == 0 {
+          self.done = true;
+          self.state.finish(self.offset, &mut self.output)
+        } else {
+          let result = self.state.push_slice(self.offset, &self.input[..count], &mut self.output);
+          self.offset += count;
+          result
+        };
+        // The group decoder has already put out everything before the bad
+        // symbol, so that goes first and the error waits until it's drained.
+        if let Err(error) = result {
+          self.error = Some(error);
+        }
+      }
+
+      let count = buf.len().min(self.output.len() - self.position);
+      buf[..count].copy_from_slice(&self.output[self.position..self.position + count]);
+      self.position += count;
+      Ok(count)
+    }
+  }
+}
+
+pub mod b32 {
+  use std::vec::Vec;
+  use radix;
+  use radix::Radix;
+  use Codec;
+  use DecodeError;
+  use DecodeErrorKind;
+
+  #[derive(Debug)]
+  pub struct B32Buffer {
+    // Internal representation is a decoded binary buffer.
+    pub raw_bytes: Vec<u8>
+  }
+
+  // Which 32 symbols encode the 5-bit values.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum Alphabet {
+    // RFC 4648 section 6: A-Z 2-7. Used by TOTP secrets.
+    Standard,
+    // RFC 4648 section 7: 0-9 A-V, which sorts the same as the bytes it encodes.
+    Hex,
+    // Douglas Crockford's base32: 0-9 and the letters other than I, L, O and
+    // U. Decoding reads O as 0, I and L as 1, and ignores hyphens.
+    Crockford,
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct B32Config {
+    pub alphabet: Alphabet,
+    // Whether encoding pads to a multiple of 8 symbols with '=' and decoding
+    // requires it. Unpadded configs reject '=' entirely.
+    pub padding: bool,
+  }
+
+  // Plain RFC 4648 base32. This is what Encodable and StringConstructible use.
+  pub const STANDARD: B32Config = B32Config { alphabet: Alphabet::Standard, padding: true };
+  // The form TOTP secrets are usually handed out in.
+  pub const STANDARD_NO_PAD: B32Config = B32Config { alphabet: Alphabet::Standard, padding: false };
+  pub const HEX: B32Config = B32Config { alphabet: Alphabet::Hex, padding: true };
+  pub const HEX_NO_PAD: B32Config = B32Config { alphabet: Alphabet::Hex, padding: false };
+  pub const CROCKFORD: B32Config = B32Config { alphabet: Alphabet::Crockford, padding: false };
+
+  impl Codec for B32Buffer {
+    const NAME: &'static str = "base32";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      encode(raw, &STANDARD)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      decode(encoded, &STANDARD)
+    }
+  }
+
+  impl B32Buffer {
+    // Decoding is case-insensitive for every alphabet.
+    pub fn try_from_encoded(buffer: &[u8]) -> Result<B32Buffer, DecodeError> {
+      B32Buffer::try_from_encoded_with_config(buffer, &STANDARD)
+    }
+
+    pub fn try_from_encoded_with_config(buffer: &[u8], config: &B32Config) -> Result<B32Buffer, DecodeError> {
+      Ok(B32Buffer { raw_bytes: decode(buffer, config)? })
+    }
+
+    pub fn encode_with_config(&self, config: &B32Config) -> Vec<u8> {
+      encode(&self.raw_bytes, config)
+    }
+  }
+
+  // The radix for each alphabet, with and without padding.
+  static STANDARD_NO_PAD_RADIX: Radix = radix::BASE32.without_padding();
+  static HEX_NO_PAD_RADIX: Radix = radix::BASE32_HEX.without_padding();
+  static CROCKFORD_PADDED_RADIX: Radix = radix::BASE32_CROCKFORD.with_padding(b'=');
+
+  fn radix(config: &B32Config) -> &'static Radix<'static> {
+    match (config.alphabet, config.padding) {
+      (Alphabet::Standard, true) => &radix::BASE32,
+      (Alphabet::Standard, false) => &STANDARD_NO_PAD_RADIX,
+      (Alphabet::Hex, true) => &radix::BASE32_HEX,
+      (Alphabet::Hex, false) => &HEX_NO_PAD_RADIX,
+      (Alphabet::Crockford, true) => &CROCKFORD_PADDED_RADIX,
+      (Alphabet::Crockford, false) => &radix::BASE32_CROCKFORD,
+    }
+  }
+
+  // The number of bytes encoding `len` bytes produces, including padding.
+  pub fn encoded_len(len: usize, config: &B32Config) -> usize {
+    radix(config).encoded_len(len)
+  }
+
+  // Take a binary buffer and translate it to its Base-32 encoded representation.
+  pub fn encode(buffer: &[u8], config: &B32Config) -> Vec<u8> {
+    radix::encode(buffer, radix(config))
+  }
+
+  // Take a Base-32 utf8 encoded buffer and decode it to its binary
+  // representation. Unpadded configs report a stray '=' as bad padding
+  // rather than an unknown symbol.
+  pub fn decode(buffer: &[u8], config: &B32Config) -> Result<Vec<u8>, DecodeError> {
+    radix::decode(buffer, radix(config)).map_err(|error| match error.byte {
+      Some(b'=') if error.kind == DecodeErrorKind::InvalidSymbol =>
+        DecodeError::new(DecodeErrorKind::InvalidPadding, error.byte, error.offset),
+      _ => error,
+    })
+  }
+}
+
+pub mod a85 {
+  use std::vec::Vec;
+  use reverse_table;
+  use Codec;
+  use DecodeError;
+  use DecodeErrorKind;
+  use INVALID_SYMBOL;
+
+  // Adobe Ascii85, as found in PDF and PostScript streams. Encoding wraps the
+  // output in "<~" and "~>" and writes all-zero groups as 'z'. Decoding
+  // accepts the data with or without the leading "<~", stops at "~>", and
+  // ignores whitespace.
+  #[derive(Debug)]
+  pub struct A85Buffer {
+    // Internal representation is a decoded binary buffer.
+    pub raw_bytes: Vec<u8>
+  }
+
+  // ZeroMQ's Z85 (RFC 32), used for CURVE keys in ZMTP. Its alphabet avoids
+  // quotes and backslashes so it can be pasted into source code. Like libzmq,
+  // it only takes whole groups: 4 bytes to encode and 5 symbols to decode.
+  #[derive(Debug)]
+  pub struct Z85Buffer {
+    // Internal representation is a decoded binary buffer.
+    pub raw_bytes: Vec<u8>
+  }
+
+  impl Codec for A85Buffer {
+    const NAME: &'static str = "ascii85";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      encode(raw)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      decode(encoded)
+    }
+  }
+
+  impl A85Buffer {
+    pub fn try_from_encoded(buffer: &[u8]) -> Result<A85Buffer, DecodeError> {
+      Ok(A85Buffer { raw_bytes: decode(buffer)? })
+    }
+  }
+
+  impl Codec for Z85Buffer {
+    const NAME: &'static str = "z85";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      encode_z85(raw)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      decode_z85(encoded)
+    }
+  }
+
+  impl Z85Buffer {
+    pub fn try_from_encoded(buffer: &[u8]) -> Result<Z85Buffer, DecodeError> {
+      Ok(Z85Buffer { raw_bytes: decode_z85(buffer)? })
+    }
+  }
+
+  // The 85 characters from '!' to 'u'.
+  const A85_CONVERSION_TABLE: &[u8; 85] =
+    b"!\"#$%&'()*+,-./0123456789:;<=>?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[\\]^_`abcdefghijklmnopqrstu";
+  // 0-9 a-z A-Z .-:+=^!/*?&<>()[]{}@%$#
+  const Z85_CONVERSION_TABLE: &[u8; 85] =
+    b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+  static A85_DECODE_TABLE: [u8; 256] = reverse_table(&[A85_CONVERSION_TABLE]);
+  static Z85_DECODE_TABLE: [u8; 256] = reverse_table(&[Z85_CONVERSION_TABLE]);
+
+  // The number of symbols encoding `len` bytes takes, before any delimiters
+  // or 'z' shortcuts.
+  pub fn encoded_len(len: usize) -> usize {
+    len / 4 * 5 + if len.is_multiple_of(4) { 0 } else { len % 4 + 1 }
+  }
+
+  fn is_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\r' | b'\n' | b'\x0c' | b'\0')
+  }
+
+  // Encode `buffer` as Ascii85 wrapped in "<~" and "~>".
+  pub fn encode(buffer: &[u8]) -> Vec<u8> {
+    let mut output: Vec<u8> = Vec::with_capacity(encoded_len(buffer.len()) + 4);
+    output.extend_from_slice(b"<~");
+    encode_base85(buffer, A85_CONVERSION_TABLE, true, &mut output);
+    output.extend_from_slice(b"~>");
+    output
+  }
+
+  // Take an Ascii85 utf8 buffer, strip its delimiters and decode it.
+  pub fn decode(buffer: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let start = if buffer.starts_with(b"<~") { 2 } else { 0 };
+    let mut end = buffer.len();
+
+    // "~>" marks the end of the data. Only whitespace may come after it.
+    if let Some(position) = buffer[start..].windows(2).position(|w| w == b"~>") {
+      end = start + position;
+      if let Some(i) = (end + 2..buffer.len()).find(|&i| !is_whitespace(buffer[i])) {
+        return Err(DecodeError::new(DecodeErrorKind::TrailingData, Some(buffer[i]), i));
+      }
+    }
+
+    let mut output: Vec<u8> = Vec::with_capacity((end - start) / 5 * 4 + 3);
+    decode_base85(&buffer[start..end], start, &A85_DECODE_TABLE, true, &mut output)?;
+    Ok(output)
+  }
+
+  // Panics unless `buffer` is a multiple of 4 bytes, as the spec requires.
+  pub fn encode_z85(buffer: &[u8]) -> Vec<u8> {
+    assert!(buffer.len().is_multiple_of(4), "z85 input must be a multiple of 4 bytes");
+    let mut output: Vec<u8> = Vec::with_capacity(encoded_len(buffer.len()));
+    encode_base85(buffer, Z85_CONVERSION_TABLE, false, &mut output);
+    output
+  }
+
+  pub fn decode_z85(buffer: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut output: Vec<u8> = Vec::with_capacity(buffer.len() / 5 * 4 + 3);
+    decode_base85(buffer, 0, &Z85_DECODE_TABLE, false, &mut output)?;
+    // Bad symbols are reported first; a valid but short final group is
+    // still rejected, since Z85 has no partial groups.
+    if !buffer.len().is_multiple_of(5) {
+      return Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, buffer.len()));
+    }
+    Ok(output)
+  }
+
+  // Take a binary buffer and append its base-85 representation to output.
+  fn encode_base85(buffer: &[u8], table: &[u8; 85], adobe: bool, output: &mut Vec<u8>) {
+    // 4 bytes make a 32 bit number, which takes 5 base-85 digits (85^5 is a
+    // little over 2^32). A short final group is padded with zeros, encoded,
+    // and then cut down to one more digit than it had bytes.
+    for c in buffer.chunks(4) {
+      if adobe && c == [0, 0, 0, 0] {
+        output.push(b'z');
+        continue;
+      }
+
+      let mut value = c.iter().fold(0u32, |value, byte| (value << 8) | u32::from(*byte)) << (8 * (4 - c.len()));
+      let mut digits = [0u8; 5];
+      for digit in digits.iter_mut().rev() {
+        *digit = table[(value % 85) as usize];
+        value /= 85;
+      }
+      output.extend_from_slice(&digits[..c.len() + 1]);
+    }
+  }
+
+  // Decode base-85 symbols onto output. `offset` is where buffer starts in
+  // the caller's input, for error reporting. Adobe mode allows whitespace and
+  // the 'z' shortcut.
+  fn decode_base85(buffer: &[u8], offset: usize, table: &[u8; 256], adobe: bool, output: &mut Vec<u8>) -> Result<(), DecodeError> {
+    let mut value: u64 = 0;
+    let mut digits = 0;
+
+    for (i, byte) in buffer.iter().enumerate() {
+      if adobe && is_whitespace(*byte) {
+        continue;
+      }
+      if adobe && *byte == b'z' {
+        // 'z' stands in for a whole group, so it can't appear inside one.
+        if digits != 0 {
+          return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(*byte), offset + i));
+        }
+        output.extend_from_slice(&[0, 0, 0, 0]);
+        continue;
+      }
+
+      let digit = table[*byte as usize];
+      if digit == INVALID_SYMBOL {
+        return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(*byte), offset + i));
+      }
+      value = value * 85 + u64::from(digit);
+      digits += 1;
+      if digits == 5 {
+        if value > u64::from(u32::MAX) {
+          return Err(DecodeError::new(DecodeErrorKind::Overflow, Some(*byte), offset + i));
+        }
+        output.extend_from_slice(&(value as u32).to_be_bytes());
+        value = 0;
+        digits = 0;
+      }
+    }
+
+    match digits {
+      0 => Ok(()),
+      // A single digit can't carry a whole byte.
+      1 => Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, offset + buffer.len())),
+      _ => {
+        // Pad with the highest digit so truncating the encoded group rounds
+        // back to the bytes it came from.
+        for _ in digits..5 {
+          value = value * 85 + 84;
+        }
+        if value > u64::from(u32::MAX) {
+          return Err(DecodeError::new(DecodeErrorKind::Overflow, None, offset + buffer.len()));
+        }
+        output.extend_from_slice(&(value as u32).to_be_bytes()[..digits - 1]);
+        Ok(())
+      }
+    }
+  }
+}
+
+pub mod b58 {
+  use std::vec::Vec;
+  use reverse_table;
+  use Codec;
+  use DecodeError;
+  use DecodeErrorKind;
+  use INVALID_SYMBOL;
+
+  #[derive(Debug)]
+  pub struct B58Buffer {
+    // Internal representation is a decoded binary buffer.
+    pub raw_bytes: Vec<u8>
+  }
+
+  // Which 58 symbols encode the digits. All three leave out 0, O, I and l so
+  // that the output survives being read aloud or copied by hand.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum Alphabet {
+    Bitcoin,
+    Flickr,
+    Ripple,
+  }
+
+  impl Codec for B58Buffer {
+    const NAME: &'static str = "base58";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      encode(raw, Alphabet::Bitcoin)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      decode(encoded, Alphabet::Bitcoin)
+    }
+  }
+
+  impl B58Buffer {
+    pub fn try_from_encoded(buffer: &[u8]) -> Result<B58Buffer, DecodeError> {
+      B58Buffer::try_from_encoded_with_alphabet(buffer, Alphabet::Bitcoin)
+    }
+
+    pub fn try_from_encoded_with_alphabet(buffer: &[u8], alphabet: Alphabet) -> Result<B58Buffer, DecodeError> {
+      Ok(B58Buffer { raw_bytes: decode(buffer, alphabet)? })
+    }
+
+    pub fn encode_with_alphabet(&self, alphabet: Alphabet) -> Vec<u8> {
+      encode(&self.raw_bytes, alphabet)
+    }
+
+    // Base58Check: the version byte and the buffer, followed by the first 4
+    // bytes of SHA-256(SHA-256(version || buffer)), all Base58 encoded. This
+    // is the format of Bitcoin addresses and WIF keys.
+    pub fn encode_check(&self, version: u8, alphabet: Alphabet) -> Vec<u8> {
+      let mut payload: Vec<u8> = Vec::with_capacity(self.raw_bytes.len() + 5);
+      payload.push(version);
+      payload.extend_from_slice(&self.raw_bytes);
+      let checksum = checksum(&payload);
+      payload.extend_from_slice(&checksum);
+      encode(&payload, alphabet)
+    }
+
+    // Decode Base58Check and verify its checksum, returning the version byte
+    // alongside the payload. A mismatch is an InvalidChecksum error.
+    pub fn try_from_check_encoded(buffer: &[u8], alphabet: Alphabet) -> Result<(u8, B58Buffer), DecodeError> {
+      let mut decoded = decode(buffer, alphabet)?;
+      if decoded.len() < 5 {
+        // Too short to hold a version byte and checksum.
+        return Err(DecodeError::new(DecodeErrorKind::InvalidChecksum, None, buffer.len()));
+      }
+      let split = decoded.len() - 4;
+      if checksum(&decoded[..split]) != decoded[split..] {
+        return Err(DecodeError::new(DecodeErrorKind::InvalidChecksum, None, buffer.len()));
+      }
+      decoded.truncate(split);
+      let version = decoded.remove(0);
+      Ok((version, B58Buffer { raw_bytes: decoded }))
+    }
+  }
+
+  // 123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz
+  const B58_BITCOIN_CONVERSION_TABLE: &[u8; 58] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+  // 123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ
+  const B58_FLICKR_CONVERSION_TABLE: &[u8; 58] = b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+  // rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz
+  const B58_RIPPLE_CONVERSION_TABLE: &[u8; 58] = b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz";
+
+  static B58_BITCOIN_DECODE_TABLE: [u8; 256] = reverse_table(&[B58_BITCOIN_CONVERSION_TABLE]);
+  static B58_FLICKR_DECODE_TABLE: [u8; 256] = reverse_table(&[B58_FLICKR_CONVERSION_TABLE]);
+  static B58_RIPPLE_DECODE_TABLE: [u8; 256] = reverse_table(&[B58_RIPPLE_CONVERSION_TABLE]);
+
+  fn conversion_table(alphabet: Alphabet) -> &'static [u8; 58] {
+    match alphabet {
+      Alphabet::Bitcoin => B58_BITCOIN_CONVERSION_TABLE,
+      Alphabet::Flickr => B58_FLICKR_CONVERSION_TABLE,
+      Alphabet::Ripple => B58_RIPPLE_CONVERSION_TABLE,
+    }
+  }
+
+  fn decode_table(alphabet: Alphabet) -> &'static [u8; 256] {
+    match alphabet {
+      Alphabet::Bitcoin => &B58_BITCOIN_DECODE_TABLE,
+      Alphabet::Flickr => &B58_FLICKR_DECODE_TABLE,
+      Alphabet::Ripple => &B58_RIPPLE_DECODE_TABLE,
+    }
+  }
+
+  // Take a binary buffer and translate it to its Base-58 representation.
+  pub fn encode(buffer: &[u8], alphabet: Alphabet) -> Vec<u8> {
+    // 58 isn't a power of two, so symbols don't line up with bits and we
+    // can't slice the input up like hex or base64. Instead we treat the whole
+    // buffer as one big-endian number and convert it to base 58 by long
+    // division, keeping the result as little-endian base-58 digits. Leading
+    // zero bytes would vanish from the number, so each one is written as the
+    // zero symbol instead.
+    let table = conversion_table(alphabet);
+    let zeros = buffer.iter().take_while(|&&b| b == 0).count();
+
+    // log(256) / log(58) is about 1.37, so this is always enough digits.
+    let mut digits: Vec<u8> = Vec::with_capacity((buffer.len() - zeros) * 138 / 100 + 1);
+    for byte in &buffer[zeros..] {
+      // Multiply what we have so far by 256 and add the next byte.
+      let mut carry = u32::from(*byte);
+      for digit in digits.iter_mut() {
+        carry += u32::from(*digit) << 8;
+        *digit = (carry % 58) as u8;
+        carry /= 58;
+      }
+      while carry > 0 {
+        digits.push((carry % 58) as u8);
+        carry /= 58;
+      }
+    }
+
+    let mut output: Vec<u8> = Vec::with_capacity(zeros + digits.len());
+    output.resize(zeros, table[0]);
+    output.extend(digits.iter().rev().map(|d| table[*d as usize]));
+    output
+  }
+
+  // Take a Base-58 utf8 encoded buffer and decode it to its binary representation.
+  pub fn decode(buffer: &[u8], alphabet: Alphabet) -> Result<Vec<u8>, DecodeError> {
+    // The reverse of encoding: accumulate the number into little-endian bytes,
+    // multiplying by 58 for each symbol.
+    let table = decode_table(alphabet);
+    let zero = conversion_table(alphabet)[0];
+    let zeros = buffer.iter().take_while(|&&b| b == zero).count();
+
+    let mut bytes: Vec<u8> = Vec::with_capacity((buffer.len() - zeros) * 733 / 1000 + 1);
+    for (i, symbol) in buffer.iter().enumerate().skip(zeros) {
+      let value = table[*symbol as usize];
+      if value == INVALID_SYMBOL {
+        return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(*symbol), i));
+      }
+      let mut carry = u32::from(value);
+      for byte in bytes.iter_mut() {
+        carry += u32::from(*byte) * 58;
+        *byte = carry as u8;
+        carry >>= 8;
+      }
+      while carry > 0 {
+        bytes.push(carry as u8);
+        carry >>= 8;
+      }
+    }
+
+    let mut output: Vec<u8> = vec![0; zeros];
+    output.extend(bytes.iter().rev());
+    Ok(output)
+  }
+
+  // The first 4 bytes of SHA-256(SHA-256(payload)).
+  fn checksum(payload: &[u8]) -> [u8; 4] {
+    let hash = sha256(&sha256(payload));
+    [hash[0], hash[1], hash[2], hash[3]]
+  }
+
+  // Round constants for SHA-256: the first 32 bits of the fractional parts of
+  // the cube roots of the first 64 primes.
+  const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+  ];
+
+  // FIPS 180-4 SHA-256. Base58Check is the only thing that needs a hash, so
+  // this is a plain implementation rather than a general digest API.
+  fn sha256(data: &[u8]) -> [u8; 32] {
+    let mut state: [u32; 8] = [
+      0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab, 0x5be0cd19,
+    ];
+
+    // Pad with a 1 bit, zeros, and the message length in bits so the total
+    // is a multiple of 64 bytes.
+    let mut message = data.to_vec();
+    message.push(0x80);
+    while message.len() % 64 != 56 {
+      message.push(0);
+    }
+    message.extend_from_slice(&((data.len() as u64) * 8).to_be_bytes());
+
+    for block in message.chunks(64) {
+      let mut w = [0u32; 64];
+      for (i, word) in block.chunks(4).enumerate() {
+        w[i] = u32::from_be_bytes([word[0], word[1], word[2], word[3]]);
+      }
+      for i in 16..64 {
+        let s0 = w[i - 15].rotate_right(7) ^ w[i - 15].rotate_right(18) ^ (w[i - 15] >> 3);
+        let s1 = w[i - 2].rotate_right(17) ^ w[i - 2].rotate_right(19) ^ (w[i - 2] >> 10);
+        w[i] = w[i - 16].wrapping_add(s0).wrapping_add(w[i - 7]).wrapping_add(s1);
+      }
+
+      let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = state;
+      for i in 0..64 {
+        let s1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choice = (e & f) ^ (!e & g);
+        let t1 = h.wrapping_add(s1).wrapping_add(choice).wrapping_add(SHA256_K[i]).wrapping_add(w[i]);
+        let s0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let t2 = s0.wrapping_add(majority);
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(t1);
+        d = c;
+        c = b;
+        b = a;
+        a = t1.wrapping_add(t2);
+      }
+
+      for (s, v) in state.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+        *s = s.wrapping_add(*v);
+      }
+    }
+
+    let mut output = [0u8; 32];
+    for (chunk, word) in output.chunks_mut(4).zip(state.iter()) {
+      chunk.copy_from_slice(&word.to_be_bytes());
+    }
+    output
+  }
+}
+
+pub mod url {
+  use std::fmt;
+  use std::mem;
+  use std::string::String;
+  use std::vec::Vec;
+  use hex::HEX_DECODE_TABLE;
+  use hex::HEX_PAIR_TABLE;
+  use hex::HEX_UPPER_PAIR_TABLE;
+  use Codec;
+  use DecodeError;
+  use DecodeErrorKind;
+  use INVALID_SYMBOL;
+
+  #[derive(Debug)]
+  pub struct PercentBuffer {
+    // Internal representation is a decoded binary buffer.
+    pub raw_bytes: Vec<u8>
+  }
+
+  // A set of ASCII bytes. Bytes outside ASCII are never members.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct AsciiSet {
+    bits: u128,
+  }
+
+  impl AsciiSet {
+    pub const EMPTY: AsciiSet = AsciiSet { bits: 0 };
+
+    pub const fn add(self, byte: u8) -> AsciiSet {
+      assert!(byte < 128, "only ascii bytes can be in an AsciiSet");
+      AsciiSet { bits: self.bits | 1 << byte }
+    }
+
+    pub const fn add_all(self, bytes: &[u8]) -> AsciiSet {
+      let mut set = self;
+      let mut i = 0;
+      while i < bytes.len() {
+        set = set.add(bytes[i]);
+        i += 1;
+      }
+      set
+    }
+
+    pub const fn union(self, other: AsciiSet) -> AsciiSet {
+      AsciiSet { bits: self.bits | other.bits }
+    }
+
+    pub const fn contains(&self, byte: u8) -> bool {
+      byte < 128 && self.bits & 1 << byte != 0
+    }
+  }
+
+  // RFC 3986 section 2.2.
+  pub const GEN_DELIMS: AsciiSet = AsciiSet::EMPTY.add_all(b":/?#[]@");
+  pub const SUB_DELIMS: AsciiSet = AsciiSet::EMPTY.add_all(b"!$&'()*+,;=");
+  pub const RESERVED: AsciiSet = GEN_DELIMS.union(SUB_DELIMS);
+  // The printable characters that appear nowhere in URL syntax, so are
+  // always unsafe to leave bare.
+  pub const UNSAFE: AsciiSet = AsciiSet::EMPTY.add_all(b"\"<>\\^`{|}");
+
+  // Which bytes percent-encoding escapes. Control characters, space, '%'
+  // and anything outside ASCII are always escaped; `escape` adds to those.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct PercentConfig {
+    pub escape: AsciiSet,
+    // Encode space as '+' rather than "%20", and decode '+' as a space. A
+    // literal '+' is then always escaped.
+    pub space_as_plus: bool,
+    // RFC 3986 prefers uppercase digits in escapes; decoding takes either.
+    pub uppercase: bool,
+  }
+
+  // Everything but the RFC 3986 unreserved characters A-Z a-z 0-9 - . _ ~,
+  // so the result is safe anywhere in a URL. This is what Encodable and
+  // StringConstructible use.
+  pub const COMPONENT: PercentConfig =
+    PercentConfig { escape: RESERVED.union(UNSAFE), space_as_plus: false, uppercase: true };
+  // A path, where '/' and the other characters allowed in segments stay bare.
+  pub const PATH: PercentConfig =
+    PercentConfig { escape: UNSAFE.add_all(b"?#[]"), space_as_plus: false, uppercase: true };
+  // application/x-www-form-urlencoded, as HTML forms submit it.
+  pub const FORM: PercentConfig =
+    PercentConfig { escape: RESERVED.union(UNSAFE).add(b'~'), space_as_plus: true, uppercase: true };
+
+  impl Codec for PercentBuffer {
+    const NAME: &'static str = "percent-encoded";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      encode(raw, &COMPONENT)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      decode(encoded, &COMPONENT)
+    }
+  }
+
+  impl PercentBuffer {
+    // Bytes that should have been escaped but weren't are taken as they are,
+    // as browsers do. Only a malformed escape is an error.
+    pub fn try_from_encoded(buffer: &[u8]) -> Result<PercentBuffer, DecodeError> {
+      PercentBuffer::try_from_encoded_with_config(buffer, &COMPONENT)
+    }
+
+    pub fn try_from_encoded_with_config(buffer: &[u8], config: &PercentConfig) -> Result<PercentBuffer, DecodeError> {
+      Ok(PercentBuffer { raw_bytes: decode(buffer, config)? })
+    }
+
+    pub fn encode_with_config(&self, config: &PercentConfig) -> Vec<u8> {
+      encode(&self.raw_bytes, config)
+    }
+  }
+
+  fn needs_escape(byte: u8, config: &PercentConfig) -> bool {
+    !(0x21..0x7f).contains(&byte) || byte == b'%' || (config.space_as_plus && byte == b'+') || config.escape.contains(byte)
+  }
+
+  pub fn encode(buffer: &[u8], config: &PercentConfig) -> Vec<u8> {
+    let table = if config.uppercase { &HEX_UPPER_PAIR_TABLE } else { &HEX_PAIR_TABLE };
+    let mut output = Vec::with_capacity(buffer.len());
+    for &byte in buffer {
+      if byte == b' ' && config.space_as_plus {
+        output.push(b'+');
+      } else if needs_escape(byte, config) {
+        output.push(b'%');
+        output.extend_from_slice(&table[byte as usize]);
+      } else {
+        output.push(byte);
+      }
+    }
+    output
+  }
+
+  // A '%' without two hex digits after it is reported against the '%' when
+  // the input ends too soon, and against the first bad digit otherwise.
+  pub fn decode(buffer: &[u8], config: &PercentConfig) -> Result<Vec<u8>, DecodeError> {
+    let mut output = Vec::with_capacity(buffer.len());
+    let mut i = 0;
+    while i < buffer.len() {
+      match buffer[i] {
+        b'%' => {
+          if i + 2 >= buffer.len() {
+            return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'%'), i));
+          }
+          let mut value = 0;
+          for offset in i + 1..i + 3 {
+            let digit = HEX_DECODE_TABLE[buffer[offset] as usize];
+            if digit == INVALID_SYMBOL {
+              return Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(buffer[offset]), offset));
+            }
+            value = value << 4 | digit;
+          }
+          output.push(value);
+          i += 3;
+        }
+        b'+' if config.space_as_plus => {
+          output.push(b' ');
+          i += 1;
+        }
+        byte => {
+          output.push(byte);
+          i += 1;
+        }
+      }
+    }
+    Ok(output)
+  }
+
+  // How a CookieMap deals with the '&' and '=' that delimit it turning up
+  // inside a key or value.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum CookieMode {
+    // Take them as they come. Serializing writes them out bare, and parsing
+    // splits on every '&' and the first '=' of each pair, so a value can
+    // smuggle in extra fields. A pair without '=' gets an empty value and
+    // empty pairs are skipped.
+    Lenient,
+    // Refuse them. Inserting a key or value containing either fails, and so
+    // does parsing anything but non-empty "key=value" pairs.
+    Strict,
+    // Percent-encode them, along with '%', when serializing, and decode
+    // escapes when parsing, so any bytes round-trip.
+    Escape,
+  }
+
+  // The "k=v&k2=v2" structure used for encoded user profiles, kept in
+  // insertion order. Setting a key that's already present replaces its
+  // value in place, and when parsing, the last value given for a key wins.
+  #[derive(Debug, Clone, PartialEq, Eq)]
+  pub struct CookieMap {
+    mode: CookieMode,
+    pairs: Vec<(Vec<u8>, Vec<u8>)>,
+  }
+
+  // Only the delimiters need escaping in a cookie; '%' and non-ASCII come
+  // along with any PercentConfig.
+  const COOKIE: PercentConfig =
+    PercentConfig { escape: AsciiSet::EMPTY.add_all(b"&="), space_as_plus: false, uppercase: true };
+
+  impl CookieMap {
+    pub fn new(mode: CookieMode) -> CookieMap {
+      CookieMap { mode, pairs: Vec::new() }
+    }
+
+    pub fn parse(encoded: &[u8], mode: CookieMode) -> Result<CookieMap, DecodeError> {
+      let mut map = CookieMap::new(mode);
+      let mut start = 0;
+      for pair in encoded.split(|&b| b == b'&') {
+        let end = start + pair.len();
+        let (key, value) = match (pair.iter().position(|&b| b == b'='), mode) {
+          (_, CookieMode::Strict) => split_strict(encoded, start, end)?,
+          (None, _) if pair.is_empty() => {
+            start = end + 1;
+            continue;
+          }
+          (None, CookieMode::Escape) => (decode(pair, &COOKIE).map_err(|e| shift(e, start))?, Vec::new()),
+          (None, _) => (pair.to_vec(), Vec::new()),
+          (Some(equals), CookieMode::Lenient) => (pair[..equals].to_vec(), pair[equals + 1..].to_vec()),
+          (Some(equals), CookieMode::Escape) => (
+            decode(&pair[..equals], &COOKIE).map_err(|e| shift(e, start))?,
+            decode(&pair[equals + 1..], &COOKIE).map_err(|e| shift(e, start + equals + 1))?,
+          ),
+        };
+        map.set(key, value);
+        start = end + 1;
+      }
+      Ok(map)
+    }
+
+    pub fn mode(&self) -> CookieMode {
+      self.mode
+    }
+
+    pub fn get(&self, key: &[u8]) -> Option<&[u8]> {
+      self.pairs.iter().find(|(k, _)| k == key).map(|(_, v)| &v[..])
+    }
+
+    // Returns the value replaced, if any. In Strict mode the error points at
+    // the first '&' or '=', offset into the key, or into the value if the
+    // key is clean.
+    pub fn insert(&mut self, key: &[u8], value: &[u8]) -> Result<Option<Vec<u8>>, DecodeError> {
+      if self.mode == CookieMode::Strict {
+        check_strict(key)?;
+        check_strict(value)?;
+      }
+      Ok(self.set(key.to_vec(), value.to_vec()))
+    }
+
+    pub fn remove(&mut self, key: &[u8]) -> Option<Vec<u8>> {
+      let index = self.pairs.iter().position(|(k, _)| k == key)?;
+      Some(self.pairs.remove(index).1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+      self.pairs.iter().map(|(k, v)| (&k[..], &v[..]))
+    }
+
+    pub fn len(&self) -> usize {
+      self.pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+      self.pairs.is_empty()
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+      let mut output = Vec::new();
+      for (i, (key, value)) in self.pairs.iter().enumerate() {
+        if i > 0 {
+          output.push(b'&');
+        }
+        match self.mode {
+          CookieMode::Escape => {
+            output.extend_from_slice(&encode(key, &COOKIE));
+            output.push(b'=');
+            output.extend_from_slice(&encode(value, &COOKIE));
+          }
+          CookieMode::Lenient | CookieMode::Strict => {
+            output.extend_from_slice(key);
+            output.push(b'=');
+            output.extend_from_slice(value);
+          }
+        }
+      }
+      output
+    }
+
+    fn set(&mut self, key: Vec<u8>, value: Vec<u8>) -> Option<Vec<u8>> {
+      match self.pairs.iter_mut().find(|(k, _)| *k == key) {
+        Some((_, v)) => Some(mem::replace(v, value)),
+        None => {
+          self.pairs.push((key, value));
+          None
+        }
+      }
+    }
+  }
+
+  // Shows the serialized form, with any invalid UTF-8 replaced.
+  impl fmt::Display for CookieMap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      write!(f, "{}", String::from_utf8_lossy(&self.encode()))
+    }
+  }
+
+  fn check_strict(text: &[u8]) -> Result<(), DecodeError> {
+    match text.iter().position(|&b| b == b'&' || b == b'=') {
+      Some(offset) => Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(text[offset]), offset)),
+      None => Ok(()),
+    }
+  }
+
+  // Split encoded[start..end] into exactly one non-empty key and a value. A
+  // missing '=' is blamed on the '&' that ended the pair, or on nothing when
+  // the input ran out.
+  fn split_strict(encoded: &[u8], start: usize, end: usize) -> Result<(Vec<u8>, Vec<u8>), DecodeError> {
+    let pair = &encoded[start..end];
+    match pair.iter().position(|&b| b == b'=') {
+      None => Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, encoded.get(end).cloned(), end)),
+      Some(0) => Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'='), start)),
+      Some(equals) => match pair[equals + 1..].iter().position(|&b| b == b'=') {
+        Some(extra) => Err(DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'='), start + equals + 1 + extra)),
+        None => Ok((pair[..equals].to_vec(), pair[equals + 1..].to_vec())),
+      },
+    }
+  }
+
+  fn shift(error: DecodeError, by: usize) -> DecodeError {
+    DecodeError { offset: error.offset + by, ..error }
+  }
+}
+
+pub mod bytes {
+  use std::fmt;
+  use std::ops::BitXor;
+  use std::ops::BitXorAssign;
+  use std::ops::Deref;
+  use std::ops::DerefMut;
+  use std::ops::Index;
+  use std::ops::IndexMut;
+  use std::slice::SliceIndex;
+  use std::str::FromStr;
+  use std::string::String;
+  use std::vec::Vec;
+  use a85;
+  use a85::A85Buffer;
+  use a85::Z85Buffer;
+  use b32;
+  use b32::B32Buffer;
+  use b58;
+  use b58::B58Buffer;
+  use b64;
+  use b64::B64Buffer;
+  use hex;
+  use hex::HexBuffer;
+  use url;
+  use url::PercentBuffer;
+  use Codec;
+  use DecodeError;
+  use Xorable;
+  use xor;
+  use xor::LengthMismatch;
+  use xor::LengthPolicy;
+  use xor::RepeatingKey;
+
+  // A plain byte buffer for passing data between attacks. Unlike HexBuffer
+  // and friends it isn't tied to any encoding: pick one with the from_* and
+  // to_* methods at the point where data comes in or goes out. Display,
+  // FromStr and the Encodable traits use hex, since that's the most readable
+  // form for arbitrary bytes.
+  #[derive(Debug, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+  pub struct Bytes {
+    pub raw_bytes: Vec<u8>
+  }
+
+  // Encoders only ever produce ASCII.
+  fn ascii_string(encoded: Vec<u8>) -> String {
+    String::from_utf8(encoded).expect("encoded output is ascii")
+  }
+
+  impl Bytes {
+    pub fn new() -> Bytes {
+      Bytes { raw_bytes: Vec::new() }
+    }
+
+    pub fn into_vec(self) -> Vec<u8> {
+      self.raw_bytes
+    }
+
+    // Accepts hex of either case, with the separators and prefixes that
+    // HexBuffer::try_from_formatted allows.
+    pub fn from_hex(encoded: &[u8]) -> Result<Bytes, DecodeError> {
+      hex::decode_formatted(encoded).map(Bytes::from)
+    }
+
+    pub fn from_b64(encoded: &[u8]) -> Result<Bytes, DecodeError> {
+      Bytes::from_b64_with_config(encoded, &b64::STANDARD)
+    }
+
+    pub fn from_b64_with_config(encoded: &[u8], config: &b64::B64Config) -> Result<Bytes, DecodeError> {
+      b64::decode(encoded, config).map(Bytes::from)
+    }
+
+    pub fn from_b32(encoded: &[u8]) -> Result<Bytes, DecodeError> {
+      Bytes::from_b32_with_config(encoded, &b32::STANDARD)
+    }
+
+    pub fn from_b32_with_config(encoded: &[u8], config: &b32::B32Config) -> Result<Bytes, DecodeError> {
+      b32::decode(encoded, config).map(Bytes::from)
+    }
+
+    pub fn from_b58(encoded: &[u8]) -> Result<Bytes, DecodeError> {
+      b58::decode(encoded, b58::Alphabet::Bitcoin).map(Bytes::from)
+    }
+
+    pub fn from_a85(encoded: &[u8]) -> Result<Bytes, DecodeError> {
+      a85::decode(encoded).map(Bytes::from)
+    }
+
+    pub fn from_z85(encoded: &[u8]) -> Result<Bytes, DecodeError> {
+      a85::decode_z85(encoded).map(Bytes::from)
+    }
+
+    pub fn from_percent(encoded: &[u8]) -> Result<Bytes, DecodeError> {
+      Bytes::from_percent_with_config(encoded, &url::COMPONENT)
+    }
+
+    pub fn from_percent_with_config(encoded: &[u8], config: &url::PercentConfig) -> Result<Bytes, DecodeError> {
+      url::decode(encoded, config).map(Bytes::from)
+    }
+
+    pub fn to_hex(&self) -> String {
+      ascii_string(hex::encode(&self.raw_bytes))
+    }
+
+    pub fn to_b64(&self) -> String {
+      self.to_b64_with_config(&b64::STANDARD)
+    }
+
+    pub fn to_b64_with_config(&self, config: &b64::B64Config) -> String {
+      ascii_string(b64::encode(&self.raw_bytes, config))
+    }
+
+    pub fn to_b32(&self) -> String {
+      self.to_b32_with_config(&b32::STANDARD)
+    }
+
+    pub fn to_b32_with_config(&self, config: &b32::B32Config) -> String {
+      ascii_string(b32::encode(&self.raw_bytes, config))
+    }
+
+    pub fn to_b58(&self) -> String {
+      ascii_string(b58::encode(&self.raw_bytes, b58::Alphabet::Bitcoin))
+    }
+
+    pub fn to_a85(&self) -> String {
+      ascii_string(a85::encode(&self.raw_bytes))
+    }
+
+    // Panics unless the length is a multiple of 4 bytes.
+    pub fn to_z85(&self) -> String {
+      ascii_string(a85::encode_z85(&self.raw_bytes))
+    }
+
+    pub fn to_percent(&self) -> String {
+      self.to_percent_with_config(&url::COMPONENT)
+    }
+
+    pub fn to_percent_with_config(&self, config: &url::PercentConfig) -> String {
+      ascii_string(url::encode(&self.raw_bytes, config))
+    }
+
+    // XOR with another buffer, choosing what happens when the lengths differ.
+    // The ^ operator is shorthand for LengthPolicy::Truncate.
+    pub fn xor_with(&self, rhs: &[u8], policy: LengthPolicy) -> Result<Bytes, LengthMismatch> {
+      xor::xor_buffers(&self.raw_bytes, rhs, policy).map(Bytes::from)
+    }
+  }
+
+  // Buffer ^ buffer stops at the end of the shorter one, which is what you
+  // want when lining up ciphertexts under a reused pad. Use xor_with for the
+  // other length policies.
+  impl BitXor<&Bytes> for &Bytes {
+    type Output = Bytes;
+
+    fn bitxor(self, rhs: &Bytes) -> Bytes {
+      self.raw_bytes.iter().zip(rhs.raw_bytes.iter()).map(|(l, r)| l ^ r).collect()
+    }
+  }
+
+  impl BitXor<&Bytes> for Bytes {
+    type Output = Bytes;
+
+    fn bitxor(mut self, rhs: &Bytes) -> Bytes {
+      self ^= rhs;
+      self
+    }
+  }
+
+  impl BitXor<Bytes> for Bytes {
+    type Output = Bytes;
+
+    fn bitxor(self, rhs: Bytes) -> Bytes {
+      self ^ &rhs
+    }
+  }
+
+  impl BitXor<u8> for &Bytes {
+    type Output = Bytes;
+
+    fn bitxor(self, rhs: u8) -> Bytes {
+      self.xor_value(&rhs)
+    }
+  }
+
+  impl BitXor<u8> for Bytes {
+    type Output = Bytes;
+
+    fn bitxor(mut self, rhs: u8) -> Bytes {
+      self ^= rhs;
+      self
+    }
+  }
+
+  // Panics if the key is empty.
+  impl<'k> BitXor<RepeatingKey<'k>> for &Bytes {
+    type Output = Bytes;
+
+    fn bitxor(self, rhs: RepeatingKey<'k>) -> Bytes {
+      self.xor_sequential(rhs.0)
+    }
+  }
+
+  impl<'k> BitXor<RepeatingKey<'k>> for Bytes {
+    type Output = Bytes;
+
+    fn bitxor(mut self, rhs: RepeatingKey<'k>) -> Bytes {
+      self ^= rhs;
+      self
+    }
+  }
+
+  impl BitXorAssign<&Bytes> for Bytes {
+    fn bitxor_assign(&mut self, rhs: &Bytes) {
+      self.raw_bytes.truncate(rhs.raw_bytes.len());
+      for (l, r) in self.raw_bytes.iter_mut().zip(rhs.raw_bytes.iter()) {
+        *l ^= r;
+      }
+    }
+  }
+
+  impl BitXorAssign<u8> for Bytes {
+    fn bitxor_assign(&mut self, rhs: u8) {
+      xor::xor_in_place(&mut self.raw_bytes, &[rhs]);
+    }
+  }
+
+  impl<'k> BitXorAssign<RepeatingKey<'k>> for Bytes {
+    fn bitxor_assign(&mut self, rhs: RepeatingKey<'k>) {
+      xor::xor_in_place(&mut self.raw_bytes, rhs.0);
+    }
+  }
+
+  // Hex, as from_hex reads it.
+  impl Codec for Bytes {
+    const NAME: &'static str = "hex";
+
+    fn encode(raw: &[u8]) -> Vec<u8> {
+      hex::encode(raw)
+    }
+
+    fn decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+      hex::decode_formatted(encoded)
+    }
+  }
+
+  impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      f.write_str(&self.to_hex())
+    }
+  }
+
+  impl FromStr for Bytes {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Bytes, DecodeError> {
+      Bytes::from_hex(s.as_bytes())
+    }
+  }
+
+  impl Deref for Bytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+      &self.raw_bytes
+    }
+  }
+
+  impl DerefMut for Bytes {
+    fn deref_mut(&mut self) -> &mut [u8] {
+      &mut self.raw_bytes
+    }
+  }
+
+  impl AsRef<[u8]> for Bytes {
+    fn as_ref(&self) -> &[u8] {
+      &self.raw_bytes
+    }
+  }
+
+  impl<I: SliceIndex<[u8]>> Index<I> for Bytes {
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &I::Output {
+      &self.raw_bytes[index]
+    }
+  }
+
+  impl<I: SliceIndex<[u8]>> IndexMut<I> for Bytes {
+    fn index_mut(&mut self, index: I) -> &mut I::Output {
+      &mut self.raw_bytes[index]
+    }
+  }
+
+  impl From<Vec<u8>> for Bytes {
+    fn from(raw_bytes: Vec<u8>) -> Bytes {
+      Bytes { raw_bytes }
+    }
+  }
+
+  impl<'a> From<&'a [u8]> for Bytes {
+    fn from(raw_bytes: &'a [u8]) -> Bytes {
+      Bytes { raw_bytes: raw_bytes.to_vec() }
+    }
+  }
+
+  // The UTF-8 bytes of the string, not a decoding of it. Use from_hex and
+  // friends for encoded text.
+  impl<'a> From<&'a str> for Bytes {
+    fn from(string: &'a str) -> Bytes {
+      Bytes { raw_bytes: string.as_bytes().to_vec() }
+    }
+  }
+
+  impl From<Bytes> for Vec<u8> {
+    fn from(bytes: Bytes) -> Vec<u8> {
+      bytes.raw_bytes
+    }
+  }
+
+  impl ::std::iter::FromIterator<u8> for Bytes {
+    fn from_iter<T: IntoIterator<Item = u8>>(iter: T) -> Bytes {
+      Bytes { raw_bytes: iter.into_iter().collect() }
+    }
+  }
+
+  // The codec buffers all hold the same raw_bytes, so they share the slice
+  // and Vec conversions Codec needs and convert freely to and from Bytes.
+  macro_rules! buffer_conversions {
+    ($($buffer:ident),*) => {
+      $(
+        impl AsRef<[u8]> for $buffer {
+          fn as_ref(&self) -> &[u8] {
+            &self.raw_bytes
+          }
+        }
+
+        impl From<Vec<u8>> for $buffer {
+          fn from(raw_bytes: Vec<u8>) -> $buffer {
+            $buffer { raw_bytes }
+          }
+        }
+
+        impl From<$buffer> for Bytes {
+          fn from(buffer: $buffer) -> Bytes {
+            Bytes { raw_bytes: buffer.raw_bytes }
+          }
+        }
+
+        impl From<Bytes> for $buffer {
+          fn from(bytes: Bytes) -> $buffer {
+            $buffer { raw_bytes: bytes.raw_bytes }
+          }
+        }
+      )*
+    }
+  }
+
+  buffer_conversions!(HexBuffer, B64Buffer, B32Buffer, B58Buffer, A85Buffer, Z85Buffer, PercentBuffer);
+}
+
+pub mod strings {
+  use std::cmp::Reverse;
+  use std::convert::TryInto;
+  use std::f64::consts::LN_2;
+  use std::vec::Vec;
+  use float;
+  use xor::LengthMismatch;
+  use xor::LengthPolicy;
+
+  // Per-byte probabilities for English prose, case, spacing and punctuation
+  // included. Every byte gets at least a small probability, so the tests
+  // below never divide by zero, but control and high bytes are rare enough
+  // that a few of them dominate the statistic.
+  pub static ENGLISH: [f32; 256] = english_distribution();
+
+  // Each lowercase letter's percentage of the letters in running text. This
+  // is also language::ENGLISH's table.
+  pub const ENGLISH_LETTERS: [(u8, f32); 26] = [
+    (b'a', 8.167), (b'b', 1.492), (b'c', 2.782), (b'd', 4.253), (b'e', 12.702), (b'f', 2.228), (b'g', 2.015),
+    (b'h', 6.094), (b'i', 6.966), (b'j', 0.153), (b'k', 0.772), (b'l', 4.025), (b'm', 2.406), (b'n', 6.749),
+    (b'o', 7.507), (b'p', 1.929), (b'q', 0.095), (b'r', 5.987), (b's', 6.327), (b't', 9.056), (b'u', 2.758),
+    (b'v', 0.978), (b'w', 2.360), (b'x', 0.150), (b'y', 1.974), (b'z', 0.074),
+  ];
+
+  // Capitals mostly start words, so they follow word-initial frequencies,
+  // with "I" on its own pulling that letter up.
+  const UPPERCASE_WEIGHTS: [f64; 26] = [
+    11700.0, 4400.0, 5200.0, 3200.0, 2800.0, 4000.0, 1600.0, 4200.0, 7800.0, 600.0, 600.0, 2400.0, 3800.0,
+    2300.0, 7600.0, 4300.0, 200.0, 2800.0, 6700.0, 16000.0, 1200.0, 800.0, 5500.0, 50.0, 800.0, 50.0,
+  ];
+
+  // Everything that isn't a letter, per million bytes. Letters fill the rest.
+  const OTHER_WEIGHTS: [(u8, f64); 24] = [
+    (b' ', 165000.0), (b'\n', 8000.0), (b',', 9500.0), (b'.', 8500.0), (b'\'', 2500.0), (b'"', 2000.0),
+    (b'-', 1500.0), (b'?', 500.0), (b'!', 400.0), (b';', 300.0), (b':', 300.0), (b'(', 200.0), (b')', 200.0),
+    (b'0', 600.0), (b'1', 600.0), (b'2', 400.0), (b'3', 250.0), (b'4', 200.0), (b'5', 250.0), (b'6', 200.0),
+    (b'7', 200.0), (b'8', 200.0), (b'9', 300.0), (b'\r', 100.0),
+  ];
+
+  // Share of letters that are capitals.
+  const UPPERCASE_SHARE: f64 = 0.035;
+  const PRINTABLE_FLOOR: f64 = 50.0;
+  const BINARY_FLOOR: f64 = 1.0;
+
+  const fn english_distribution() -> [f32; 256] {
+    let mut weights = [BINARY_FLOOR; 256];
+    let mut b = 0x20;
+    while b < 0x7f {
+      weights[b] = PRINTABLE_FLOOR;
+      b += 1;
+    }
+    weights[b'\t' as usize] = PRINTABLE_FLOOR;
+
+    let mut others = 0.0;
+    let mut i = 0;
+    while i < OTHER_WEIGHTS.len() {
+      weights[OTHER_WEIGHTS[i].0 as usize] = OTHER_WEIGHTS[i].1;
+      others += OTHER_WEIGHTS[i].1;
+      i += 1;
+    }
+
+    let letters = 1_000_000.0 - others;
+    let mut lowercase_total = 0.0;
+    let mut uppercase_total = 0.0;
+    i = 0;
+    while i < 26 {
+      lowercase_total += ENGLISH_LETTERS[i].1 as f64;
+      uppercase_total += UPPERCASE_WEIGHTS[i];
+      i += 1;
+    }
+    i = 0;
+    while i < 26 {
+      weights[ENGLISH_LETTERS[i].0 as usize] = letters * (1.0 - UPPERCASE_SHARE) * ENGLISH_LETTERS[i].1 as f64 / lowercase_total;
+      weights[b'A' as usize + i] = letters * UPPERCASE_SHARE * UPPERCASE_WEIGHTS[i] / uppercase_total;
+      i += 1;
+    }
+
+    let mut total = 0.0;
+    b = 0;
+    while b < 256 {
+      total += weights[b];
+      b += 1;
+    }
+    let mut distribution = [0.0; 256];
+    b = 0;
+    while b < 256 {
+      distribution[b] = (weights[b] / total) as f32;
+      b += 1;
+    }
+    distribution
+  }
+
+  pub fn normality(frequency_map: &[usize; 256]) -> f32 {
+    normality_for(frequency_map, b"et aoinshrdlcumwfgypbvkjxqz")
+  }
+
+  // normality against any expected ordering, most frequent symbol first,
+  // e.g. a language::Language's ranking.
+  pub fn normality_for(frequency_map: &[usize; 256], expected: &[u8]) -> f32 {
+    let mut sorted_vec: Vec<(u8, usize)> =
+      (0..=255).zip(frequency_map.iter().cloned()).filter(|&(_, count)| count > 0).collect();
+    sorted_vec.sort_by_key(|&(_, count)| Reverse(count));
+    let sorted_vec: Vec<u8> = sorted_vec.iter().map(|(val, _)| *val).collect();
+
+    let mut sum_similar: f32 = 0.0;
+
+    for (i, actual) in sorted_vec.iter().enumerate() {
+      if let Some(exp_position) = expected.iter().position(|&s| s == *actual) {
+        sum_similar += 1.0 - ((exp_position as f32 - (i as f32)).abs() / expected.len() as f32)
+      }
+    }
+    sum_similar / (expected.len() as f32)
+  }
+
+  // How often each byte occurs in the lowercased input, indexed by byte.
+  pub fn frequency(input: &str) -> [usize; 256] {
+    let input = input.to_lowercase();
+    let mut histogram = [0; 256];
+    for byte in input.as_bytes().iter() {
+      histogram[*byte as usize] += 1;
+    }
+    histogram
+  }
+
+  // Like frequency, but over arbitrary bytes so candidates that aren't valid
+  // UTF-8 can still be counted. Only ASCII letters are lowercased.
+  pub fn frequency_bytes(input: &[u8]) -> [usize; 256] {
+    let mut histogram = [0; 256];
+    for byte in input.iter() {
+      histogram[byte.to_ascii_lowercase() as usize] += 1;
+    }
+    histogram
+  }
+
+  // Printable ASCII plus the whitespace that shows up in ordinary text.
+  pub fn is_text_byte(byte: u8) -> bool {
+    (0x20..0x7f).contains(&byte) || byte == b'\t' || byte == b'\n' || byte == b'\r'
+  }
+
+  // The share of counted bytes that are text bytes, or 0.0 for an empty
+  // histogram.
+  pub fn printable_fraction(frequency_map: &[usize; 256]) -> f32 {
+    let total: usize = frequency_map.iter().sum();
+    if total == 0 {
+      return 0.0;
+    }
+    let printable: usize = (0..=255u8).filter(|&b| is_text_byte(b)).map(|b| frequency_map[b as usize]).sum();
+    printable as f32 / total as f32
+  }
+
+  // normality scaled by printable_fraction, so each stray byte costs the
+  // candidate its share of the input rather than the whole score.
+  pub fn score(frequency_map: &[usize; 256]) -> f32 {
+    normality(frequency_map) * printable_fraction(frequency_map)
+  }
+
+  pub fn score_bytes(input: &[u8]) -> f32 {
+    score(&frequency_bytes(input))
+  }
+
+  // How often each byte occurs, with nothing folded together. This is the
+  // histogram the goodness-of-fit tests below expect, since ENGLISH tells
+  // capitals apart.
+  pub fn byte_counts(input: &[u8]) -> [usize; 256] {
+    let mut histogram = [0; 256];
+    for byte in input.iter() {
+      histogram[*byte as usize] += 1;
+    }
+    histogram
+  }
+
+  // Pearson's chi-squared statistic for the observed counts against an
+  // expected distribution such as ENGLISH: the sum over bytes of
+  // (observed - expected)^2 / expected. Lower is better, 0.0 being a perfect
+  // fit. It grows with the input length, so only compare inputs of the same
+  // length. A byte that occurs but has probability zero makes it infinite,
+  // and an empty histogram scores 0.0.
+  pub fn chi_squared(frequency_map: &[usize; 256], expected: &[f32; 256]) -> f32 {
+    let total: usize = frequency_map.iter().sum();
+    let mut statistic = 0.0;
+    for (&observed, &probability) in frequency_map.iter().zip(expected.iter()) {
+      let expected_count = total as f32 * probability;
+      if expected_count > 0.0 {
+        let difference = observed as f32 - expected_count;
+        statistic += difference * difference / expected_count;
+      } else if observed > 0 {
+        return f32::INFINITY;
+      }
+    }
+    statistic
+  }
+
+  // The G-test (log-likelihood ratio) statistic, 2 * sum of
+  // observed * ln(observed / expected). It reads like chi_squared, lower
+  // being better and growing with length, but is less swayed by bytes whose
+  // expected count is tiny.
+  pub fn g_test(frequency_map: &[usize; 256], expected: &[f32; 256]) -> f32 {
+    let total: usize = frequency_map.iter().sum();
+    let mut statistic = 0.0;
+    for (&observed, &probability) in frequency_map.iter().zip(expected.iter()) {
+      if observed == 0 {
+        continue;
+      }
+      let expected_count = total as f32 * probability;
+      if expected_count <= 0.0 {
+        return f32::INFINITY;
+      }
+      statistic += observed as f32 * float::ln(observed as f32 / expected_count);
+    }
+    2.0 * statistic
+  }
+
+  // The Bhattacharyya distance between the observed and expected
+  // distributions, -ln of the sum of sqrt(p * q). It is 0.0 for identical
+  // distributions and infinite for ones with nothing in common. Unlike the
+  // tests above it doesn't depend on the input length, so inputs of
+  // different lengths can be compared. An empty histogram scores 0.0.
+  pub fn bhattacharyya(frequency_map: &[usize; 256], expected: &[f32; 256]) -> f32 {
+    let total: usize = frequency_map.iter().sum();
+    if total == 0 {
+      return 0.0;
+    }
+    let coefficient: f32 = frequency_map.iter().zip(expected.iter())
+      .map(|(&observed, &probability)| float::sqrt(observed as f32 / total as f32 * probability))
+      .sum();
+    (-float::ln(coefficient)).max(0.0)
+  }
+
+  // Shannon entropy in bits per byte, from 0.0 for a run of one byte value
+  // up to 8.0 for bytes spread evenly over all 256 values. Plain text sits
+  // around 4 to 5; compressed or encrypted data close to 8. Empty input is
+  // 0.0.
+  pub fn entropy(input: &[u8]) -> f32 {
+    entropy_of(&byte_counts(input), input.len())
+  }
+
+  fn entropy_of(counts: &[usize; 256], total: usize) -> f32 {
+    if total == 0 {
+      return 0.0;
+    }
+    // log2(n) - sum(c * log2(c)) / n, which stays accurate when there are
+    // many small counts.
+    let sum: f64 = counts.iter().filter(|&&count| count > 1)
+      .map(|&count| count as f64 * float::ln(count as f32) as f64)
+      .sum();
+    (((float::ln(total as f32) as f64 - sum / total as f64) / LN_2) as f32).max(0.0)
+  }
+
+  // The chance that two bytes drawn from different positions are equal.
+  // Uniformly random bytes give about 1/256, and the more repetitive the
+  // data the higher it gets. Less than two bytes is 0.0.
+  pub fn index_of_coincidence(input: &[u8]) -> f32 {
+    coincidence(&byte_counts(input))
+  }
+
+  // index_of_coincidence over just the letters, ignoring case. English
+  // comes out around 0.066 and random letters 1/26, about 0.038, which is
+  // what makes it useful for telling the period of a polyalphabetic cipher.
+  pub fn alphabetic_index_of_coincidence(input: &[u8]) -> f32 {
+    let mut counts = [0; 256];
+    for &byte in input.iter().filter(|b| b.is_ascii_alphabetic()) {
+      counts[byte.to_ascii_lowercase() as usize] += 1;
+    }
+    coincidence(&counts)
+  }
+
+  fn coincidence(counts: &[usize; 256]) -> f32 {
+    let total: usize = counts.iter().sum();
+    if total < 2 {
+      return 0.0;
+    }
+    let pairs: u64 = counts.iter().map(|&count| count as u64 * count.saturating_sub(1) as u64).sum();
+    (pairs as f64 / (total as u64 * (total as u64 - 1)) as f64) as f32
+  }
+
+  // The entropy of one window of a larger buffer.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct EntropyWindow {
+    pub offset: usize,
+    pub len: usize,
+    pub entropy: f32,
+  }
+
+  // The entropy of each `window` bytes of the input, moving along `step`
+  // bytes at a time, to show where compressed or encrypted regions start
+  // and stop in a larger blob. Only whole windows are reported, so a tail
+  // shorter than the window is left out, unless the whole input is shorter
+  // than the window, in which case it is one window. Panics if window or
+  // step is 0.
+  pub fn entropy_profile(input: &[u8], window: usize, step: usize) -> Vec<EntropyWindow> {
+    assert!(window > 0 && step > 0, "window and step must be positive");
+    if input.len() <= window {
+      return if input.is_empty() {
+        Vec::new()
+      } else {
+        vec![EntropyWindow { offset: 0, len: input.len(), entropy: entropy(input) }]
+      };
+    }
+
+    let mut profile = Vec::with_capacity((input.len() - window) / step + 1);
+    let mut counts = byte_counts(&input[..window]);
+    let mut offset = 0;
+    loop {
+      profile.push(EntropyWindow { offset, len: window, entropy: entropy_of(&counts, window) });
+      let next = offset + step;
+      if next + window > input.len() {
+        return profile;
+      }
+      if step < window {
+        // Slide the counts along rather than recounting the overlap.
+        for &byte in &input[offset..next] {
+          counts[byte as usize] -= 1;
+        }
+        for &byte in &input[offset + window..next + window] {
+          counts[byte as usize] += 1;
+        }
+      } else {
+        counts = byte_counts(&input[next..next + window]);
+      }
+      offset = next;
+    }
+  }
+
+  // The number of differing bits, with every bit of the longer input's
+  // extra bytes counted as different. Kept for existing callers; hamming
+  // makes the length rule explicit.
+  pub fn hamming_distance(lhs: &[u8], rhs: &[u8]) -> u32 {
+    let common = lhs.len().min(rhs.len());
+    (differing_bits(&lhs[..common], &rhs[..common]) + lhs.len().abs_diff(rhs.len()) as u64 * 8) as u32
+  }
+
+  // The number of bits that differ between lhs and rhs, with `policy`
+  // deciding what happens when their lengths don't match: Error refuses,
+  // Truncate compares only as far as the shorter goes, and Cycle compares
+  // lhs against rhs repeated, e.g. to measure a buffer against a key.
+  pub fn hamming(lhs: &[u8], rhs: &[u8], policy: LengthPolicy) -> Result<u64, LengthMismatch> {
+    let mismatch = LengthMismatch { lhs: lhs.len(), rhs: rhs.len() };
+    match policy {
+      LengthPolicy::Error if lhs.len() != rhs.len() => Err(mismatch),
+      LengthPolicy::Error | LengthPolicy::Truncate => {
+        let common = lhs.len().min(rhs.len());
+        Ok(differing_bits(&lhs[..common], &rhs[..common]))
+      }
+      LengthPolicy::Cycle if rhs.is_empty() => Err(mismatch),
+      LengthPolicy::Cycle => Ok(lhs.chunks(rhs.len()).map(|chunk| differing_bits(chunk, &rhs[..chunk.len()])).sum()),
+    }
+  }
+
+  // hamming divided by the number of bytes compared, so 0.0 to 8.0 bits per
+  // byte whatever the length. Comparing nothing is 0.0.
+  pub fn normalized_hamming(lhs: &[u8], rhs: &[u8], policy: LengthPolicy) -> Result<f32, LengthMismatch> {
+    let distance = hamming(lhs, rhs, policy)?;
+    let compared = match policy {
+      LengthPolicy::Cycle => lhs.len(),
+      _ => lhs.len().min(rhs.len()),
+    };
+    Ok(if compared == 0 { 0.0 } else { distance as f32 / compared as f32 })
+  }
+
+  // The normalized_hamming between every pair of whole block_size blocks
+  // of the input, averaged. Blocks XORed with the same repeating key are
+  // about as far apart as their plaintexts, 2 to 3 bits per byte for text,
+  // against about 4 for unrelated bytes, so the key size scores lowest.
+  // That takes every pair, so it's quadratic in the number of blocks; pass
+  // a prefix of a large input. None with fewer than two whole blocks.
+  // Panics if block_size is 0.
+  pub fn average_pairwise_hamming(input: &[u8], block_size: usize) -> Option<f32> {
+    assert!(block_size > 0, "block size must be positive");
+    let blocks: Vec<&[u8]> = input.chunks_exact(block_size).collect();
+    if blocks.len() < 2 {
+      return None;
+    }
+    let mut total = 0;
+    for (i, lhs) in blocks.iter().enumerate() {
+      for rhs in &blocks[i + 1..] {
+        total += differing_bits(lhs, rhs);
+      }
+    }
+    let pairs = blocks.len() * (blocks.len() - 1) / 2;
+    Some((total as f64 / (pairs * block_size) as f64) as f32)
+  }
+
+  // Popcount of lhs ^ rhs a u64 at a time. The inputs must be the same
+  // length.
+  fn differing_bits(lhs: &[u8], rhs: &[u8]) -> u64 {
+    let mut lhs_words = lhs.chunks_exact(8);
+    let mut rhs_words = rhs.chunks_exact(8);
+    let mut distance: u64 = lhs_words.by_ref().zip(rhs_words.by_ref())
+      .map(|(l, r)| (word(l) ^ word(r)).count_ones() as u64)
+      .sum();
+    for (l, r) in lhs_words.remainder().iter().zip(rhs_words.remainder().iter()) {
+      distance += (l ^ r).count_ones() as u64;
+    }
+    distance
+  }
+
+  fn word(bytes: &[u8]) -> u64 {
+    u64::from_ne_bytes(bytes.try_into().expect("chunks_exact yields 8 bytes"))
+  }
+}
+
+pub mod ngram {
+  // Models are built over folded text: letters lose their case, whitespace
+  // becomes ' ', other printable ASCII is kept, and everything else becomes
+  // 0. That leaves this many symbols.
+  pub const SYMBOLS: usize = 70;
+
+  // The longest gram a model can use.
+  pub const MAX_ORDER: usize = 8;
+
+  pub const fn fold(byte: u8) -> u8 {
+    match byte {
+      b'A'..=b'Z' => byte.to_ascii_lowercase(),
+      b'a'..=b'z' => byte,
+      b' ' | b'\t' | b'\n' | b'\r' => b' ',
+      0x21..=0x7e => byte,
+      _ => 0,
+    }
+  }
+
+  // Log10 probabilities for the grams of one order, as a fitness function
+  // for candidate plaintexts. The model borrows its tables, which hold the
+  // folded grams back to back in ascending order and each gram's log10
+  // probability at the same index. Grams missing from the table get `floor`,
+  // which is how unseen grams are smoothed.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct NgramModel<'a> {
+    order: usize,
+    grams: &'a [u8],
+    log_probs: &'a [f32],
+    floor: f32,
+  }
+
+  pub static ENGLISH_BIGRAMS: NgramModel<'static> =
+    NgramModel::new(2, ENGLISH_BIGRAM_GRAMS, &ENGLISH_BIGRAM_LOG_PROBS, ENGLISH_BIGRAM_FLOOR);
+  pub static ENGLISH_TRIGRAMS: NgramModel<'static> =
+    NgramModel::new(3, ENGLISH_TRIGRAM_GRAMS, &ENGLISH_TRIGRAM_LOG_PROBS, ENGLISH_TRIGRAM_FLOOR);
+  pub static ENGLISH_QUADGRAMS: NgramModel<'static> =
+    NgramModel::new(4, ENGLISH_QUADGRAM_GRAMS, &ENGLISH_QUADGRAM_LOG_PROBS, ENGLISH_QUADGRAM_FLOOR);
+
+  impl<'a> NgramModel<'a> {
+    // Panics if the order isn't in 1..=MAX_ORDER, the tables don't line up,
+    // or the grams aren't folded and strictly ascending.
+    pub const fn new(order: usize, grams: &'a [u8], log_probs: &'a [f32], floor: f32) -> NgramModel<'a> {
+      assert!(order >= 1 && order <= MAX_ORDER, "n-gram order must be between 1 and MAX_ORDER");
+      assert!(grams.len() == order * log_probs.len(), "n-gram tables don't line up");
+      let mut i = 0;
+      while i < grams.len() {
+        assert!(fold(grams[i]) == grams[i], "n-gram table holds unfolded bytes");
+        if i >= order {
+          let mut j = i - order;
+          while j < i && grams[j] == grams[j + order] {
+            j += 1;
+          }
+          assert!(j < i && grams[j] < grams[j + order], "n-grams must be strictly ascending");
+        }
+        i += order;
+      }
+      NgramModel { order, grams, log_probs, floor }
+    }
+
+    pub fn order(&self) -> usize {
+      self.order
+    }
+
+    pub fn floor(&self) -> f32 {
+      self.floor
+    }
+
+    // The log10 probability of one gram, which is folded first. Panics if
+    // the gram isn't `order` bytes long.
+    pub fn log_probability(&self, gram: &[u8]) -> f32 {
+      assert_eq!(gram.len(), self.order, "gram length doesn't match the model's order");
+      let mut folded = [0; MAX_ORDER];
+      for (f, &b) in folded.iter_mut().zip(gram.iter()) {
+        *f = fold(b);
+      }
+      self.lookup(&folded[..self.order])
+    }
+
+    // The summed log10 probability of every overlapping gram in the input.
+    // Higher is better. It grows more negative with length, so only compare
+    // inputs of the same length; an input shorter than the order has no
+    // grams and scores 0.0.
+    pub fn log_likelihood(&self, input: &[u8]) -> f32 {
+      let mut window = [0; MAX_ORDER];
+      let mut total = 0.0;
+      for (i, &b) in input.iter().enumerate() {
+        window.copy_within(1..self.order, 0);
+        window[self.order - 1] = fold(b);
+        if i + 1 >= self.order {
+          total += self.lookup(&window[..self.order]);
+        }
+      }
+      total
+    }
+
+    // log_likelihood averaged over the grams, so inputs of any length can be
+    // compared. An input shorter than the order scores the floor, so it never
+    // beats real text.
+    pub fn fitness(&self, input: &[u8]) -> f32 {
+      if input.len() < self.order {
+        return self.floor;
+      }
+      self.log_likelihood(input) / (input.len() - self.order + 1) as f32
+    }
+
+    fn lookup(&self, gram: &[u8]) -> f32 {
+      let (mut low, mut high) = (0, self.log_probs.len());
+      while low < high {
+        let mid = (low + high) / 2;
+        let entry = &self.grams[mid * self.order..(mid + 1) * self.order];
+        if entry < gram {
+          low = mid + 1;
+        } else if entry > gram {
+          high = mid;
+        } else {
+          return self.log_probs[mid];
+        }
+      }
+      self.floor
+    }
+  }
+
+  include!("ngram_tables.rs");
+}
+
+pub mod profile {
+  use std::collections::BTreeMap;
+  use std::error;
+  use std::fmt;
+  use std::str;
+  use std::string::String;
+  use std::vec::Vec;
+  use float;
+  use ngram;
+  use ngram::NgramModel;
+  use url;
+  use url::PercentConfig;
+
+  // Byte and n-gram statistics for some kind of plaintext, trained from a
+  // sample of it so scorers can be pointed at logs, source code or JSON as
+  // easily as at English.
+  //
+  // Profiles keep raw counts, which is what the text format stores; the
+  // probabilities are worked out from them. Bytes get add-half smoothing, so
+  // none has probability zero. Like the built-in English tables, n-grams are
+  // over folded bytes, only the most common are kept, and the rest of the
+  // probability is spread evenly over the grams left out to give the floor.
+  #[derive(Debug, Clone, PartialEq)]
+  pub struct Profile {
+    name: String,
+    byte_counts: [u64; 256],
+    order: usize,
+    total_grams: u64,
+    grams: Vec<u8>,
+    gram_counts: Vec<u64>,
+    distribution: [f32; 256],
+    log_probs: Vec<f32>,
+    floor: f32,
+  }
+
+  // Counts a corpus fed in as many chunks as is convenient, e.g. while
+  // reading a file, then turns the counts into a Profile.
+  #[derive(Debug, Clone)]
+  pub struct Trainer {
+    order: usize,
+    byte_counts: [u64; 256],
+    gram_counts: BTreeMap<[u8; ngram::MAX_ORDER], u64>,
+    total_grams: u64,
+    window: [u8; ngram::MAX_ORDER],
+    seen: usize,
+  }
+
+  impl Trainer {
+    // Panics if the order isn't in 1..=ngram::MAX_ORDER.
+    pub fn new(order: usize) -> Trainer {
+      assert!((1..=ngram::MAX_ORDER).contains(&order), "n-gram order must be between 1 and MAX_ORDER");
+      Trainer {
+        order,
+        byte_counts: [0; 256],
+        gram_counts: BTreeMap::new(),
+        total_grams: 0,
+        window: [0; ngram::MAX_ORDER],
+        seen: 0,
+      }
+    }
+
+    // Grams run on across chunk boundaries, so the chunking doesn't matter.
+    pub fn feed(&mut self, chunk: &[u8]) {
+      for &byte in chunk {
+        self.byte_counts[byte as usize] += 1;
+        self.window.copy_within(1..self.order, 0);
+        self.window[self.order - 1] = ngram::fold(byte);
+        self.seen += 1;
+        if self.seen >= self.order {
+          let mut gram = [0; ngram::MAX_ORDER];
+          gram[..self.order].copy_from_slice(&self.window[..self.order]);
+          *self.gram_counts.entry(gram).or_insert(0) += 1;
+          self.total_grams += 1;
+        }
+      }
+    }
+
+    // Keep the max_grams most common grams, ties going to the lower gram.
+    pub fn finish(self, name: &str, max_grams: usize) -> Profile {
+      let mut kept: Vec<([u8; ngram::MAX_ORDER], u64)> = self.gram_counts.into_iter().collect();
+      kept.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+      kept.truncate(max_grams);
+      kept.sort();
+      let mut grams = Vec::with_capacity(kept.len() * self.order);
+      let mut gram_counts = Vec::with_capacity(kept.len());
+      for (gram, count) in kept {
+        grams.extend_from_slice(&gram[..self.order]);
+        gram_counts.push(count);
+      }
+      Profile::from_counts(String::from(name), self.byte_counts, self.order, self.total_grams, grams, gram_counts)
+    }
+  }
+
+  impl Profile {
+    pub fn train(name: &str, corpus: &[u8], order: usize, max_grams: usize) -> Profile {
+      let mut trainer = Trainer::new(order);
+      trainer.feed(corpus);
+      trainer.finish(name, max_grams)
+    }
+
+    // grams must be folded, strictly ascending, and line up with gram_counts.
+    fn from_counts(name: String, byte_counts: [u64; 256], order: usize, total_grams: u64, grams: Vec<u8>,
+                   gram_counts: Vec<u64>) -> Profile {
+      let total_bytes: u64 = byte_counts.iter().sum();
+      let mut distribution = [0.0; 256];
+      for (p, &count) in distribution.iter_mut().zip(byte_counts.iter()) {
+        *p = ((count as f64 + 0.5) / (total_bytes as f64 + 128.0)) as f32;
+      }
+
+      let log_probs: Vec<f32> = gram_counts.iter().map(|&count| log10(count as f64 / total_grams as f64)).collect();
+      let kept: u64 = gram_counts.iter().sum();
+      let possible = (0..order).fold(1.0, |total, _| total * ngram::SYMBOLS as f64);
+      let unseen = possible - gram_counts.len() as f64;
+      let floor = if total_grams == 0 {
+        -log10(possible)
+      } else if kept < total_grams && unseen > 0.0 {
+        log10((total_grams - kept) as f64 / total_grams as f64 / unseen)
+      } else {
+        // Every gram seen was kept, or every possible gram was, so there's
+        // nothing to share out. Treat the unseen ones as a hundredth of a
+        // single sighting.
+        log10(0.01 / total_grams as f64)
+      };
+      // Leftover mass spread over few unseen grams can come out above the
+      // rarest kept one; an unseen gram should never score better.
+      let floor = log_probs.iter().cloned().fold(floor, f32::min);
+
+      Profile { name, byte_counts, order, total_grams, grams, gram_counts, distribution, log_probs, floor }
+    }
+
+    pub fn name(&self) -> &str {
+      &self.name
+    }
+
+    pub fn order(&self) -> usize {
+      self.order
+    }
+
+    // How likely each byte is, for strings::chi_squared and the other
+    // goodness-of-fit tests, or the scorers built on them.
+    pub fn distribution(&self) -> &[f32; 256] {
+      &self.distribution
+    }
+
+    pub fn ngram_model(&self) -> NgramModel<'_> {
+      NgramModel::new(self.order, &self.grams, &self.log_probs, self.floor)
+    }
+
+    // The text format: a header line, then one "key value..." line per
+    // field, with the byte and gram lines each giving a count. Bytes are in
+    // hex and grams and the name are percent-encoded, so no value holds a
+    // space. Blank lines and lines starting with '#' are ignored by parse.
+    //
+    //   utilities-profile 1
+    //   name json%20logs
+    //   order 3
+    //   grams 51234
+    //   byte 0a 1200
+    //   gram .%22: 803
+    pub fn encode(&self) -> Vec<u8> {
+      format!("{}", self).into_bytes()
+    }
+
+    pub fn parse(text: &[u8]) -> Result<Profile, ProfileError> {
+      let mut lines = text.split(|&b| b == b'\n').enumerate()
+        .map(|(i, line)| (i + 1, line.strip_suffix(b"\r").unwrap_or(line)))
+        .filter(|(_, line)| !line.is_empty() && line[0] != b'#');
+
+      match lines.next() {
+        Some((_, line)) if line == HEADER => {}
+        Some((number, _)) => return Err(ProfileError::new(ProfileErrorKind::UnknownFormat, number)),
+        None => return Err(ProfileError::new(ProfileErrorKind::UnknownFormat, 1)),
+      }
+
+      let mut name = None;
+      let mut order = None;
+      let mut total_grams = None;
+      let mut byte_counts = [0; 256];
+      let mut seen_bytes = [false; 256];
+      let mut grams: Vec<(Vec<u8>, u64)> = Vec::new();
+      let mut last_line = 1;
+
+      for (number, line) in lines {
+        last_line = number;
+        let invalid = ProfileError::new(ProfileErrorKind::InvalidValue, number);
+        let mut fields = line.split(|&b| b == b' ');
+        let key = fields.next().unwrap_or_default();
+        let values: Vec<&[u8]> = fields.collect();
+        match (key, values.as_slice()) {
+          (b"name", [value]) if name.is_none() => name = Some(decode_text(value).ok_or(invalid)?),
+          (b"order", [value]) if order.is_none() => {
+            let value = parse_number(value).ok_or(invalid)? as usize;
+            if !(1..=ngram::MAX_ORDER).contains(&value) {
+              return Err(invalid);
+            }
+            order = Some(value);
+          }
+          (b"grams", [value]) if total_grams.is_none() => total_grams = Some(parse_number(value).ok_or(invalid)?),
+          (b"byte", [byte, count]) => {
+            let byte = match *byte {
+              [high, low] => hex_digit(*high).and_then(|h| hex_digit(*low).map(|l| (h << 4 | l) as usize)),
+              _ => None,
+            }.ok_or(invalid)?;
+            if seen_bytes[byte] {
+              return Err(ProfileError::new(ProfileErrorKind::Duplicate, number));
+            }
+            seen_bytes[byte] = true;
+            byte_counts[byte] = parse_number(count).ok_or(invalid)?;
+          }
+          (b"gram", [gram, count]) => {
+            let order = order.ok_or(ProfileError::new(ProfileErrorKind::MissingField, number))?;
+            let gram = url::PercentBuffer::try_from_encoded_with_config(gram, &TEXT).map_err(|_| invalid)?.raw_bytes;
+            if gram.len() != order || gram.iter().any(|&b| ngram::fold(b) != b) {
+              return Err(invalid);
+            }
+            // A gram that was never seen has no business in the table, and
+            // would score as log 0.
+            grams.push((gram, parse_number(count).filter(|&count| count > 0).ok_or(invalid)?));
+          }
+          (b"name", [_]) | (b"order", [_]) | (b"grams", [_]) =>
+            return Err(ProfileError::new(ProfileErrorKind::Duplicate, number)),
+          (b"name", _) | (b"order", _) | (b"grams", _) | (b"byte", _) | (b"gram", _) => return Err(invalid),
+          _ => return Err(ProfileError::new(ProfileErrorKind::UnknownKey, number)),
+        }
+      }
+
+      let missing = ProfileError::new(ProfileErrorKind::MissingField, last_line);
+      let (name, order, total_grams) = (name.ok_or(missing)?, order.ok_or(missing)?, total_grams.ok_or(missing)?);
+      grams.sort();
+      if grams.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+        return Err(ProfileError::new(ProfileErrorKind::Duplicate, last_line));
+      }
+      // Counts are positive, so this also catches grams listed under a
+      // total of 0.
+      if grams.iter().map(|&(_, count)| count).sum::<u64>() > total_grams {
+        return Err(ProfileError::new(ProfileErrorKind::InvalidValue, last_line));
+      }
+      let gram_counts = grams.iter().map(|&(_, count)| count).collect();
+      let grams = grams.into_iter().flat_map(|(gram, _)| gram).collect();
+      Ok(Profile::from_counts(name, byte_counts, order, total_grams, grams, gram_counts))
+    }
+  }
+
+  impl fmt::Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      writeln!(f, "{}", str::from_utf8(HEADER).unwrap_or_default())?;
+      writeln!(f, "name {}", encode_text(self.name.as_bytes()))?;
+      writeln!(f, "order {}", self.order)?;
+      writeln!(f, "grams {}", self.total_grams)?;
+      for (byte, &count) in self.byte_counts.iter().enumerate().filter(|&(_, &count)| count > 0) {
+        writeln!(f, "byte {:02x} {}", byte, count)?;
+      }
+      for (gram, count) in self.grams.chunks(self.order).zip(self.gram_counts.iter()) {
+        writeln!(f, "gram {} {}", encode_text(gram), count)?;
+      }
+      Ok(())
+    }
+  }
+
+  // What's wrong with a profile that failed to parse.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum ProfileErrorKind {
+    // The first line isn't a header for a version of the format this
+    // understands.
+    UnknownFormat,
+    // A line starts with a key the format doesn't have.
+    UnknownKey,
+    // A value doesn't parse, is out of range, a gram's count is 0 or the
+    // counts add up to more than the total, or a gram isn't folded or
+    // doesn't match the order.
+    InvalidValue,
+    // A field, byte or gram appears more than once.
+    Duplicate,
+    // name, order or grams is missing, or a gram comes before the order.
+    MissingField,
+  }
+
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct ProfileError {
+    pub kind: ProfileErrorKind,
+    // The line the problem was found on, counting from 1.
+    pub line: usize,
+  }
+
+  impl ProfileError {
+    pub fn new(kind: ProfileErrorKind, line: usize) -> ProfileError {
+      ProfileError { kind, line }
+    }
+  }
+
+  impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      let description = match self.kind {
+        ProfileErrorKind::UnknownFormat => "not a profile this version understands",
+        ProfileErrorKind::UnknownKey => "unknown key",
+        ProfileErrorKind::InvalidValue => "invalid value",
+        ProfileErrorKind::Duplicate => "duplicate entry",
+        ProfileErrorKind::MissingField => "missing field",
+      };
+      write!(f, "{} on line {}", description, self.line)
+    }
+  }
+
+  impl error::Error for ProfileError {}
+
+  const HEADER: &[u8] = b"utilities-profile 1";
+
+  // Escapes only what has to be: space, '%' and anything unprintable.
+  const TEXT: PercentConfig = PercentConfig { escape: url::AsciiSet::EMPTY, space_as_plus: false, uppercase: true };
+
+  fn encode_text(text: &[u8]) -> String {
+    let encoded = url::encode(text, &TEXT);
+    String::from_utf8(encoded).unwrap_or_default()
+  }
+
+  fn decode_text(text: &[u8]) -> Option<String> {
+    let decoded = url::PercentBuffer::try_from_encoded_with_config(text, &TEXT).ok()?.raw_bytes;
+    String::from_utf8(decoded).ok()
+  }
+
+  fn parse_number(text: &[u8]) -> Option<u64> {
+    str::from_utf8(text).ok()?.parse().ok()
+  }
+
+  fn hex_digit(digit: u8) -> Option<u8> {
+    (digit as char).to_digit(16).map(|d| d as u8)
+  }
+
+  fn log10(x: f64) -> f32 {
+    float::ln(x as f32) / float::ln(10.0)
+  }
+}
+
+pub mod language {
+  use std::vec::Vec;
+  use float;
+  use strings;
+
+  // A language's letter frequencies, for telling languages apart and for
+  // scoring plaintexts that aren't English. Letters are Latin-1 code points
+  // so accented ones fit in a byte; in input they're recognised in UTF-8.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct Language {
+    pub name: &'static str,
+    // ISO 639-1.
+    pub code: &'static str,
+    // Each lowercase letter with its percentage of all letters.
+    pub letters: &'static [(u8, f32)],
+  }
+
+  // Letter shares from the usual published tables for each language.
+  // English shares the table strings::ENGLISH is built from.
+  pub static ENGLISH: Language = Language {
+    name: "English",
+    code: "en",
+    letters: &strings::ENGLISH_LETTERS,
+  };
+
+  pub static FRENCH: Language = Language {
+    name: "French",
+    code: "fr",
+    letters: &[
+      (b'a', 7.636), (b'b', 0.901), (b'c', 3.260), (b'd', 3.669), (b'e', 14.715), (b'f', 1.066), (b'g', 0.866),
+      (b'h', 0.737), (b'i', 7.529), (b'j', 0.613), (b'k', 0.074), (b'l', 5.456), (b'm', 2.968), (b'n', 7.095),
+      (b'o', 5.796), (b'p', 2.521), (b'q', 1.362), (b'r', 6.693), (b's', 7.948), (b't', 7.244), (b'u', 6.311),
+      (b'v', 1.838), (b'w', 0.049), (b'x', 0.427), (b'y', 0.128), (b'z', 0.326),
+      (0xe0, 0.486), (0xe2, 0.051), (0xe7, 0.085), (0xe8, 0.271), (0xe9, 1.504), (0xea, 0.218), (0xeb, 0.008),
+      (0xee, 0.045), (0xef, 0.005), (0xf4, 0.023), (0xf9, 0.058), (0xfb, 0.060),
+    ],
+  };
+
+  pub static GERMAN: Language = Language {
+    name: "German",
+    code: "de",
+    letters: &[
+      (b'a', 6.516), (b'b', 1.886), (b'c', 2.732), (b'd', 5.076), (b'e', 16.396), (b'f', 1.656), (b'g', 3.009),
+      (b'h', 4.577), (b'i', 6.550), (b'j', 0.268), (b'k', 1.417), (b'l', 3.437), (b'm', 2.534), (b'n', 9.776),
+      (b'o', 2.594), (b'p', 0.670), (b'q', 0.018), (b'r', 7.003), (b's', 7.270), (b't', 6.154), (b'u', 4.166),
+      (b'v', 0.846), (b'w', 1.921), (b'x', 0.034), (b'y', 0.039), (b'z', 1.134),
+      (0xdf, 0.307), (0xe4, 0.578), (0xf6, 0.443), (0xfc, 0.995),
+    ],
+  };
+
+  pub static SPANISH: Language = Language {
+    name: "Spanish",
+    code: "es",
+    letters: &[
+      (b'a', 11.525), (b'b', 2.215), (b'c', 4.019), (b'd', 5.010), (b'e', 12.181), (b'f', 0.692), (b'g', 1.768),
+      (b'h', 0.703), (b'i', 6.247), (b'j', 0.493), (b'k', 0.011), (b'l', 4.967), (b'm', 3.157), (b'n', 6.712),
+      (b'o', 8.683), (b'p', 2.510), (b'q', 0.877), (b'r', 6.871), (b's', 7.977), (b't', 4.632), (b'u', 2.927),
+      (b'v', 1.138), (b'w', 0.017), (b'x', 0.215), (b'y', 1.008), (b'z', 0.467),
+      (0xe1, 0.502), (0xe9, 0.433), (0xed, 0.725), (0xf1, 0.311), (0xf3, 0.827), (0xfa, 0.168), (0xfc, 0.012),
+    ],
+  };
+
+  pub static ITALIAN: Language = Language {
+    name: "Italian",
+    code: "it",
+    letters: &[
+      (b'a', 11.745), (b'b', 0.927), (b'c', 4.501), (b'd', 3.736), (b'e', 11.792), (b'f', 1.153), (b'g', 1.644),
+      (b'h', 0.636), (b'i', 10.143), (b'j', 0.011), (b'k', 0.009), (b'l', 6.510), (b'm', 2.512), (b'n', 6.883),
+      (b'o', 9.832), (b'p', 3.056), (b'q', 0.505), (b'r', 6.367), (b's', 4.981), (b't', 5.623), (b'u', 3.011),
+      (b'v', 2.097), (b'w', 0.033), (b'x', 0.003), (b'y', 0.020), (b'z', 1.181),
+      (0xe0, 0.635), (0xe8, 0.263), (0xec, 0.030), (0xf2, 0.002), (0xf9, 0.166),
+    ],
+  };
+
+  pub static PORTUGUESE: Language = Language {
+    name: "Portuguese",
+    code: "pt",
+    letters: &[
+      (b'a', 14.634), (b'b', 1.043), (b'c', 3.882), (b'd', 4.992), (b'e', 12.570), (b'f', 1.023), (b'g', 1.303),
+      (b'h', 0.781), (b'i', 6.186), (b'j', 0.397), (b'k', 0.015), (b'l', 2.779), (b'm', 4.738), (b'n', 4.446),
+      (b'o', 9.735), (b'p', 2.523), (b'q', 1.204), (b'r', 6.530), (b's', 6.805), (b't', 4.336), (b'u', 3.639),
+      (b'v', 1.575), (b'w', 0.037), (b'x', 0.253), (b'y', 0.006), (b'z', 0.470),
+      (0xe0, 0.072), (0xe1, 0.118), (0xe2, 0.562), (0xe3, 0.733), (0xe7, 0.530), (0xe9, 0.337), (0xea, 0.450),
+      (0xed, 0.132), (0xf3, 0.296), (0xf4, 0.635), (0xf5, 0.040), (0xfa, 0.207), (0xfc, 0.026),
+    ],
+  };
+
+  pub static LANGUAGES: [&Language; 6] = [&ENGLISH, &FRENCH, &GERMAN, &SPANISH, &ITALIAN, &PORTUGUESE];
+
+  impl Language {
+    // Each letter's share of all letters, indexed by Latin-1 code point.
+    pub fn distribution(&self) -> [f32; 256] {
+      let total: f32 = self.letters.iter().map(|&(_, share)| share).sum();
+      let mut distribution = [0.0; 256];
+      for &(letter, share) in self.letters {
+        distribution[letter as usize] = share / total;
+      }
+      distribution
+    }
+
+    // The letters, most frequent first, as strings::normality_for wants.
+    pub fn ranking(&self) -> Vec<u8> {
+      let mut letters = self.letters.to_vec();
+      letters.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+      letters.iter().map(|&(letter, _)| letter).collect()
+    }
+
+    // strings::normality_for the input's letters against this language's
+    // ranking.
+    pub fn normality(&self, input: &[u8]) -> f32 {
+      strings::normality_for(&letter_counts(input).0, &self.ranking())
+    }
+
+    // The Bhattacharyya coefficient between the input's letter frequencies
+    // and this language's: 1.0 for a perfect match, 0.0 if there are no
+    // letters at all.
+    pub fn similarity(&self, input: &[u8]) -> f32 {
+      similarity(&letter_counts(input).0, &self.distribution())
+    }
+  }
+
+  // How well a piece of text matched a language.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct LanguageMatch {
+    pub language: &'static Language,
+    // Language::similarity, which the matches are ranked by.
+    pub similarity: f32,
+    // Language::normality, the rank-order measure strings::normality uses.
+    // Only breaks ties in the ranking.
+    pub normality: f32,
+  }
+
+  // Rank the built-in languages by how well the input's letter frequencies
+  // match theirs, best first. The Bhattacharyya similarity decides the
+  // order, since it weighs every letter by how common it is; normality,
+  // which only looks at the rank order of letters, is reported alongside and
+  // breaks exact ties. A few hundred letters is enough to tell the Romance
+  // languages apart; much less than that and it's guesswork.
+  pub fn identify_language(input: &[u8]) -> Vec<LanguageMatch> {
+    let (counts, _) = letter_counts(input);
+    let mut matches: Vec<LanguageMatch> = LANGUAGES.iter().map(|&language| LanguageMatch {
+      language,
+      similarity: similarity(&counts, &language.distribution()),
+      normality: strings::normality_for(&counts, &language.ranking()),
+    }).collect();
+    matches.sort_by(|a, b| b.similarity.total_cmp(&a.similarity).then_with(|| b.normality.total_cmp(&a.normality)));
+    matches
+  }
+
+  // Count the letters in the input, lowercased and indexed by Latin-1 code
+  // point, taking accented letters from their UTF-8 encoding. Alongside is
+  // how many bytes were plausible text: ASCII text bytes plus both bytes of
+  // each accented letter. Other non-ASCII bytes count for neither.
+  pub fn letter_counts(input: &[u8]) -> ([usize; 256], usize) {
+    let mut counts = [0; 256];
+    let mut plausible = 0;
+    let mut i = 0;
+    while i < input.len() {
+      let byte = input[i];
+      if byte.is_ascii_alphabetic() {
+        counts[byte.to_ascii_lowercase() as usize] += 1;
+        plausible += 1;
+      } else if strings::is_text_byte(byte) {
+        plausible += 1;
+      } else if byte == 0xc3 && i + 1 < input.len() && (0x80..0xc0).contains(&input[i + 1]) {
+        // U+00C0 to U+00FF; all are letters except the multiplication and
+        // division signs.
+        let letter = input[i + 1] + 0x40;
+        if letter != 0xd7 && letter != 0xf7 {
+          let lower = if letter < 0xdf { letter + 0x20 } else { letter };
+          counts[lower as usize] += 1;
+          plausible += 2;
+        }
+        i += 1;
+      }
+      i += 1;
+    }
+    (counts, plausible)
+  }
+
+  fn similarity(counts: &[usize; 256], distribution: &[f32; 256]) -> f32 {
+    let total: usize = counts.iter().sum();
+    if total == 0 {
+      return 0.0;
+    }
+    counts.iter().zip(distribution.iter())
+      .map(|(&count, &p)| float::sqrt(count as f32 / total as f32 * p))
+      .sum()
+  }
+}
+
+pub mod score {
+  use std::vec::Vec;
+  use float;
+  use language;
+  use language::Language;
+  use ngram::NgramModel;
+  use strings;
+
+  // Which way a scorer's raw scores point.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub enum Polarity {
+    HigherIsBetter,
+    LowerIsBetter,
+  }
+
+  // Rates how plausible a candidate plaintext is, so attacks can be written
+  // once and pointed at prose, logs or binary data by swapping the scorer.
+  pub trait Scorer {
+    fn score(&self, candidate: &[u8]) -> f32;
+
+    fn polarity(&self) -> Polarity;
+
+    // The score mapped onto 0.0..=1.0 with 1.0 the best, so scores from
+    // different scorers can be weighed against each other.
+    fn normalized(&self, candidate: &[u8]) -> f32;
+
+    // A score good enough that an attack can stop searching once a candidate
+    // reaches it. None means always search everything.
+    fn threshold(&self) -> Option<f32> {
+      None
+    }
+
+    // Whether score `a` beats score `b`. NaN never wins.
+    fn prefers(&self, a: f32, b: f32) -> bool {
+      match self.polarity() {
+        Polarity::HigherIsBetter => a > b,
+        Polarity::LowerIsBetter => a < b,
+      }
+    }
+
+    fn good_enough(&self, score: f32) -> bool {
+      match self.threshold() {
+        Some(threshold) => score == threshold || self.prefers(score, threshold),
+        None => false,
+      }
+    }
+  }
+
+  // strings::normality over frequency_bytes, in 0.0..=1.0.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct Normality;
+
+  impl Scorer for Normality {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      strings::normality(&strings::frequency_bytes(candidate))
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::HigherIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      self.score(candidate)
+    }
+  }
+
+  // The share of text bytes, strings::printable_fraction. Useful on its own
+  // when the plaintext isn't prose, or alongside a language scorer.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+  pub struct PrintableRatio;
+
+  impl Scorer for PrintableRatio {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      strings::printable_fraction(&strings::byte_counts(candidate))
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::HigherIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      self.score(candidate)
+    }
+  }
+
+  // strings::chi_squared against a byte distribution. Normalized, it is
+  // e^(-statistic / 2n), which approximates the per-byte likelihood ratio.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct ChiSquared<'a>(pub &'a [f32; 256]);
+
+  // strings::g_test against a byte distribution. Normalized the same way as
+  // ChiSquared, where it is exactly e^(-KL divergence).
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct GTest<'a>(pub &'a [f32; 256]);
+
+  // strings::bhattacharyya against a byte distribution. Normalized, it is the
+  // Bhattacharyya coefficient e^(-distance).
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct Bhattacharyya<'a>(pub &'a [f32; 256]);
+
+  pub static ENGLISH_CHI_SQUARED: ChiSquared<'static> = ChiSquared(&strings::ENGLISH);
+  pub static ENGLISH_G_TEST: GTest<'static> = GTest(&strings::ENGLISH);
+  pub static ENGLISH_BHATTACHARYYA: Bhattacharyya<'static> = Bhattacharyya(&strings::ENGLISH);
+
+  fn per_byte_likelihood(statistic: f32, length: usize) -> f32 {
+    if length == 0 {
+      return 1.0;
+    }
+    float::exp(-statistic / (2 * length) as f32)
+  }
+
+  impl<'a> Scorer for ChiSquared<'a> {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      strings::chi_squared(&strings::byte_counts(candidate), self.0)
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::LowerIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      per_byte_likelihood(self.score(candidate), candidate.len())
+    }
+  }
+
+  impl<'a> Scorer for GTest<'a> {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      strings::g_test(&strings::byte_counts(candidate), self.0)
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::LowerIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      per_byte_likelihood(self.score(candidate), candidate.len())
+    }
+  }
+
+  impl<'a> Scorer for Bhattacharyya<'a> {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      strings::bhattacharyya(&strings::byte_counts(candidate), self.0)
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::LowerIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      float::exp(-self.score(candidate))
+    }
+  }
+
+  // NgramModel::fitness. Normalized, the fitness is placed between the
+  // model's floor (0.0) and certainty (1.0).
+  impl<'a> Scorer for NgramModel<'a> {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      self.fitness(candidate)
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::HigherIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      let floor = self.floor();
+      ((self.fitness(candidate) - floor) / -floor).clamp(0.0, 1.0)
+    }
+  }
+
+  // Language::similarity, scaled by the share of bytes that are plausible
+  // text so bytes that aren't letters still cost something.
+  impl Scorer for Language {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      if candidate.is_empty() {
+        return 0.0;
+      }
+      let (_, plausible) = language::letter_counts(candidate);
+      self.similarity(candidate) * plausible as f32 / candidate.len() as f32
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::HigherIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      self.score(candidate)
+    }
+  }
+
+  // A weighted mean of other scorers' normalized scores, so it is higher is
+  // better and in 0.0..=1.0 too. Weights are relative; they needn't sum to 1.
+  pub struct Ensemble<'a> {
+    pub members: Vec<(&'a dyn Scorer, f32)>,
+    pub threshold: Option<f32>,
+  }
+
+  impl<'a> Ensemble<'a> {
+    pub fn new(members: Vec<(&'a dyn Scorer, f32)>) -> Ensemble<'a> {
+      Ensemble { members, threshold: None }
+    }
+  }
+
+  impl<'a> Scorer for Ensemble<'a> {
+    fn score(&self, candidate: &[u8]) -> f32 {
+      let total_weight: f32 = self.members.iter().map(|&(_, weight)| weight).sum();
+      if total_weight <= 0.0 {
+        return 0.0;
+      }
+      let weighted: f32 = self.members.iter()
+        .map(|&(scorer, weight)| scorer.normalized(candidate) * weight)
+        .sum();
+      weighted / total_weight
+    }
+
+    fn polarity(&self) -> Polarity {
+      Polarity::HigherIsBetter
+    }
+
+    fn normalized(&self, candidate: &[u8]) -> f32 {
+      self.score(candidate)
+    }
+
+    fn threshold(&self) -> Option<f32> {
+      self.threshold
+    }
+  }
+}
+
+pub mod attack {
+  use std::vec::Vec;
+  use score::Scorer;
+  use xor;
+
+  // The key an attack settled on, what it decrypts to, and the score the
+  // scorer gave that plaintext.
+  #[derive(Debug, Clone, PartialEq)]
+  pub struct Guess {
+    pub key: Vec<u8>,
+    pub plaintext: Vec<u8>,
+    pub score: f32,
+  }
+
+  // Try every single-byte key and keep the plaintext the scorer prefers,
+  // stopping early if one meets the scorer's threshold.
+  pub fn single_byte_xor(ciphertext: &[u8], scorer: &dyn Scorer) -> Guess {
+    let mut plaintext = vec![0; ciphertext.len()];
+    let mut best: Option<Guess> = None;
+    for key in 0..=255u8 {
+      xor::xor_into(ciphertext, &[key], &mut plaintext);
+      let score = scorer.score(&plaintext);
+      if best.as_ref().is_none_or(|best| scorer.prefers(score, best.score)) {
+        best = Some(Guess { key: vec![key], plaintext: plaintext.clone(), score });
+        if scorer.good_enough(score) {
+          break;
+        }
+      }
+    }
+    best.expect("there is always a first key")
+  }
+
+  // Split the ciphertext into key_length columns, each XORed with a single
+  // key byte, break each column on its own, then score the whole plaintext.
+  // Panics if key_length is 0.
+  pub fn repeating_key_xor(ciphertext: &[u8], key_length: usize, scorer: &dyn Scorer) -> Guess {
+    assert!(key_length > 0, "key length must be positive");
+    let key: Vec<u8> = (0..key_length).map(|offset| {
+      let column: Vec<u8> = ciphertext.iter().skip(offset).step_by(key_length).cloned().collect();
+      single_byte_xor(&column, scorer).key[0]
+    }).collect();
+    let plaintext = xor::xor_sequential(ciphertext, &key);
+    let score = scorer.score(&plaintext);
+    Guess { key, plaintext, score }
+  }
+
+  // The guess the scorer prefers, e.g. across several key lengths or
+  // ciphertexts. Scores are only comparable if the plaintexts are too, so
+  // mind length-dependent scorers.
+  pub fn best<I: IntoIterator<Item = Guess>>(guesses: I, scorer: &dyn Scorer) -> Option<Guess> {
+    guesses.into_iter().fold(None, |best: Option<Guess>, guess| match best {
+      Some(best) if !scorer.prefers(guess.score, best.score) => Some(best),
+      _ => Some(guess),
+    })
+  }
+}
+
+pub mod detect {
+  use std::fmt;
+  use std::str;
+  use std::string::String;
+  use std::vec::Vec;
+  use a85;
+  use a85::A85Buffer;
+  use b32;
+  use b32::B32Buffer;
+  use b64;
+  use b64::B64Buffer;
+  use float;
+  use hex::HexBuffer;
+  use strings;
+
+  // The encodings detect knows how to recognise. Raw means the input taken
+  // as it is, which is always a possibility.
+  #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+  pub enum Encoding {
+    Hex,
+    Base64,
+    Base64Url,
+    Base32,
+    Base32Hex,
+    Ascii85,
+    Raw,
+  }
+
+  impl fmt::Display for Encoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      let name = match *self {
+        Encoding::Hex => "hex",
+        Encoding::Base64 => "base64",
+        Encoding::Base64Url => "base64url",
+        Encoding::Base32 => "base32",
+        Encoding::Base32Hex => "base32hex",
+        Encoding::Ascii85 => "ascii85",
+        Encoding::Raw => "raw",
+      };
+      f.write_str(name)
+    }
+  }
+
+  // One way of reading the input, with what it decodes to.
+  #[derive(Debug, Clone, PartialEq)]
+  pub struct Candidate {
+    pub encoding: Encoding,
+    pub confidence: f32,
+    pub decoded: Vec<u8>,
+  }
+
+  const ENCODINGS: [Encoding; 6] =
+    [Encoding::Hex, Encoding::Base64, Encoding::Base64Url, Encoding::Base32, Encoding::Base32Hex, Encoding::Ascii85];
+
+  // Rank the encodings `input` decodes under, most likely first, with
+  // confidences that sum to 1. Raw is always included.
+  //
+  // Each reading is weighed by the chance of seeing exactly this input if
+  // it were the right one: the chance of its decoded bytes, times the
+  // chance they'd be written out in this form. Raw is the input taken as
+  // its own decoding. Decoded bytes are either text, judged a byte at a
+  // time against strings::ENGLISH blended with plain printable ASCII, or
+  // binary, where every byte is as likely as any other but which is far
+  // rarer to begin with. So a 32 digit hex hash is hex, "TWFu" is base64
+  // for "Man", but a bare word like "password123" or "deadbeef" is itself:
+  // it decodes only to junk, and the input was already readable.
+  //
+  // Form counts too: padding, or a length that needs none, and ascii85's
+  // <~ ~> delimiters all make an encoding likelier, while stray bits in a
+  // final group, which only the lenient decoders accept, make it unlikely.
+  //
+  // Leading and trailing whitespace is ignored and line breaks may split
+  // the encodings, but any other whitespace rules out everything except
+  // separated hex like "de ad be ef". Mixed case rules out hex and base32.
+  pub fn detect(input: &[u8]) -> Vec<Candidate> {
+    let trimmed = input.trim_ascii();
+    let joined: Vec<u8> = trimmed.iter().cloned().filter(|b| *b != b'\r' && *b != b'\n').collect();
+    let mut candidates: Vec<Candidate> = Vec::new();
+    let mut scores: Vec<f64> = Vec::new();
+    for encoding in ENCODINGS.iter() {
+      if let Some(decoded) = decode_as(*encoding, trimmed) {
+        // Readings that agree, like base64 and base64url without any of the
+        // symbols they differ on, count as one. Nothing but separators or
+        // padding isn't a reading at all.
+        if !decoded.is_empty() && !candidates.iter().any(|c| c.decoded == decoded) {
+          scores.push(form_log_likelihood(*encoding, &joined, &decoded) + payload_log_likelihood(&decoded));
+          candidates.push(Candidate { encoding: *encoding, confidence: 0.0, decoded });
+        }
+      }
+    }
+    scores.push(float::ln(1.0 - ENCODED_SHARE) as f64 + payload_log_likelihood(input));
+    candidates.push(Candidate { encoding: Encoding::Raw, confidence: 0.0, decoded: input.to_vec() });
+
+    // Scale against the best, so the weights stay in range however long
+    // the input is.
+    let best = scores.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let weights: Vec<f64> = scores.iter().map(|score| float::exp((score - best) as f32) as f64).collect();
+    let total: f64 = weights.iter().sum();
+    for (candidate, weight) in candidates.iter_mut().zip(weights) {
+      candidate.confidence = (weight / total) as f32;
+    }
+    // A stable sort keeps ties in ENCODINGS order.
+    candidates.sort_by(|lhs, rhs| rhs.confidence.total_cmp(&lhs.confidence));
+    candidates
+  }
+
+  // One layer removed by a Peeler.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct Layer {
+    pub encoding: Encoding,
+    pub confidence: f32,
+  }
+
+  #[derive(Debug, Clone, PartialEq)]
+  pub struct Peeled {
+    // Outermost first.
+    pub layers: Vec<Layer>,
+    pub output: Vec<u8>,
+  }
+
+  // Shows the chain of layers, e.g. "base64 -> hex -> base64".
+  impl fmt::Display for Peeled {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+      if self.layers.is_empty() {
+        return f.write_str("raw");
+      }
+      let names: Vec<String> = self.layers.iter().map(|layer| format!("{}", layer.encoding)).collect();
+      f.write_str(&names.join(" -> "))
+    }
+  }
+
+  // Strips encodings off a blob one at a time, taking the best candidate
+  // from detect each time. It stops when raw is the best reading, when
+  // there's nothing left, after `max_layers`, or when the data already
+  // looks like printable text whose strings::normality reaches
+  // `plaintext_normality`.
+  #[derive(Debug, Clone, Copy, PartialEq)]
+  pub struct Peeler {
+    pub max_layers: usize,
+    pub plaintext_normality: f32,
+  }
+
+  pub const PEELER: Peeler = Peeler { max_layers: 16, plaintext_normality: 0.7 };
+
+  impl Peeler {
+    pub fn peel(&self, input: &[u8]) -> Peeled {
+      let mut layers = Vec::new();
+      let mut output = input.to_vec();
+      while layers.len() < self.max_layers && !output.is_empty() && !self.looks_like_plaintext(&output) {
+        let best = detect(&output).swap_remove(0);
+        if best.encoding == Encoding::Raw {
+          break;
+        }
+        layers.push(Layer { encoding: best.encoding, confidence: best.confidence });
+        output = best.decoded;
+      }
+      Peeled { layers, output }
+    }
+
+    fn looks_like_plaintext(&self, data: &[u8]) -> bool {
+      match str::from_utf8(data) {
+        Ok(text) if text.chars().all(|c| !c.is_control() || c.is_ascii_whitespace()) =>
+          strings::normality(&strings::frequency(text)) >= self.plaintext_normality,
+        _ => false,
+      }
+    }
+  }
+
+  pub fn peel(input: &[u8]) -> Peeled {
+    PEELER.peel(input)
+  }
+
+  fn decode_as(encoding: Encoding, input: &[u8]) -> Option<Vec<u8>> {
+    if input.is_empty() {
+      return None;
+    }
+    // Hex and base32 are written in one case or the other, never both.
+    let single_case = matches!(encoding, Encoding::Hex | Encoding::Base32 | Encoding::Base32Hex);
+    if single_case && input.iter().any(u8::is_ascii_lowercase) && input.iter().any(u8::is_ascii_uppercase) {
+      return None;
+    }
+    if encoding == Encoding::Hex {
+      return HexBuffer::try_from_formatted(input).ok().map(|buffer| buffer.raw_bytes);
+    }
+    let joined: Vec<u8> = input.iter().cloned().filter(|b| *b != b'\r' && *b != b'\n').collect();
+    if joined.iter().any(u8::is_ascii_whitespace) {
+      return None;
+    }
+    let b64 = |configs: [b64::B64Config; 2]| configs.iter()
+      .find_map(|config| B64Buffer::try_from_encoded_with_config(&joined, config).ok())
+      .map(|buffer| buffer.raw_bytes);
+    let b32 = |configs: [b32::B32Config; 2]| configs.iter()
+      .find_map(|config| B32Buffer::try_from_encoded_with_config(&joined, config).ok())
+      .map(|buffer| buffer.raw_bytes);
+    match encoding {
+      Encoding::Base64 => b64([b64::STANDARD, b64::B64Config { padding: false, ..b64::STANDARD }]),
+      Encoding::Base64Url => b64([b64::URL_SAFE, b64::URL_SAFE_NO_PAD]),
+      Encoding::Base32 => b32([b32::STANDARD, b32::STANDARD_NO_PAD]),
+      Encoding::Base32Hex => b32([b32::HEX, b32::HEX_NO_PAD]),
+      Encoding::Ascii85 => A85Buffer::try_from_encoded(&joined).ok().map(|buffer| buffer.raw_bytes),
+      Encoding::Hex | Encoding::Raw => None,
+    }
+  }
+
+  // The natural log of the chance that `decoded` would be written out as
+  // `symbols`, the input with line breaks taken out, under `encoding`.
+  fn form_log_likelihood(encoding: Encoding, symbols: &[u8], decoded: &[u8]) -> f64 {
+    let mut share = ENCODED_SHARE * match encoding {
+      Encoding::Hex | Encoding::Raw => 1.0,
+      Encoding::Base64 | Encoding::Base64Url if symbols.len().is_multiple_of(4) => 1.0,
+      Encoding::Base64 | Encoding::Base64Url => UNPADDED_SHARE,
+      Encoding::Base32 | Encoding::Base32Hex if symbols.len().is_multiple_of(8) => 1.0,
+      Encoding::Base32 | Encoding::Base32Hex => UNPADDED_SHARE,
+      Encoding::Ascii85 if symbols.starts_with(b"<~") && symbols.ends_with(b"~>") => 1.0,
+      Encoding::Ascii85 => UNDELIMITED_SHARE,
+    };
+    if matches!(encoding, Encoding::Base32 | Encoding::Base32Hex) && symbols.iter().any(u8::is_ascii_lowercase) {
+      share *= LOWERCASE_SHARE;
+    }
+    if encoding == Encoding::Base32Hex {
+      share *= BASE32_HEX_SHARE;
+    }
+    if !is_canonical(encoding, symbols, decoded) {
+      share *= NONCANONICAL_SHARE;
+    }
+    float::ln(share) as f64
+  }
+
+  // Whether an encoder would have written `decoded` as `symbols`, give or
+  // take padding, delimiters and, outside ascii85, case. The decoders
+  // ignore stray bits in a final partial group, which is how most words
+  // manage to decode at all; an encoder never writes them.
+  fn is_canonical(encoding: Encoding, symbols: &[u8], decoded: &[u8]) -> bool {
+    let encoded = match encoding {
+      Encoding::Hex | Encoding::Raw => return true,
+      Encoding::Base64 => b64::encode(decoded, &b64::STANDARD),
+      Encoding::Base64Url => b64::encode(decoded, &b64::URL_SAFE),
+      Encoding::Base32 => b32::encode(decoded, &b32::STANDARD),
+      Encoding::Base32Hex => b32::encode(decoded, &b32::HEX),
+      Encoding::Ascii85 => a85::encode(decoded),
+    };
+    let normalize = |text: &[u8]| -> Vec<u8> {
+      let text = text.strip_prefix(b"<~").unwrap_or(text);
+      let text = text.strip_suffix(b"~>").unwrap_or(text);
+      if encoding == Encoding::Ascii85 {
+        return text.to_vec();
+      }
+      let end = text.iter().rposition(|&b| b != b'=').map_or(0, |i| i + 1);
+      text[..end].to_ascii_uppercase()
+    };
+    normalize(symbols) == normalize(&encoded)
+  }
+
+  // The natural log of the chance of `payload` as either text or binary,
+  // whichever explains it better.
+  fn payload_log_likelihood(payload: &[u8]) -> f64 {
+    let counts = strings::byte_counts(payload);
+    let text: f64 = (0..256)
+      .filter(|&b| counts[b] > 0)
+      .map(|b| {
+        let printable = if strings::is_text_byte(b as u8) { 1.0 / TEXT_BYTES } else { 0.0 };
+        counts[b] as f64 * float::ln((1.0 - PRINTABLE_SHARE) * strings::ENGLISH[b] + PRINTABLE_SHARE * printable) as f64
+      })
+      .sum();
+    let text = text + float::ln(1.0 - BINARY_SHARE) as f64;
+    let binary = float::ln(BINARY_SHARE) as f64 - payload.len() as f64 * float::ln(256.0) as f64;
+    let (larger, smaller) = if text > binary { (text, binary) } else { (binary, text) };
+    larger + float::ln(1.0 + float::exp((smaller - larger) as f32)) as f64
+  }
+
+  // The share of inputs that are encoded at all.
+  const ENCODED_SHARE: f32 = 0.03;
+  // How often encoded data leaves off the padding it needs, ascii85 its
+  // delimiters, or base32 is written in lowercase.
+  const UNPADDED_SHARE: f32 = 0.25;
+  const UNDELIMITED_SHARE: f32 = 0.25;
+  const LOWERCASE_SHARE: f32 = 0.25;
+  // How much rarer base32hex is than plain base32.
+  const BASE32_HEX_SHARE: f32 = 0.25;
+  // How often something that was encoded has stray bits in its last group.
+  const NONCANONICAL_SHARE: f32 = 0.01;
+  // The share of payloads that are binary rather than text.
+  const BINARY_SHARE: f32 = 0.01;
+  // How much of the text model is any of the TEXT_BYTES strings::is_text_byte
+  // accepts, so identifiers, digits and punctuation aren't written off.
+  const PRINTABLE_SHARE: f32 = 0.25;
+  const TEXT_BYTES: f32 = 98.0;
+}
+
+#[cfg(test)]
+mod tests {
+  use a85;
+  use a85::A85Buffer;
+  use a85::Z85Buffer;
+  use attack;
+  use b32;
+  use b32::B32Buffer;
+  use b58;
+  use b58::B58Buffer;
+  use b64;
+  use b64::B64Buffer;
+  use bytes::Bytes;
+  use detect;
+  use detect::Encoding;
+  use float;
+  use hex;
+  use hexdump;
+  use language;
+  use ngram;
+  use profile;
+  use profile::Profile;
+  use profile::ProfileError;
+  use profile::ProfileErrorKind;
+  use radix;
+  use score;
+  use score::Polarity;
+  use score::Scorer;
+  use url;
+  use url::CookieMap;
+  use url::CookieMode;
+  use xor;
+  use xor::Keystream;
+  use xor::LengthPolicy;
+  use xor::RepeatingKey;
+  use xor::RepeatingKeystream;
+  use hex::HexBuffer;
+  use DecodeError;
+  use DecodeErrorKind;
+  use Encodable;
+  use StringConstructible;
+  use TryStringConstructible;
+  use Xorable;
+  use std;
+  #[cfg(feature = "std")]
+  use std::io::Read;
+  #[cfg(feature = "std")]
+  use std::io::Write;
+  use strings;
+
+    // Hands out its contents one byte per read, to exercise partial groups.
+    #[cfg(feature = "std")]
+    struct TrickleReader<'a> {
+      data: &'a [u8],
+    }
+
+    #[cfg(feature = "std")]
+    impl<'a> Read for TrickleReader<'a> {
+      fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        if self.data.is_empty() || buf.is_empty() {
+          return Ok(0);
+        }
+        buf[0] = self.data[0];
+        self.data = &self.data[1..];
+        Ok(1)
+      }
+    }
+
+    #[test]
+    fn hex_buffer_converts_string() {
+      let buf = HexBuffer { raw_bytes: "hey! my name is Oliver, what's yours?".as_bytes().to_vec() };
+      assert_eq!(std::str::from_utf8(&buf.encode_to_utf8()).unwrap(), "68657921206d79206e616d65206973204f6c697665722c2077686174277320796f7572733f");
+    }
+
+    #[test]
+    fn hex_buffer_converts_string_and_back() {
+      let string = "hey! my name is Oliver, what's yours?";
+      let raw_bytes = string.as_bytes().to_vec();
+      let buf = HexBuffer { raw_bytes };
+      let encoded = buf.encode_to_utf8();
+      let new_buf = HexBuffer::from_encoded_utf8_buffer(&encoded);
+      let decoded_bin = new_buf.decode_to_bin();
+      let decoded = std::str::from_utf8(&decoded_bin).unwrap();
+      assert_eq!(decoded, string);
+    }
+
+    #[test]
+    fn b64_buffer_converts_string() {
+      let buf = B64Buffer { raw_bytes: "hey! my name is Oliver, what's yours?".as_bytes().to_vec() };
+      assert_eq!(std::str::from_utf8(&buf.encode_to_utf8()).unwrap(), "aGV5ISBteSBuYW1lIGlzIE9saXZlciwgd2hhdCdzIHlvdXJzPw==");
+    }
+
+    #[test]
+    fn b64_buffer_converts_string_and_back() {
+      let string = "hey! my name is Oliver, what's yours?";
+      let raw_bytes = string.as_bytes().to_vec();
+      let buf = B64Buffer { raw_bytes };
+      let encoded = buf.encode_to_utf8();
+      let new_buf = B64Buffer::from_encoded_utf8_buffer(&encoded);
+      let decoded_bin = new_buf.decode_to_bin();
+      let decoded = std::str::from_utf8(&decoded_bin).unwrap();
+      assert_eq!(decoded, string);
+    }
+
+    #[test]
+    fn hamming_distance_wokka_wokka() {
+      assert_eq!(strings::hamming_distance(b"this is a test", b"wokka wokka!!!"), 37);
+    }
+
+    #[test]
+    fn hamming_distance_counts_extra_bytes_as_eight_bits() {
+      assert_eq!(strings::hamming_distance(b"this is a test!", b"wokka wokka!!!"), 45);
+      assert_eq!(strings::hamming_distance(b"", b"ab"), 16);
+    }
+
+    #[test]
+    fn hamming_matches_bitwise_count_across_word_boundaries() {
+      let lhs: Vec<u8> = (0..37u8).map(|i| i.wrapping_mul(73)).collect();
+      let rhs: Vec<u8> = (0..37u8).map(|i| i.wrapping_mul(29) ^ 0x5a).collect();
+      let expected: u64 = lhs.iter().zip(&rhs).map(|(l, r)| (l ^ r).count_ones() as u64).sum();
+      assert_eq!(strings::hamming(&lhs, &rhs, LengthPolicy::Error), Ok(expected));
+      assert_eq!(strings::hamming(b"this is a test", b"wokka wokka!!!", LengthPolicy::Error), Ok(37));
+    }
+
+    #[test]
+    fn hamming_length_policies() {
+      assert_eq!(strings::hamming(b"abcd", b"ab", LengthPolicy::Error), Err(xor::LengthMismatch { lhs: 4, rhs: 2 }));
+      assert_eq!(strings::hamming(b"abcd", b"ab", LengthPolicy::Truncate), Ok(0));
+      assert_eq!(strings::hamming(b"abab", b"ab", LengthPolicy::Cycle), Ok(0));
+      assert_eq!(strings::hamming(b"abcb", b"ab", LengthPolicy::Cycle), Ok(1));
+      assert_eq!(strings::hamming(b"ab", b"", LengthPolicy::Cycle), Err(xor::LengthMismatch { lhs: 2, rhs: 0 }));
+    }
+
+    #[test]
+    fn normalized_hamming_is_bits_per_byte() {
+      assert_eq!(strings::normalized_hamming(&[0x00; 4], &[0xff; 4], LengthPolicy::Error), Ok(8.0));
+      assert_eq!(strings::normalized_hamming(&[0x00; 6], &[0x0f], LengthPolicy::Cycle), Ok(4.0));
+      assert_eq!(strings::normalized_hamming(b"abc", b"", LengthPolicy::Truncate), Ok(0.0));
+    }
+
+    #[test]
+    fn average_pairwise_hamming_averages_every_pair() {
+      // Pairs: (00, 0f) 4 bits, (00, ff) 8, (0f, ff) 4, over one byte each.
+      assert_eq!(strings::average_pairwise_hamming(&[0x00, 0x0f, 0xff], 1), Some(16.0 / 3.0));
+      assert_eq!(strings::average_pairwise_hamming(&[0x00, 0x00, 0xff, 0xff, 0x01], 2), Some(8.0));
+      assert_eq!(strings::average_pairwise_hamming(b"abc", 2), None);
+    }
+
+    #[test]
+    fn average_pairwise_hamming_favours_the_key_size() {
+      let text = b"Now that the party is jumping with the bass kicked in and the vegas are pumpin";
+      let ciphertext = xor::xor_sequential(text, b"ICE!x");
+      let best = (2..=12)
+        .min_by(|&a, &b| {
+          let a = strings::average_pairwise_hamming(&ciphertext, a).unwrap();
+          let b = strings::average_pairwise_hamming(&ciphertext, b).unwrap();
+          a.partial_cmp(&b).unwrap()
+        })
+        .unwrap();
+      assert_eq!(best % 5, 0);
+    }
+
+    #[test]
+    fn frequency_count_single_char_works() {
+      assert_eq!(strings::frequency("eeeeeeeeee")[101], 10);
+    }
+
+    #[test]
+    fn frequency_count_multiple_char_works() {
+      let freq = strings::frequency("eeeeeeeeeeaaaaa");
+      assert_eq!(freq[101], 10);
+      assert_eq!(freq[97], 5);
+    }
+
+    #[test]
+    fn frequency_bytes_counts_invalid_utf8() {
+      let freq = strings::frequency_bytes(b"EeE\xff\xfe");
+      assert_eq!(freq[101], 3);
+      assert_eq!(freq[0xff], 1);
+      assert_eq!(freq[0xfe], 1);
+    }
+
+    #[test]
+    fn score_bytes_penalizes_stray_bytes_proportionally() {
+      let clean = b"Now that the party is jumping with the bass kicked in".to_vec();
+      let mut stray = clean.clone();
+      stray[10] = 0xe9;
+      let clean_score = strings::score_bytes(&clean);
+      let stray_score = strings::score_bytes(&stray);
+      assert!(stray_score > 0.0);
+      assert!(stray_score < clean_score);
+      assert!(stray_score > clean_score * 0.8);
+      assert_eq!(strings::score_bytes(b""), 0.0);
+    }
+
+    #[test]
+    fn float_functions_match_known_values() {
+      for &(x, ln) in [(1.0f32, 0.0f32), (3.0, 1.098_612_3), (0.25, -1.386_294_4), (1e-40, -92.103_4), (12345.0, 9.421_006)].iter() {
+        assert!((float::ln(x) - ln).abs() <= ln.abs() * 1e-6 + 1e-7, "ln({})", x);
+      }
+      for &(x, sqrt) in [(4.0f32, 2.0f32), (5.0, 2.236_068), (1e-30, 1e-15), (3e20, 1.732_050_8e10)].iter() {
+        assert!((float::sqrt(x) - sqrt).abs() <= sqrt * 1e-6, "sqrt({})", x);
+      }
+      for &(x, exp) in [(0.0f32, 1.0f32), (1.5, 4.481_689), (-3.25, 0.038_774_208), (60.0, 1.142_007_4e26), (-80.0, 1.804_851_4e-35)].iter() {
+        assert!((float::exp(x) - exp).abs() <= exp * 2e-6, "exp({})", x);
+      }
+      assert!(float::ln(-1.0).is_nan());
+      assert_eq!(float::ln(0.0), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn english_distribution_sums_to_one() {
+      let total: f32 = strings::ENGLISH.iter().sum();
+      assert!((total - 1.0).abs() < 1e-4);
+      assert!(strings::ENGLISH[b' ' as usize] > strings::ENGLISH[b'e' as usize]);
+      assert!(strings::ENGLISH[b'e' as usize] > strings::ENGLISH[b'E' as usize]);
+      assert!(strings::ENGLISH[b'T' as usize] > strings::ENGLISH[b'E' as usize]);
+    }
+
+    #[test]
+    fn goodness_of_fit_prefers_english_on_short_columns() {
+      let plaintext = b"Cooking MC's like a pound of bacon";
+      let counts = strings::byte_counts(plaintext);
+      for key in 1..=255u8 {
+        let candidate = strings::byte_counts(&xor::xor_buffer(plaintext, &key));
+        assert!(strings::chi_squared(&candidate, &strings::ENGLISH) > strings::chi_squared(&counts, &strings::ENGLISH));
+        assert!(strings::g_test(&candidate, &strings::ENGLISH) > strings::g_test(&counts, &strings::ENGLISH));
+        assert!(strings::bhattacharyya(&candidate, &strings::ENGLISH) > strings::bhattacharyya(&counts, &strings::ENGLISH));
+      }
+    }
+
+    #[test]
+    fn goodness_of_fit_edge_cases() {
+      let empty = [0; 256];
+      assert_eq!(strings::chi_squared(&empty, &strings::ENGLISH), 0.0);
+      assert_eq!(strings::g_test(&empty, &strings::ENGLISH), 0.0);
+      assert_eq!(strings::bhattacharyya(&empty, &strings::ENGLISH), 0.0);
+
+      let mut only_a = [0.0; 256];
+      only_a[b'a' as usize] = 1.0;
+      let counts = strings::byte_counts(b"aaab");
+      assert_eq!(strings::chi_squared(&counts, &only_a), f32::INFINITY);
+      assert_eq!(strings::g_test(&counts, &only_a), f32::INFINITY);
+      assert!(strings::bhattacharyya(&strings::byte_counts(b"aaaa"), &only_a) < 1e-6);
+      assert_eq!(strings::bhattacharyya(&strings::byte_counts(b"bbbb"), &only_a), f32::INFINITY);
+    }
+
+    #[test]
+    fn entropy_and_index_of_coincidence() {
+      let all_bytes: Vec<u8> = (0..=255).collect();
+      assert_eq!(strings::entropy(b""), 0.0);
+      assert_eq!(strings::entropy(b"aaaa"), 0.0);
+      assert!((strings::entropy(b"abab") - 1.0).abs() < 1e-6);
+      assert!((strings::entropy(&all_bytes) - 8.0).abs() < 1e-5);
+
+      assert_eq!(strings::index_of_coincidence(b"a"), 0.0);
+      assert!((strings::index_of_coincidence(b"aabb") - 1.0 / 3.0).abs() < 1e-6);
+      assert_eq!(strings::index_of_coincidence(&all_bytes), 0.0);
+
+      let text: &[u8] = b"It was the best of times, it was the worst of times, it was the age of wisdom, it was the \
+        age of foolishness, it was the epoch of belief, it was the epoch of incredulity";
+      let english = strings::alphabetic_index_of_coincidence(text);
+      assert!(english > 0.055 && english < 0.08, "{}", english);
+      // Vigenere with the key LEMON flattens the letter frequencies.
+      let shifted: Vec<u8> = text.iter().filter(|b| b.is_ascii_alphabetic()).enumerate()
+        .map(|(i, b)| (b.to_ascii_lowercase() - b'a' + b"lemon"[i % 5] - b'a') % 26 + b'a')
+        .collect();
+      assert!(strings::alphabetic_index_of_coincidence(&shifted) < 0.05);
+      assert_eq!(strings::alphabetic_index_of_coincidence(b"A a"), 1.0);
+    }
+
+    #[test]
+    fn entropy_profile_finds_the_random_region() {
+      let mut state: u32 = 0x2545f491;
+      let mut blob = vec![b'A'; 1024];
+      blob.extend((0..1024).map(|_| {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        state as u8
+      }));
+      blob.extend_from_slice(&[b'z'; 100]);
+
+      let profile = strings::entropy_profile(&blob, 256, 64);
+      assert_eq!(profile.len(), (blob.len() - 256) / 64 + 1);
+      assert_eq!(profile[0], strings::EntropyWindow { offset: 0, len: 256, entropy: 0.0 });
+      for window in profile.iter() {
+        let expected = strings::entropy(&blob[window.offset..window.offset + window.len]);
+        assert!((window.entropy - expected).abs() < 1e-4);
+      }
+      let random = profile.iter().find(|w| w.offset == 1024).unwrap();
+      assert!(random.entropy > 7.0);
+
+      // Windows that don't overlap are counted afresh.
+      let apart = strings::entropy_profile(&blob, 100, 300);
+      assert_eq!(apart.iter().map(|w| w.offset).collect::<Vec<_>>(), vec![0, 300, 600, 900, 1200, 1500, 1800]);
+      assert_eq!(strings::entropy_profile(b"abab", 16, 4), vec![strings::EntropyWindow { offset: 0, len: 4, entropy: 1.0 }]);
+      assert!(strings::entropy_profile(b"", 16, 4).is_empty());
+    }
+
+    #[test]
+    fn ngram_fold_maps_bytes_onto_model_symbols() {
+      let folded: Vec<u8> = b"Hi,\tYOU\n\x00\xff".iter().map(|&b| ngram::fold(b)).collect();
+      assert_eq!(folded, b"hi, you \x00\x00");
+    }
+
+    #[test]
+    fn ngram_lookup_folds_and_falls_back_to_floor() {
+      let model = &ngram::ENGLISH_QUADGRAMS;
+      assert_eq!(model.order(), 4);
+      assert_eq!(model.log_probability(b"THE "), model.log_probability(b"the\n"));
+      assert!(model.log_probability(b"the ") > -3.0);
+      assert_eq!(model.log_probability(b"xqzj"), model.floor());
+      assert_eq!(model.log_likelihood(b"the"), 0.0);
+      assert_eq!(model.fitness(b"the"), model.floor());
+    }
+
+    #[test]
+    fn ngram_fitness_tells_text_from_letter_soup() {
+      let text = b"Now that the party is jumping with the bass kicked in and the vegas are pumpin";
+      // Same bytes, so the same letter frequencies, in a scrambled order.
+      let soup: Vec<u8> = (0..text.len()).map(|i| text[i * 7 % text.len()]).collect();
+      assert_eq!(strings::byte_counts(text)[..], strings::byte_counts(&soup)[..]);
+      for model in [&ngram::ENGLISH_BIGRAMS, &ngram::ENGLISH_TRIGRAMS, &ngram::ENGLISH_QUADGRAMS].iter() {
+        assert!(model.fitness(text) > model.fitness(&soup) + 0.3, "order {}", model.order());
+      }
+    }
+
+    #[test]
+    fn ngram_fitness_breaks_single_byte_xor() {
+      let ciphertext = Bytes::from_hex(b"1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736").unwrap();
+      let key = (0..=255u8).max_by(|&a, &b| {
+        let a = ngram::ENGLISH_QUADGRAMS.fitness(&ciphertext.xor_value(&a));
+        let b = ngram::ENGLISH_QUADGRAMS.fitness(&ciphertext.xor_value(&b));
+        a.partial_cmp(&b).unwrap()
+      });
+      assert_eq!(key, Some(88));
+    }
+
+    #[test]
+    #[should_panic(expected = "n-grams must be strictly ascending")]
+    fn ngram_model_rejects_unsorted_grams() {
+      ngram::NgramModel::new(2, b"thhe", &[-1.0, -2.0], -5.0);
+    }
+
+    #[test]
+    fn scorers_rank_english_above_xored_text() {
+      let plaintext: &[u8] = b"Now that the party is jumping with the bass kicked in and the vegas are pumpin";
+      let garbled = xor::xor_buffer(plaintext, &0x5c);
+      let scorers: [&dyn Scorer; 7] = [
+        &score::Normality, &score::PrintableRatio, &score::ENGLISH_CHI_SQUARED, &score::ENGLISH_G_TEST,
+        &score::ENGLISH_BHATTACHARYYA, &ngram::ENGLISH_QUADGRAMS,
+        &score::Ensemble::new(vec![(&score::PrintableRatio as &dyn Scorer, 1.0), (&ngram::ENGLISH_BIGRAMS, 2.0)]),
+      ];
+      for scorer in scorers.iter() {
+        assert!(scorer.prefers(scorer.score(plaintext), scorer.score(&garbled)));
+        let (good, bad) = (scorer.normalized(plaintext), scorer.normalized(&garbled));
+        assert!(good > bad && good <= 1.0 && bad >= 0.0, "{} vs {}", good, bad);
+      }
+    }
+
+    #[test]
+    fn scorer_polarity_and_threshold() {
+      let chi = &score::ENGLISH_CHI_SQUARED;
+      assert_eq!(chi.polarity(), Polarity::LowerIsBetter);
+      assert!(chi.prefers(1.0, 2.0));
+      assert!(!chi.prefers(f32::NAN, 2.0));
+      assert!(!chi.good_enough(0.0));
+
+      let mut ensemble = score::Ensemble::new(vec![(&score::PrintableRatio as &dyn Scorer, 3.0)]);
+      assert_eq!(ensemble.score(b"ab\x00\x01"), 0.5);
+      ensemble.threshold = Some(0.5);
+      assert!(ensemble.good_enough(0.5));
+      assert!(!ensemble.good_enough(0.49));
+      assert_eq!(score::Ensemble::new(Vec::new()).score(b"abc"), 0.0);
+    }
+
+    #[test]
+    fn attack_single_byte_xor_with_any_scorer() {
+      let ciphertext = Bytes::from_hex(b"1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736").unwrap();
+      let scorers: [&dyn Scorer; 4] =
+        [&score::Normality, &score::ENGLISH_CHI_SQUARED, &score::ENGLISH_BHATTACHARYYA, &ngram::ENGLISH_QUADGRAMS];
+      for scorer in scorers.iter() {
+        let guess = attack::single_byte_xor(&ciphertext, *scorer);
+        assert_eq!(guess.key, vec![88]);
+        assert_eq!(guess.plaintext, b"Cooking MC's like a pound of bacon");
+      }
+
+      // Anything printable is good enough here, so the search stops at the
+      // first key that gets there.
+      let mut printable = score::Ensemble::new(vec![(&score::PrintableRatio as &dyn Scorer, 1.0)]);
+      printable.threshold = Some(1.0);
+      let guess = attack::single_byte_xor(&ciphertext, &printable);
+      assert!(guess.key[0] < 88);
+      assert_eq!(guess.score, 1.0);
+    }
+
+    #[test]
+    fn attack_repeating_key_xor_and_best() {
+      let plaintext: &[u8] = b"Burning 'em, if you ain't quick and nimble\nI go crazy when I hear a cymbal \
+        and a high hat with a souped up tempo. I'm on a roll, it's time to go solo, rollin' in my five point oh";
+      let ciphertext = xor::xor_sequential(plaintext, b"ICE");
+      let scorer = &score::ENGLISH_CHI_SQUARED;
+      let guesses: Vec<attack::Guess> = (2..6).map(|length| attack::repeating_key_xor(&ciphertext, length, scorer)).collect();
+      assert_eq!(guesses[1].key, b"ICE");
+      let best = attack::best(guesses, scorer).unwrap();
+      assert_eq!(best.key, b"ICE");
+      assert_eq!(best.plaintext, plaintext);
+      assert_eq!(attack::best(Vec::new(), scorer), None);
+    }
+
+    fn json_log() -> Vec<u8> {
+      let mut corpus = Vec::new();
+      for i in 0..200 {
+        let line = format!("{{\"ts\":{},\"level\":\"{}\",\"msg\":\"request {} served\",\"status\":{}}}\n",
+          1700000000 + i * 7, ["info", "warn", "debug"][i % 3], i * 31 % 977, [200, 404, 500][i % 3]);
+        corpus.extend_from_slice(line.as_bytes());
+      }
+      corpus
+    }
+
+    #[test]
+    fn profile_trainer_ignores_chunking() {
+      let corpus = json_log();
+      let mut trainer = profile::Trainer::new(3);
+      for chunk in corpus.chunks(7) {
+        trainer.feed(chunk);
+      }
+      assert_eq!(trainer.finish("logs", 500), Profile::train("logs", &corpus, 3, 500));
+    }
+
+    #[test]
+    fn profile_round_trips_through_text() {
+      let trained = Profile::train("json logs", &json_log(), 3, 300);
+      let encoded = trained.encode();
+      assert!(encoded.starts_with(b"utilities-profile 1\nname json%20logs\norder 3\n"));
+      let parsed = Profile::parse(&encoded).unwrap();
+      assert_eq!(parsed, trained);
+      assert_eq!(parsed.name(), "json logs");
+      assert_eq!(parsed.ngram_model().order(), 3);
+
+      let with_comments = [b"# made by hand\n\n".to_vec(), encoded.clone()].concat();
+      assert_eq!(Profile::parse(&with_comments).unwrap(), trained);
+    }
+
+    #[test]
+    fn profile_scores_its_own_kind_of_text() {
+      let profile = Profile::train("json logs", &json_log(), 3, 2000);
+      let log_line: &[u8] = b"{\"ts\":1700009999,\"level\":\"warn\",\"msg\":\"request 12 served\",\"status\":404}";
+      let prose: &[u8] = b"Now that the party is jumping with the bass kicked in and the vegas are pumpin";
+      let model = profile.ngram_model();
+      assert!(model.fitness(log_line) > model.fitness(prose));
+      assert!(ngram::ENGLISH_TRIGRAMS.fitness(log_line) < ngram::ENGLISH_TRIGRAMS.fitness(prose));
+
+      let ciphertext = xor::xor_buffer(log_line, &0x3a);
+      assert_eq!(attack::single_byte_xor(&ciphertext, &model).key, vec![0x3a]);
+      let chi = score::ChiSquared(profile.distribution());
+      assert_eq!(attack::single_byte_xor(&ciphertext, &chi).key, vec![0x3a]);
+    }
+
+    #[test]
+    fn profile_parse_errors() {
+      use profile::ProfileErrorKind::*;
+      let cases: [(&[u8], ProfileErrorKind, usize); 9] = [
+        (b"", UnknownFormat, 1),
+        (b"utilities-profile 2\n", UnknownFormat, 1),
+        (b"utilities-profile 1\nname x\nweight 3\n", UnknownKey, 3),
+        (b"utilities-profile 1\ngram abc 3\n", MissingField, 2),
+        (b"utilities-profile 1\norder 3\ngram ab 3\n", InvalidValue, 3),
+        (b"utilities-profile 1\nbyte 41 3\nbyte 41 4\n", Duplicate, 3),
+        (b"utilities-profile 1\nname x\norder 2\n", MissingField, 3),
+        (b"utilities-profile 1\nname x\norder 2\ngrams 0\ngram ab 0\n", InvalidValue, 5),
+        (b"utilities-profile 1\nname x\norder 1\ngrams 0\ngram a 1\n", InvalidValue, 5),
+      ];
+      for &(text, kind, line) in cases.iter() {
+        assert_eq!(Profile::parse(text), Err(ProfileError::new(kind, line)), "{:?}", String::from_utf8_lossy(text));
+      }
+      let unfolded = b"utilities-profile 1\nname x\norder 2\ngrams 9\ngram AB 3\n";
+      assert_eq!(Profile::parse(unfolded).unwrap_err().kind, InvalidValue);
+      assert_eq!(ProfileError::new(Duplicate, 4).to_string(), "duplicate entry on line 4");
+
+      // Every possible order-1 gram kept, under a total that leaves some
+      // mass over with nowhere to go.
+      let all: Vec<u8> = (0..=255).collect();
+      let full = Profile::train("full", &all, 1, ngram::SYMBOLS).encode();
+      let full = String::from_utf8(full).unwrap().replace("grams 256\n", "grams 300\n");
+      let model = Profile::parse(full.as_bytes()).unwrap();
+      let model = model.ngram_model();
+      let lowest = (0..=255u8).map(|b| model.log_probability(&[ngram::fold(b)])).fold(0.0, f32::min);
+      assert!(model.floor().is_finite() && model.floor() <= lowest);
+      assert!(model.fitness(b"").is_finite() && model.fitness(b"a").is_finite());
+    }
+
+    static SAMPLES: [(&str, &str); 6] = [
+      ("en", "It was the best of times, it was the worst of times, it was the age of wisdom, it was the age of \
+        foolishness, it was the epoch of belief, it was the epoch of incredulity, it was the season of Light, it \
+        was the season of Darkness, it was the spring of hope, it was the winter of despair."),
+      ("fr", "Le petit prince s'en fut revoir les roses. Vous n'êtes pas du tout semblables à ma rose, vous n'êtes \
+        rien encore, leur dit-il. Personne ne vous a apprivoisées et vous n'avez apprivoisé personne. Vous êtes \
+        comme était mon renard. Ce n'était qu'un renard semblable à cent mille autres."),
+      ("de", "Als Gregor Samsa eines Morgens aus unruhigen Träumen erwachte, fand er sich in seinem Bett zu einem \
+        ungeheueren Ungeziefer verwandelt. Er lag auf seinem panzerartig harten Rücken und sah, wenn er den Kopf \
+        ein wenig hob, seinen gewölbten, braunen, von bogenförmigen Versteifungen geteilten Bauch."),
+      ("es", "En un lugar de la Mancha, de cuyo nombre no quiero acordarme, no ha mucho tiempo que vivía un hidalgo \
+        de los de lanza en astillero, adarga antigua, rocín flaco y galgo corredor. Una olla de algo más vaca que \
+        carnero, salpicón las más noches, duelos y quebrantos los sábados, lantejas los viernes."),
+      ("it", "Nel mezzo del cammin di nostra vita mi ritrovai per una selva oscura, ché la diritta via era smarrita. \
+        Ahi quanto a dir qual era è cosa dura esta selva selvaggia e aspra e forte che nel pensier rinova la paura! \
+        Tant'è amara che poco è più morte; ma per trattar del ben ch'i' vi trovai, dirò de l'altre cose."),
+      ("pt", "Não sei quantas almas tenho. Cada momento mudei. Continuamente me estranho. Nunca me vi nem acabei. \
+        De tanto ser, só tenho alma. Quem tem alma não tem calma. Quem vê é só o que vê, quem sente não é quem é. \
+        Atento ao que sou e vejo, torno-me eles e não eu. Cada meu sonho ou desejo é do que nasce e não meu."),
+    ];
+
+    #[test]
+    fn language_identifies_samples() {
+      for &(code, text) in SAMPLES.iter() {
+        let matches = language::identify_language(text.as_bytes());
+        assert_eq!(matches.len(), language::LANGUAGES.len());
+        assert_eq!(matches[0].language.code, code, "{:?}", matches);
+        assert!(matches[0].similarity > matches[1].similarity);
+      }
+      assert_eq!(language::identify_language(b"1234")[0].similarity, 0.0);
+    }
+
+    #[test]
+    fn language_letter_counts_read_utf8_accents() {
+      let input = ["Ça été Über\u{d7}".as_bytes(), b"\xff"].concat();
+      let (counts, plausible) = language::letter_counts(&input);
+      assert_eq!(counts[0xe7], 1);
+      assert_eq!(counts[0xe9], 2);
+      assert_eq!(counts[0xfc], 1);
+      assert_eq!(counts[b'a' as usize], 1);
+      assert_eq!(counts[0xd7] + counts[0xf7], 0);
+      assert_eq!(plausible, 15);
+      assert_eq!(language::GERMAN.ranking()[..3], b"ens"[..]);
+      // Equal shares fall back to alphabetical order.
+      let tied = language::Language { name: "Tied", code: "xx", letters: &[(b'b', 1.0), (b'a', 1.0), (b'c', 2.0)] };
+      assert_eq!(tied.ranking(), b"cab".to_vec());
+      assert_eq!(language::ENGLISH.letters, &strings::ENGLISH_LETTERS[..]);
+      assert!(language::FRENCH.normality("Le petit prince".as_bytes()) > 0.0);
+    }
+
+    #[test]
+    fn language_scorer_breaks_non_english_xor() {
+      let plaintext = SAMPLES[1].1.as_bytes();
+      let ciphertext = xor::xor_buffer(plaintext, &0x4f);
+      let guess = attack::single_byte_xor(&ciphertext, &language::FRENCH);
+      assert_eq!(guess.key, vec![0x4f]);
+      assert_eq!(guess.plaintext, plaintext);
+    }
+
+    #[test]
+    fn single_xor_buffer() {
       let buf = HexBuffer::from_encoded_utf8_buffer(b"1b37373331363f78151b7f2b783431333d78397828372d363c78373e783a393b3736");
       let xored = buf.xor_value(&88);
       let decoded_bin = xored.decode_to_bin();
       let decoded_string = std::str::from_utf8(&decoded_bin).unwrap();
       assert_eq!(decoded_string, "Cooking MC\'s like a pound of bacon");
     }
+
+    #[test]
+    fn hex_decode_rejects_invalid_symbol() {
+      let err = HexBuffer::try_from_encoded(b"00ff0g").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'g'), 5));
+    }
+
+    #[test]
+    fn hex_decode_rejects_odd_length() {
+      let err = HexBuffer::try_from_encoded_utf8_buffer(b"abc").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::OddLength, Some(b'c'), 2));
+    }
+
+    #[test]
+    fn b64_decode_rejects_bad_input() {
+      let err = B64Buffer::try_from_encoded(b"aGV5I$==").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'$'), 5));
+      let err = B64Buffer::try_from_encoded(b"aGV5IQ").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidPadding, None, 6));
+      let err = B64Buffer::try_from_encoded(b"aG=5").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidPadding, Some(b'='), 2));
+      let err = B64Buffer::try_from_encoded(b"aGU=aGV5").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::TrailingData, Some(b'a'), 4));
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid hex input")]
+    fn hex_from_encoded_panics_on_garbage() {
+      HexBuffer::from_encoded_utf8_buffer(b"zz");
+    }
+
+    #[test]
+    fn b64_url_safe_round_trips_without_padding() {
+      let buf = B64Buffer { raw_bytes: vec![0xfb, 0xff, 0xbf, 0x3e] };
+      assert_eq!(buf.encode_with_config(&b64::URL_SAFE_NO_PAD), b"-_-_Pg".to_vec());
+      assert_eq!(buf.encode_with_config(&b64::URL_SAFE), b"-_-_Pg==".to_vec());
+      let decoded = B64Buffer::try_from_encoded_with_config(b"-_-_Pg", &b64::URL_SAFE_NO_PAD).unwrap();
+      assert_eq!(decoded.raw_bytes, buf.raw_bytes);
+      let err = B64Buffer::try_from_encoded_with_config(b"-_-_Pg==", &b64::URL_SAFE_NO_PAD).unwrap_err();
+      assert_eq!(err.kind, DecodeErrorKind::InvalidPadding);
+    }
+
+    #[test]
+    fn b64_wraps_and_decodes_wrapped_lines() {
+      let buf = B64Buffer { raw_bytes: vec![0; 60] };
+      let encoded = buf.encode_with_config(&b64::PEM);
+      assert_eq!(encoded.len(), 80 + 1);
+      assert_eq!(encoded[64], b'\n');
+      let decoded = B64Buffer::try_from_encoded_with_config(&encoded, &b64::PEM).unwrap();
+      assert_eq!(decoded.raw_bytes, buf.raw_bytes);
+
+      let err = B64Buffer::try_from_encoded(&encoded).unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'\n'), 64));
+      let err = B64Buffer::try_from_encoded_with_config(b"aGV5\r\nI$==", &b64::MIME).unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'$'), 7));
+    }
+
+    #[test]
+    fn hex_decodes_either_case() {
+      let upper = HexBuffer::try_from_encoded(b"DEADBEEF").unwrap();
+      let mixed = HexBuffer::try_from_encoded(b"DeAdbEeF").unwrap();
+      assert_eq!(upper.raw_bytes, vec![0xde, 0xad, 0xbe, 0xef]);
+      assert_eq!(mixed.raw_bytes, upper.raw_bytes);
+    }
+
+    #[test]
+    fn hex_decodes_tool_formatted_input() {
+      let expected = vec![0xde, 0xad, 0xbe, 0xef];
+      for input in [&b"de:ad:be:ef"[..], b"DE AD BE EF", b"0xdeadbeef", b"\\xde\\xad\\xbe\\xef",
+                    b"0xde, 0xad, 0xbe, 0xef", b"dead-beef\n"].iter() {
+        assert_eq!(HexBuffer::try_from_formatted(input).unwrap().raw_bytes, expected);
+      }
+
+      let err = HexBuffer::try_from_formatted(b"de:a:d").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b':'), 4));
+      let err = HexBuffer::try_from_formatted(b"de ad b").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::OddLength, Some(b'b'), 6));
+    }
+
+    #[test]
+    fn hex_encodes_with_format() {
+      let buf = HexBuffer { raw_bytes: vec![0xde, 0xad, 0xbe, 0xef] };
+      assert_eq!(buf.encode_with_format(&hex::UPPER), b"DEADBEEF".to_vec());
+      assert_eq!(buf.encode_with_format(&hex::COLON), b"de:ad:be:ef".to_vec());
+      assert_eq!(buf.encode_with_format(&hex::ESCAPED), b"\\xde\\xad\\xbe\\xef".to_vec());
+      assert_eq!(buf.encode_with_format(&hex::C_ARRAY), b"0xde, 0xad, 0xbe, 0xef".to_vec());
+      let words = hex::HexFormat { group_size: 2, ..hex::SPACED };
+      assert_eq!(buf.encode_with_format(&words), b"dead beef".to_vec());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hex_streams_round_trip() {
+      let data: Vec<u8> = (0..=255).collect();
+      let mut encoder = hex::Encoder::new(Vec::new());
+      for chunk in data.chunks(7) {
+        encoder.write_all(chunk).unwrap();
+      }
+      let encoded = encoder.into_inner();
+      assert_eq!(encoded, HexBuffer { raw_bytes: data.clone() }.encode_to_utf8());
+
+      let mut decoded = Vec::new();
+      hex::Decoder::new(TrickleReader { data: &encoded }).read_to_end(&mut decoded).unwrap();
+      assert_eq!(decoded, data);
+
+      let mut decoded = Vec::new();
+      hex::Decoder::skipping_whitespace(&b"de ad\nbe\nef\n"[..]).read_to_end(&mut decoded).unwrap();
+      assert_eq!(decoded, vec![0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn hex_stream_reports_decode_errors() {
+      let err = hex::Decoder::new(TrickleReader { data: b"deadbee" }).read_to_end(&mut Vec::new()).unwrap_err();
+      assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+      let inner = err.get_ref().unwrap().downcast_ref::<DecodeError>().unwrap();
+      assert_eq!(*inner, DecodeError::new(DecodeErrorKind::OddLength, Some(b'e'), 6));
+
+      // The input arrives in two reads, the second with good digits ahead of
+      // the bad one. Both reads' bytes come out before the error, which is
+      // placed from the start of the stream.
+      let mut decoder = hex::Decoder::new((&b"6869"[..]).chain(&b"6a6bzz"[..]));
+      let mut decoded = Vec::new();
+      let err = decoder.read_to_end(&mut decoded).unwrap_err();
+      assert_eq!(decoded, b"hijk".to_vec());
+      let inner = err.get_ref().unwrap().downcast_ref::<DecodeError>().unwrap();
+      assert_eq!(*inner, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'z'), 8));
+      let again = decoder.read(&mut [0; 4]).unwrap_err();
+      assert_eq!(again.get_ref().unwrap().downcast_ref::<DecodeError>(), Some(inner));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn b64_streams_round_trip_across_chunk_boundaries() {
+      let data: Vec<u8> = (0..200).map(|i| (i * 7) as u8).collect();
+      let buf = B64Buffer { raw_bytes: data.clone() };
+      for chunk_size in [1, 2, 4, 5, 64].iter() {
+        let mut encoder = b64::Encoder::new(Vec::new(), &b64::PEM);
+        for chunk in data.chunks(*chunk_size) {
+          encoder.write_all(chunk).unwrap();
+        }
+        let encoded = encoder.finish().unwrap();
+        assert_eq!(encoded, buf.encode_with_config(&b64::PEM));
+
+        let mut decoded = Vec::new();
+        b64::Decoder::new(TrickleReader { data: &encoded }, &b64::PEM).read_to_end(&mut decoded).unwrap();
+        assert_eq!(decoded, data);
+      }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn b64_stream_reports_decode_errors() {
+      let mut decoder = b64::Decoder::new(TrickleReader { data: b"aGU=aGV5" }, &b64::STANDARD);
+      let mut decoded = Vec::new();
+      let err = decoder.read_to_end(&mut decoded).unwrap_err();
+      assert_eq!(decoded, b"he".to_vec());
+      let inner = err.get_ref().unwrap().downcast_ref::<DecodeError>().unwrap();
+      assert_eq!(*inner, DecodeError::new(DecodeErrorKind::TrailingData, Some(b'a'), 4));
+      let again = decoder.read(&mut [0; 4]).unwrap_err();
+      assert_eq!(again.get_ref().unwrap().downcast_ref::<DecodeError>(), Some(inner));
+
+      // A bad symbol inside one read still lets the groups before it through.
+      let mut decoder = b64::Decoder::new(&b"QUJD$EVGR0hJ"[..], &b64::STANDARD);
+      let mut decoded = Vec::new();
+      let err = decoder.read_to_end(&mut decoded).unwrap_err();
+      assert_eq!(decoded, b"ABC".to_vec());
+      let inner = err.get_ref().unwrap().downcast_ref::<DecodeError>().unwrap();
+      assert_eq!(*inner, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'$'), 4));
+
+      // Truncated input fails at the end of the stream, and keeps failing
+      // rather than turning into a clean EOF.
+      let mut decoder = b64::Decoder::new(&b"QQ"[..], &b64::STANDARD);
+      let err = decoder.read(&mut [0; 4]).unwrap_err();
+      let inner = err.get_ref().unwrap().downcast_ref::<DecodeError>().unwrap();
+      assert_eq!(inner.kind, DecodeErrorKind::InvalidPadding);
+      assert!(decoder.read(&mut [0; 4]).is_err());
+    }
+
+    #[test]
+    fn codecs_encode_and_decode_into_slices() {
+      let data: Vec<u8> = (0..100).map(|i| (i * 37 + 11) as u8).collect();
+      for len in 0..data.len() {
+        let input = &data[..len];
+
+        let mut encoded = vec![0; hex::encoded_len(len)];
+        assert_eq!(hex::encode_into(input, &mut encoded), encoded.len());
+        let mut decoded = vec![0; hex::decoded_len(encoded.len())];
+        assert_eq!(hex::decode_into(&encoded, &mut decoded), Ok(len));
+        assert_eq!(&decoded[..], input);
+
+        for config in [b64::STANDARD, b64::URL_SAFE_NO_PAD, b64::MIME, b64::B64Config { line_width: Some(7), ..b64::PEM }].iter() {
+          let mut encoded = vec![0; b64::encoded_len(len, config)];
+          assert_eq!(b64::encode_into(input, &mut encoded, config), encoded.len());
+          let mut decoded = vec![0; b64::decoded_max_len(encoded.len())];
+          let decoded_len = b64::decode_into(&encoded, &mut decoded, config).unwrap();
+          assert_eq!(&decoded[..decoded_len], input);
+        }
+      }
+    }
+
+    #[test]
+    fn fast_paths_report_the_offending_symbol() {
+      let mut encoded = HexBuffer { raw_bytes: vec![0x5a; 40] }.encode_to_utf8();
+      encoded[37] = b'.';
+      let err = HexBuffer::try_from_encoded(&encoded).unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'.'), 37));
+
+      let mut encoded = B64Buffer { raw_bytes: vec![0x5a; 40] }.encode_to_utf8();
+      encoded[21] = b'.';
+      let err = B64Buffer::try_from_encoded(&encoded).unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'.'), 21));
+    }
+
+    #[test]
+    fn b32_matches_rfc_4648_vectors() {
+      let vectors = [("", "", ""), ("f", "MY======", "CO======"), ("fo", "MZXQ====", "CPNG===="),
+                     ("foo", "MZXW6===", "CPNMU==="), ("foob", "MZXW6YQ=", "CPNMUOG="),
+                     ("fooba", "MZXW6YTB", "CPNMUOJ1"), ("foobar", "MZXW6YTBOI======", "CPNMUOJ1E8======")];
+      for &(plain, standard, hex) in vectors.iter() {
+        let buf = B32Buffer { raw_bytes: plain.as_bytes().to_vec() };
+        assert_eq!(buf.encode_to_utf8(), standard.as_bytes());
+        assert_eq!(buf.encode_with_config(&b32::HEX), hex.as_bytes());
+        assert_eq!(B32Buffer::from_encoded_utf8_buffer(standard.as_bytes()).raw_bytes, plain.as_bytes());
+        assert_eq!(B32Buffer::try_from_encoded_with_config(hex.as_bytes(), &b32::HEX).unwrap().raw_bytes, plain.as_bytes());
+      }
+    }
+
+    #[test]
+    fn b32_handles_unpadded_and_crockford_input() {
+      let buf = B32Buffer::try_from_encoded_with_config(b"jbswy3dpehpk3pxp", &b32::STANDARD_NO_PAD).unwrap();
+      assert_eq!(buf.raw_bytes, b"Hello!\xde\xad\xbe\xef".to_vec());
+
+      let buf = B32Buffer { raw_bytes: vec![0x01, 0xdd, 0x3e, 0x62, 0xfa] };
+      assert_eq!(buf.encode_with_config(&b32::CROCKFORD), b"07EKWRQT".to_vec());
+      let decoded = B32Buffer::try_from_encoded_with_config(b"o7ek-wrqt", &b32::CROCKFORD).unwrap();
+      assert_eq!(decoded.raw_bytes, buf.raw_bytes);
+      assert_eq!(radix::decode(b"0IL1", &radix::BASE32_CROCKFORD).unwrap(), radix::decode(b"0111", &radix::BASE32_CROCKFORD).unwrap());
+      // Hyphens don't count towards the padded group.
+      let padded = b32::B32Config { padding: true, ..b32::CROCKFORD };
+      let mut encoded = b32::encode(b"fo", &padded);
+      assert_eq!(encoded.len(), 8);
+      encoded.insert(2, b'-');
+      assert_eq!(B32Buffer::try_from_encoded_with_config(&encoded, &padded).unwrap().raw_bytes, b"fo".to_vec());
+
+      let err = B32Buffer::try_from_encoded_with_config(b"MZXQ====", &b32::STANDARD_NO_PAD).unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidPadding, Some(b'='), 4));
+    }
+
+    #[test]
+    fn b32_decode_rejects_bad_input() {
+      let err = B32Buffer::try_from_encoded(b"MZXW6===MZ").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::TrailingData, Some(b'M'), 8));
+      let err = B32Buffer::try_from_encoded(b"MZXW6==").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidPadding, None, 5));
+      let err = B32Buffer::try_from_encoded(b"MZX=====").unwrap_err();
+      assert_eq!(err.kind, DecodeErrorKind::InvalidPadding);
+      let err = B32Buffer::try_from_encoded(b"MZ1W6===").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'1'), 2));
+    }
+
+    #[test]
+    fn a85_matches_adobe_vectors() {
+      let vectors = [(&b"Man is distinguished"[..], &b"<~9jqo^BlbD-BleB1DJ+*+F(f,q~>"[..]), (b"sure.", b"<~F*2M7/c~>"),
+                     (b"\0\0\0\0abc", b"<~z@:E^~>"), (b"\xff\xff\xff\xff", b"<~s8W-!~>"), (b"", b"<~~>")];
+      for &(plain, encoded) in vectors.iter() {
+        assert_eq!(A85Buffer { raw_bytes: plain.to_vec() }.encode_to_utf8(), encoded);
+        assert_eq!(A85Buffer::from_encoded_utf8_buffer(encoded).raw_bytes, plain);
+      }
+      // PDF streams often drop the opening delimiter and wrap lines.
+      assert_eq!(A85Buffer::try_from_encoded(b"F*2M\n7/c~>\n").unwrap().raw_bytes, b"sure.".to_vec());
+    }
+
+    #[test]
+    fn a85_decode_rejects_bad_input() {
+      let err = A85Buffer::try_from_encoded(b"<~F*2M7/c~>x").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::TrailingData, Some(b'x'), 11));
+      let err = A85Buffer::try_from_encoded(b"<~F*z~>").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'z'), 4));
+      let err = A85Buffer::try_from_encoded(b"<~s8W-\"~>").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::Overflow, Some(b'"'), 6));
+      let err = A85Buffer::try_from_encoded(b"<~F*2M7/~>").unwrap_err();
+      assert_eq!(err.kind, DecodeErrorKind::InvalidPadding);
+    }
+
+    #[test]
+    fn z85_matches_spec_vector() {
+      let raw_bytes = vec![0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+      assert_eq!(Z85Buffer { raw_bytes: raw_bytes.clone() }.encode_to_utf8(), b"HelloWorld".to_vec());
+      assert_eq!(Z85Buffer::from_encoded_utf8_buffer(b"HelloWorld").raw_bytes, raw_bytes);
+      let err = Z85Buffer::try_from_encoded(b"Hello World").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b' '), 5));
+    }
+
+    #[test]
+    fn z85_rejects_partial_groups() {
+      assert_eq!(a85::decode_z85(b"abcd"), Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, 4)));
+      assert_eq!(a85::decode_z85(b"HelloWorl"), Err(DecodeError::new(DecodeErrorKind::InvalidPadding, None, 9)));
+      assert_eq!(a85::decode_z85(b""), Ok(Vec::new()));
+    }
+
+    #[test]
+    #[should_panic(expected = "multiple of 4 bytes")]
+    fn z85_encode_rejects_partial_groups() {
+      a85::encode_z85(b"sure.");
+    }
+
+    #[test]
+    fn b58_matches_reference_vectors() {
+      let buf = B58Buffer { raw_bytes: b"Hello World!".to_vec() };
+      assert_eq!(buf.encode_to_utf8(), b"2NEpo7TZRRrLZSi2U".to_vec());
+      assert_eq!(buf.encode_with_alphabet(b58::Alphabet::Flickr), b"2nePN7syqqRkyrH2t".to_vec());
+      assert_eq!(buf.encode_with_alphabet(b58::Alphabet::Ripple), b"p4NFofTZRRiLZS5p7".to_vec());
+      assert_eq!(B58Buffer::from_encoded_utf8_buffer(b"2NEpo7TZRRrLZSi2U").raw_bytes, buf.raw_bytes);
+
+      let zeros = B58Buffer { raw_bytes: vec![0, 0, 0x28, 0x7f, 0xb4, 0xcd] };
+      assert_eq!(zeros.encode_to_utf8(), b"11233QC4".to_vec());
+      assert_eq!(B58Buffer::from_encoded_utf8_buffer(b"11233QC4").raw_bytes, zeros.raw_bytes);
+
+      let err = B58Buffer::try_from_encoded(b"2NEpo0TZ").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::InvalidSymbol, Some(b'0'), 5));
+    }
+
+    #[test]
+    fn b58check_verifies_checksum() {
+      let address = b"1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAs";
+      let hash = HexBuffer::from_encoded_utf8_buffer(b"f54a5851e9372b87810a8e60cdd2e7cfd80b6e31");
+      let buf = B58Buffer { raw_bytes: hash.raw_bytes.clone() };
+      assert_eq!(buf.encode_check(0, b58::Alphabet::Bitcoin), address.to_vec());
+
+      let (version, decoded) = B58Buffer::try_from_check_encoded(address, b58::Alphabet::Bitcoin).unwrap();
+      assert_eq!(version, 0);
+      assert_eq!(decoded.raw_bytes, hash.raw_bytes);
+
+      let err = B58Buffer::try_from_check_encoded(b"1PMycacnJaSqwwJqjawXBErnLsZ7RkXUAt", b58::Alphabet::Bitcoin).unwrap_err();
+      assert_eq!(err.kind, DecodeErrorKind::InvalidChecksum);
+    }
+
+    #[test]
+    fn bytes_converts_between_encodings() {
+      let bytes = Bytes::from_hex(b"49276d206b696c6c696e67").unwrap();
+      assert_eq!(bytes.to_b64(), "SSdtIGtpbGxpbmc=");
+      assert_eq!(Bytes::from_b64(b"SSdtIGtpbGxpbmc=").unwrap(), bytes);
+      assert_eq!(Bytes::from_b32(bytes.to_b32().as_bytes()).unwrap(), bytes);
+      assert_eq!(Bytes::from_b58(bytes.to_b58().as_bytes()).unwrap(), bytes);
+      assert_eq!(Bytes::from_a85(bytes.to_a85().as_bytes()).unwrap(), bytes);
+      assert_eq!(Bytes::from_z85(b"HelloWorld").unwrap().to_z85(), "HelloWorld");
+
+      let hex_buffer: HexBuffer = bytes.clone().into();
+      assert_eq!(Bytes::from(B64Buffer { raw_bytes: hex_buffer.raw_bytes }), bytes);
+    }
+
+    #[test]
+    fn codec_buffers_encode_like_their_modules() {
+      let raw = b"any carnal pleas".to_vec();
+      assert_eq!(HexBuffer::from(raw.clone()).encode_to_utf8(), hex::encode(&raw));
+      assert_eq!(B64Buffer::from(raw.clone()).encode_to_utf8(), b64::encode(&raw, &b64::STANDARD));
+      assert_eq!(B32Buffer::from(raw.clone()).encode_to_utf8(), b32::encode(&raw, &b32::STANDARD));
+      assert_eq!(B58Buffer::from(raw.clone()).encode_to_utf8(), b58::encode(&raw, b58::Alphabet::Bitcoin));
+      assert_eq!(A85Buffer::from(raw.clone()).encode_to_utf8(), a85::encode(&raw));
+      assert_eq!(Z85Buffer::from(raw.clone()).encode_to_utf8(), a85::encode_z85(&raw));
+      assert_eq!(url::PercentBuffer::from(raw.clone()).encode_to_utf8(), url::encode(&raw, &url::COMPONENT));
+      assert_eq!(Bytes::from(raw.clone()).encode_to_utf8(), hex::encode(&raw));
+      assert_eq!(B64Buffer::from(raw.clone()).xor_value(&1).decode_to_bin(), xor::xor_buffer(&raw, &1));
+
+      // Bytes reads hex the way its FromStr does, separators and all.
+      assert_eq!(Bytes::try_from_encoded_utf8_buffer(b"de:ad").unwrap().decode_to_bin(), vec![0xde, 0xad]);
+      assert!(HexBuffer::try_from_encoded_utf8_buffer(b"de:ad").is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid base58 input")]
+    fn codec_panic_names_the_encoding() {
+      B58Buffer::from_encoded_utf8_buffer(b"0OIl");
+    }
+
+    #[test]
+    fn bytes_behaves_like_a_slice() {
+      let mut bytes: Bytes = "de:ad:be:ef".parse().unwrap();
+      assert_eq!(bytes.to_string(), "deadbeef");
+      assert_eq!(format!("{}", Bytes::from("hi")), "6869");
+      assert_eq!(bytes.len(), 4);
+      assert_eq!(bytes[0], 0xde);
+      assert_eq!(&bytes[1..3], &[0xad, 0xbe]);
+      bytes[3] = 0;
+      assert_eq!(bytes.last(), Some(&0));
+      assert_eq!(bytes.chunks(2).count(), 2);
+      assert!("nope".parse::<Bytes>().is_err());
+    }
+
+    #[test]
+    fn bytes_xor_operators() {
+      let ct1 = Bytes::from_hex(b"1c0111001f010100061a024b53535009181c").unwrap();
+      let ct2 = Bytes::from_hex(b"686974207468652062756c6c277320657965").unwrap();
+      assert_eq!((&ct1 ^ &ct2).to_hex(), "746865206b696420646f6e277420706c6179");
+      assert_eq!(ct1.clone() ^ ct2.clone() ^ &ct2, ct1);
+
+      let plain = Bytes::from("Burning 'em, if you ain't quick and nimble");
+      let encrypted = &plain ^ RepeatingKey(b"ICE");
+      assert_eq!(encrypted.to_hex(), "0b3637272a2b2e63622c2e69692a23693a2a3c6324202d623d63343c2a26226324272765272a282b2f20");
+      let mut decrypted = encrypted.clone();
+      decrypted ^= RepeatingKey(b"ICE");
+      assert_eq!(decrypted, plain);
+      assert_eq!((plain.clone() ^ 0x20) ^ 0x20, plain);
+    }
+
+    #[test]
+    fn xor_length_policies() {
+      let long = Bytes::from(vec![1, 2, 3, 4]);
+      let short = [1, 2];
+      assert_eq!(&long ^ &Bytes::from(&short[..]), Bytes::from(vec![0, 0]));
+      assert_eq!(long.xor_with(&short, LengthPolicy::Truncate).unwrap(), Bytes::from(vec![0, 0]));
+      assert_eq!(long.xor_with(&short, LengthPolicy::Cycle).unwrap(), Bytes::from(vec![0, 0, 2, 6]));
+      let err = long.xor_with(&short, LengthPolicy::Error).unwrap_err();
+      assert_eq!(err, xor::LengthMismatch { lhs: 4, rhs: 2 });
+      assert!(long.xor_with(&[], LengthPolicy::Cycle).is_err());
+    }
+
+    #[test]
+    fn xor_kernels_match_bytewise_xor() {
+      let data: Vec<u8> = (0..1000).map(|i| (i * 31 + 7) as u8).collect();
+      for key_len in [1, 3, 16, 29, 255, 256, 300].iter() {
+        let key: Vec<u8> = (0..*key_len).map(|i| (i * 13 + 5) as u8).collect();
+        for len in [0, 1, 15, 16, 17, 257, 1000].iter() {
+          let expected: Vec<u8> = data[..*len].iter().zip(key.iter().cycle()).map(|(d, k)| d ^ k).collect();
+
+          let mut in_place = data[..*len].to_vec();
+          xor::xor_in_place(&mut in_place, &key);
+          assert_eq!(in_place, expected);
+
+          let mut into = vec![0; *len + 3];
+          xor::xor_into(&data[..*len], &key, &mut into);
+          assert_eq!(&into[..*len], &expected[..]);
+          assert_eq!(xor::xor_sequential(&data[..*len], &key), expected);
+        }
+      }
+    }
+
+    #[test]
+    fn keystreams_drive_xor() {
+      let plain = Bytes::from("Burning 'em, if you ain't quick and nimble");
+      let repeating = Bytes::from(xor::xor_sequential(&plain, b"ICE"));
+      assert_eq!(plain.xor_sequential(b"ICE"), repeating);
+      assert_eq!(plain.xor_keystream(&mut RepeatingKeystream::from(RepeatingKey(b"ICE"))), repeating);
+      assert_eq!(plain.xor_value(&0x42), Bytes::from(xor::xor_buffer(&plain, &0x42)));
+      assert_eq!(plain.xor_keystream(&mut 0x42u8), plain.xor_value(&0x42));
+
+      // Seeking lets a stream start mid-message, here as a counter mode would.
+      let mut counter = xor::from_fn(|position| (position / 4) as u8 ^ 0x5a);
+      let encrypted = plain.xor_keystream(&mut counter);
+      counter.seek(8);
+      let mut tail = encrypted[8..].to_vec();
+      xor::xor_keystream_in_place(&mut tail, &mut counter);
+      assert_eq!(&tail[..], &plain[8..]);
+
+      let mut stream = RepeatingKeystream::new(b"ICE");
+      let mut split = plain.clone();
+      let (front, back) = split.split_at_mut(5);
+      xor::xor_keystream_in_place(front, &mut stream);
+      xor::xor_keystream_in_place(back, &mut stream);
+      assert_eq!(split, repeating);
+      stream.seek(4);
+      assert_eq!(stream.next_byte(), b'C');
+    }
+
+    #[test]
+    fn xorable_provides_keystreams_to_existing_impls() {
+      // Written against the two methods Xorable started out with.
+      #[derive(Debug, PartialEq)]
+      struct Legacy(Vec<u8>);
+
+      impl AsRef<[u8]> for Legacy {
+        fn as_ref(&self) -> &[u8] {
+          &self.0
+        }
+      }
+
+      impl From<Vec<u8>> for Legacy {
+        fn from(bytes: Vec<u8>) -> Legacy {
+          Legacy(bytes)
+        }
+      }
+
+      impl Xorable for Legacy {
+        fn xor_value(&self, value: &u8) -> Self {
+          Legacy(xor::xor_buffer(&self.0, value))
+        }
+
+        fn xor_sequential(&self, sequence: &[u8]) -> Self {
+          Legacy(xor::xor_sequential(&self.0, sequence))
+        }
+      }
+
+      let legacy = Legacy(b"nimble".to_vec());
+      assert_eq!(legacy.xor_keystream(&mut RepeatingKeystream::new(b"ICE")), legacy.xor_sequential(b"ICE"));
+    }
+
+    #[test]
+    fn hexdump_matches_xxd() {
+      assert_eq!(hexdump::format(b"Hello, world!\n", &hexdump::XXD),
+        "00000000: 4865 6c6c 6f2c 2077 6f72 6c64 210a       Hello, world!.\n");
+      let bytes: Vec<u8> = (0..40).collect();
+      let dump = hexdump::format(&bytes, &hexdump::XXD);
+      assert_eq!(dump, concat!(
+        "00000000: 0001 0203 0405 0607 0809 0a0b 0c0d 0e0f  ................\n",
+        "00000010: 1011 1213 1415 1617 1819 1a1b 1c1d 1e1f  ................\n",
+        "00000020: 2021 2223 2425 2627                       !\"#$%&'\n"));
+      assert_eq!(HexBuffer::try_from_hexdump(dump.as_bytes()).unwrap().raw_bytes, bytes);
+    }
+
+    #[test]
+    fn hexdump_marks_blocks_and_highlights() {
+      let bytes: Vec<u8> = (0x40..0x60).collect();
+      let config = hexdump::HexdumpConfig { width: 8, group_size: 2, block_size: Some(4), ..hexdump::XXD };
+      assert_eq!(hexdump::format_highlighted(&bytes[..8], &config, &[2..4, 4..5]), concat!(
+        "00000000: 4041 4243|4445 4647  @ABCDEFG\n",
+        "               ^^^^ ^^           ^^^\n"));
+
+      let ansi = hexdump::HexdumpConfig { highlight: hexdump::Highlight::Ansi, ..config };
+      let dump = hexdump::format_highlighted(&bytes, &ansi, &[3..4, 20..21]);
+      assert!(dump.starts_with("00000000: 4041 42\x1b[7m43\x1b[0m|4445"));
+      assert!(dump.contains("|\x1b[7m54\x1b[0m55"));
+      assert_eq!(hexdump::parse(dump.as_bytes()).unwrap(), bytes);
+
+      let lines = hexdump::format(&bytes, &hexdump::HexdumpConfig { block_size: Some(16), ..config });
+      assert_eq!(lines.lines().nth(2), Some("-".repeat(29).as_str()));
+      assert_eq!(hexdump::parse(lines.as_bytes()).unwrap(), bytes);
+    }
+
+    #[test]
+    fn hexdump_diff_shows_changed_bytes() {
+      let before = [0u8; 20];
+      let mut after = before;
+      after[17] = 0xff;
+      let config = hexdump::HexdumpConfig { ascii: false, ..hexdump::BYTES };
+      assert_eq!(hexdump::diff(&before, &after, &config), concat!(
+        "00000000: 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00 00\n",
+        "00000010: 00 00 00 00\n",
+        "             ^^\n",
+        "00000010: 00 ff 00 00\n",
+        "             ^^\n"));
+    }
+
+    #[test]
+    fn hexdump_parse_errors_and_gaps() {
+      assert_eq!(hexdump::parse(b"00000004: 0102  ..\nnot a dump line\n").unwrap(), vec![0, 0, 0, 0, 1, 2]);
+      let err = hexdump::parse(b"00000000: 01\n00000002: 0g").unwrap_err();
+      assert_eq!(err.kind, DecodeErrorKind::InvalidSymbol);
+      assert_eq!((err.byte, err.offset), (Some(b'g'), 24));
+      assert_eq!(hexdump::parse(b"00000000: 012").unwrap_err().kind, DecodeErrorKind::OddLength);
+    }
+
+    #[test]
+    fn hexdump_parse_rejects_unreachable_offsets() {
+      let err = hexdump::parse(b"00000000: 01\nffffffffffffffff: 0102").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::Overflow, None, 13));
+      let err = hexdump::parse(b"00000000: 01\n7fffffff: 02").unwrap_err();
+      assert_eq!(err, DecodeError::new(DecodeErrorKind::Overflow, None, 13));
+      // Gaps count against one budget, however many lines they're spread over.
+      let gap = hexdump::MAX_FILL / 2;
+      let dump = format!("{:08x}: 01\n{:08x}: 02\n{:08x}: 03\n", gap, 2 * gap + 1, 3 * gap + 2);
+      assert_eq!(hexdump::parse(dump.as_bytes()).unwrap_err().kind, DecodeErrorKind::Overflow);
+      let dump = format!("{:08x}: 01\n", hexdump::MAX_FILL);
+      assert_eq!(hexdump::parse(dump.as_bytes()).unwrap().len(), hexdump::MAX_FILL + 1);
+    }
+
+    #[test]
+    fn percent_encoding_configs() {
+      let input = b"a b&c=d/e~f+\xff%";
+      assert_eq!(Bytes::from(&input[..]).to_percent(), "a%20b%26c%3Dd%2Fe~f%2B%FF%25");
+      assert_eq!(Bytes::from(&input[..]).to_percent_with_config(&url::PATH), "a%20b&c=d/e~f+%FF%25");
+      assert_eq!(Bytes::from(&input[..]).to_percent_with_config(&url::FORM), "a+b%26c%3Dd%2Fe%7Ef%2B%FF%25");
+      for config in [url::COMPONENT, url::PATH, url::FORM].iter() {
+        let encoded = Bytes::from(&input[..]).to_percent_with_config(config);
+        assert_eq!(&Bytes::from_percent_with_config(encoded.as_bytes(), config).unwrap()[..], &input[..]);
+      }
+      assert_eq!(&Bytes::from_percent(b"%7e%7E+").unwrap()[..], b"~~+");
+
+      let truncated = Bytes::from_percent(b"ab%4").unwrap_err();
+      assert_eq!((truncated.kind, truncated.byte, truncated.offset), (DecodeErrorKind::InvalidSymbol, Some(b'%'), 2));
+      let bad_digit = Bytes::from_percent(b"%4g%20").unwrap_err();
+      assert_eq!((bad_digit.byte, bad_digit.offset), (Some(b'g'), 2));
+    }
+
+    #[test]
+    fn cookie_modes() {
+      let mut profile = CookieMap::new(CookieMode::Lenient);
+      profile.insert(b"email", b"foo@bar.com&role=admin").unwrap();
+      profile.insert(b"uid", b"10").unwrap();
+      profile.insert(b"role", b"user").unwrap();
+      assert_eq!(profile.to_string(), "email=foo@bar.com&role=admin&uid=10&role=user");
+      // The smuggled role comes first, so it keeps its place but loses its value.
+      let parsed = CookieMap::parse(&profile.encode(), CookieMode::Lenient).unwrap();
+      assert_eq!(parsed.iter().map(|(k, _)| k).collect::<Vec<_>>(), vec![&b"email"[..], b"role", b"uid"]);
+      assert_eq!(parsed.get(b"role"), Some(&b"user"[..]));
+
+      let mut strict = CookieMap::new(CookieMode::Strict);
+      let err = strict.insert(b"email", b"foo@bar.com&role=admin").unwrap_err();
+      assert_eq!((err.byte, err.offset), (Some(b'&'), 11));
+      assert!(strict.is_empty());
+      assert!(CookieMap::parse(b"a=1&b=2", CookieMode::Strict).is_ok());
+      let missing = CookieMap::parse(b"a=1&b&c=3", CookieMode::Strict).unwrap_err();
+      assert_eq!((missing.byte, missing.offset), (Some(b'&'), 5));
+      assert_eq!(CookieMap::parse(b"a=1&b", CookieMode::Strict).unwrap_err().byte, None);
+      assert_eq!(CookieMap::parse(b"a=1=2", CookieMode::Strict).unwrap_err().offset, 3);
+      assert_eq!(CookieMap::parse(b"a=1&&b=2", CookieMode::Strict).unwrap_err().offset, 4);
+
+      let mut escaped = CookieMap::new(CookieMode::Escape);
+      escaped.insert(b"email", b"foo@bar.com&role=admin%").unwrap();
+      escaped.insert(b"uid", b"10").unwrap();
+      assert_eq!(escaped.to_string(), "email=foo@bar.com%26role%3Dadmin%25&uid=10");
+      assert_eq!(CookieMap::parse(&escaped.encode(), CookieMode::Escape).unwrap(), escaped);
+      assert_eq!(escaped.remove(b"uid"), Some(b"10".to_vec()));
+      assert_eq!(escaped.len(), 1);
+      assert_eq!(CookieMap::parse(b"a=1&b=%zz", CookieMode::Escape).unwrap_err().offset, 7);
+      // A bare key is decoded like any other.
+      let bare = CookieMap::parse(b"a%26b&c=1", CookieMode::Escape).unwrap();
+      assert_eq!(bare.get(b"a&b"), Some(&b""[..]));
+      assert_eq!(bare.to_string(), "a%26b=&c=1");
+      assert_eq!(CookieMap::parse(b"a=1&%z", CookieMode::Escape).unwrap_err().offset, 4);
+    }
+
+    #[test]
+    fn radix_matches_dedicated_codecs() {
+      let data: Vec<u8> = (0..200u32).map(|i| (i * 97 + 13) as u8).collect();
+      for len in 0..40 {
+        let input = &data[..len];
+        let bytes = Bytes::from(input);
+        assert_eq!(radix::encode(input, &radix::HEX), bytes.to_hex().as_bytes());
+        assert_eq!(radix::encode(input, &radix::BASE64), bytes.to_b64().as_bytes());
+        assert_eq!(radix::encode(input, &radix::BASE64_URL), bytes.to_b64_with_config(&b64::URL_SAFE_NO_PAD).as_bytes());
+        assert_eq!(radix::encode(input, &radix::BASE32), bytes.to_b32().as_bytes());
+        assert_eq!(radix::encode(input, &radix::BASE32_HEX), bytes.to_b32_with_config(&b32::HEX).as_bytes());
+        for radix in [radix::BINARY, radix::OCTAL, radix::HEX_UPPER, radix::BASE32, radix::BASE64].iter() {
+          let encoded = radix::encode(input, radix);
+          assert_eq!(encoded.len(), radix.encoded_len(len));
+          assert_eq!(radix::decode(&encoded, radix).unwrap(), input);
+        }
+      }
+    }
+
+    #[test]
+    fn radix_transcodes_without_bytes() {
+      let hex = b"49276d206b696c6c696e6720796f757220627261696e206c696b65206120706f69736f6e6f7573206d757368726f6f6d";
+      assert_eq!(radix::transcode(hex, &radix::HEX, &radix::BASE64).unwrap(),
+        &b"SSdtIGtpbGxpbmcgeW91ciBicmFpbiBsaWtlIGEgcG9pc29ub3VzIG11c2hyb29t"[..]);
+      let pairs = [radix::BINARY, radix::OCTAL, radix::HEX, radix::BASE32, radix::BASE64, radix::BASE64_URL];
+      for len in 0..12 {
+        let input: Vec<u8> = (0..len).map(|i| (i * 41 + 200) as u8).collect();
+        for from in pairs.iter() {
+          for to in pairs.iter() {
+            let encoded = radix::encode(&input, from);
+            assert_eq!(radix::transcode(&encoded, from, to).unwrap(), radix::encode(&input, to));
+          }
+        }
+      }
+    }
+
+    #[test]
+    fn radix_custom_alphabets_and_errors() {
+      // A shuffled base64 alphabet, as CTF challenges like to use.
+      let shuffled = radix::Radix::new(b"ZYXWVUTSRQPONMLKJIHGFEDCBAzyxwvutsrqponmlkjihgfedcba9876543210+/", Some(b'='));
+      assert_eq!(radix::encode(b"Man", &shuffled), b"GDUf");
+      assert_eq!(radix::transcode(b"GDUf", &shuffled, &radix::BASE64).unwrap(), b"TWFu");
+      assert_eq!(radix::decode(b"DEADbeef", &radix::HEX).unwrap(), vec![0xde, 0xad, 0xbe, 0xef]);
+      assert_eq!(radix::encode(b"Hi", &radix::BINARY), b"0100100001101001");
+      assert_eq!(radix::encode(b"\xff", &radix::OCTAL), b"776");
+
+      let err = radix::decode(b"TWE=x", &radix::BASE64).unwrap_err();
+      assert_eq!((err.kind, err.offset), (DecodeErrorKind::TrailingData, 4));
+      assert_eq!(radix::decode(b"TWE", &radix::BASE64).unwrap_err().kind, DecodeErrorKind::InvalidPadding);
+      assert_eq!(radix::decode(b"TQ===", &radix::BASE64).unwrap_err().kind, DecodeErrorKind::InvalidPadding);
+      // A dangling hex digit is reported the way hex:: reports it.
+      let odd = radix::decode(b"abc", &radix::HEX).unwrap_err();
+      assert_eq!(odd, hex::HexBuffer::try_from_encoded(b"abc").unwrap_err());
+      assert_eq!(odd.kind, DecodeErrorKind::OddLength);
+      let err = radix::transcode(b"0g", &radix::HEX, &radix::BASE64).unwrap_err();
+      assert_eq!((err.kind, err.byte, err.offset), (DecodeErrorKind::InvalidSymbol, Some(b'g'), 1));
+    }
+
+    #[test]
+    fn detect_ranks_likeliest_reading_first() {
+      let ranked = |input: &[u8]| detect::detect(input).iter().map(|c| c.encoding).collect::<Vec<_>>();
+      assert_eq!(ranked(b"d41d8cd98f00b204e9800998ecf8427e")[0], Encoding::Hex);
+      assert_eq!(ranked(b"de ad be ef\n"), vec![Encoding::Hex, Encoding::Raw]);
+      // Mixed case can't be base32, and base64url adds nothing when it
+      // decodes the same as base64.
+      assert_eq!(ranked(b"TWFu"), vec![Encoding::Base64, Encoding::Raw, Encoding::Ascii85]);
+      assert_eq!(ranked(b"PDw_Pz4-")[0], Encoding::Base64Url);
+      assert_eq!(ranked(b"JBSWY3DPEE======")[0], Encoding::Base32);
+      assert_eq!(ranked(b"<~87cURD]i,\"Ebo80~>")[0], Encoding::Ascii85);
+      assert_eq!(ranked(b"hello world"), vec![Encoding::Raw]);
+      assert_eq!(ranked(b"\x00\x01\xff"), vec![Encoding::Raw]);
+
+      let candidates = detect::detect(b"TWFu\r\n");
+      assert_eq!(candidates[0].decoded, b"Man");
+      let total: f32 = candidates.iter().map(|c| c.confidence).sum();
+      assert!((total - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn detect_reads_bare_words_as_raw() {
+      // Each decodes as something, but only to junk.
+      for word in ["Hello", "password123", "ciphertext", "Attack", "Cooking", "deadbeef", "hunter2", "-_-_"].iter() {
+        let candidates = detect::detect(word.as_bytes());
+        assert!(candidates.len() > 1, "{} should decode as something", word);
+        assert_eq!(candidates[0].encoding, Encoding::Raw, "{}", word);
+        assert!(detect::peel(word.as_bytes()).layers.is_empty(), "{}", word);
+      }
+      // Padding and a readable decoding make the same length convincing.
+      assert_eq!(detect::detect(b"SGVsbG8=")[0].decoded, b"Hello");
+      assert_eq!(detect::detect(b"cGFzc3dvcmQxMjM=")[0].decoded, b"password123");
+    }
+
+    #[test]
+    fn peeler_reports_each_layer() {
+      let plain = "Now that the party is jumping";
+      let layered = Bytes::from(Bytes::from(Bytes::from(plain).to_b64().as_str()).to_hex().as_str()).to_b64();
+      let peeled = detect::peel(layered.as_bytes());
+      assert_eq!(peeled.output, plain.as_bytes());
+      assert_eq!(peeled.layers.iter().map(|l| l.encoding).collect::<Vec<_>>(),
+        vec![Encoding::Base64, Encoding::Hex, Encoding::Base64]);
+      assert_eq!(peeled.to_string(), "base64 -> hex -> base64");
+
+      let shallow = detect::Peeler { max_layers: 1, ..detect::PEELER };
+      assert_eq!(shallow.peel(layered.as_bytes()).layers.len(), 1);
+      let untouched = detect::peel(b"hello world");
+      assert!(untouched.layers.is_empty());
+      assert_eq!(untouched.to_string(), "raw");
+    }
 }
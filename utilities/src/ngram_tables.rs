@@ -0,0 +1,931 @@
+// Tables behind ngram::ENGLISH_BIGRAMS, ENGLISH_TRIGRAMS and ENGLISH_QUADGRAMS.
+// Counted over the prose of The Rust Programming Language (about 800KB once
+// the code listings are removed) after folding each byte with ngram::fold.
+// Bigrams are complete; the other orders keep their most common grams and
+// spread what's left of the probability evenly over the grams they leave out,
+// which is where each floor comes from. Grams are in ascending byte order,
+// with log10 probabilities alongside. Clippy mistakes some of those, like
+// -3.14, for approximations of constants.
+
+const ENGLISH_BIGRAM_FLOOR: f32 = -7.54;
+
+static ENGLISH_BIGRAM_GRAMS: &[u8] = b"\
+  \x20! \" $ ' ( ) , - . / 0 1 2 3 4 5 6 7 8 9 : ; ? a b c d e f g h i\
+  \x20j k l m n o p q r s t u v w y z ~! !\"!)\" \")\",\".\":\";\"a\"b\"c\"d\"e\"f\
+  \"g\"h\"i\"l\"m\"n\"o\"p\"q\"r\"s\"t\"u\"v\"w# $h' 'd'l'm'r's't'v( ()(,(0(1(2(5\
+  (a(b(c(d(f(g(h(i(k(l(m(n(o(p(r(s(t(u(w(y) ))),).):);+ +\"+++,, ,\"\
+  ,0,8- -1-2-3-4-5-6-7-8-9-a-b-c-d-e-f-g-h-i-j-l-m-n-o-p-q-r-s-t-u\
+  -v-w-y. .\".)...0.1.5.6.8.9.c.e.g.h.i.j.l.o.p.r.t.w.x/ /,/.///1/a\
+  /b/c/d/e/f/g/h/i/j/l/m/o/p/r/s/t/v/w0 0!0)0,0-0.00010408090:0;0s\
+  0t1 1!1)1,1-1.101112131415161718191:1;2 2)2,2-2.2021222324252627\
+  28292:2n3 3)3,3-3.303132333435363738393:4 4)4,4-4.4041424:4?5 5)\
+  5,5-5.50545556575:6 6'6)6,6-6.6064676:6;7 7)7,7-7.727576787:7;7?\
+  8 8)8,8-8.8/80878;9 9,9-9.949697999:: :/:1:2:5:7; ? ?\"\\d\\h_c_g_h\
+  _o_t_wa a!a'a)a,a-a.a:a;abacadafagahaiajakalamanapaqarasatauavaw\
+  axayazb b!b,b.b:babbbcbdbebibjblbmbobpbrbsbtbubvbyc c!c#c'c)c+c,\
+  c-c.c/c:c;cacccechcickclcmcocpcqcrcsctcucyd d!d\"d'd)d,d-d.d/d:d;\
+  d?d_dadbdcdddedfdgdhdidjdldmdndodrdsdtdudvdwdxdye e!e\"e'e)e,e-e.\
+  e/e:e;e?eaebecedeeefegeheiejekelemeneoepeqereseteuevewexeyf f)f,\
+  f-f.f:f_fafefffiflfofrfsftfufyg g!g\"g'g)g,g-g.g/g:g;g?g\\g_gagbgc\
+  gegfggghgiglgmgngogpgrgsgtgugyh h!h\"h)h,h-h.h:h;hahehihlhmhnhohp\
+  hrhshthuhwhyi i\"i'i)i,i.i/i;iaibicidieifigiiijikiliminioipiqiris\
+  itivixizjajejijkjojsjuk k!k\"k'k)k,k-k.k/k:k;kakdkekfkgkikkklknko\
+  ksktkukyl l!l\"l'l)l,l-l.l5l:l;l?lalblcldlelflglilklllmlnlolplrls\
+  ltlulvlwlym m!m\"m'm)m,m-m.m:m;mambmdmemfmimlmmmnmompmsmumvmyn n!\
+  n\"n'n)n,n-n.n/n:n;n?n_nanbncndnenfngnhninjnknlnmnnnonpnrnsntnunv\
+  nwnynzo o!o\"o'o(o)o,o-o.o/o:o;o?o_oaobocodoeofogohoiojokolomonoo\
+  opoqorosotouovowoxoyp p!p\"p)p,p-p.p/p:p;p?papdpepfpgphpiplpmpopp\
+  prpsptpupwpyqlqur r!r\"r'r)r,r-r.r/r:r;r?rarbrcrdrerfrgrhrirkrlrm\
+  rnrorprrrsrtrurvrwrys s!s\"s's)s,s-s.s/s1s:s;s?sasbscsdsesfsgshsi\
+  skslsmsnsospsqsrssstsusvswsyt t!t\"t't(t)t,t-t.t/t:t;t?t\\t_tatctd\
+  tetfthtitltmtntotptrtstttutvtwtxtyu u!u'u,u-u.u?uaubucudueufugui\
+  ukulumunuoupurusutuxuzv v,v.vavcvevivlvovuw w!w\"w'w)w,w-w.w:w;wa\
+  wewfwhwiwlwnwowrwswtwwx x\"x'x)x,x.x:xaxcxexhxixpxty y!y\"y'y)y,y-\
+  y.y:y;y?yaybycyeyiylymynyoypyrysytyuywyzzazezizlzyzz~/";
+
+#[allow(clippy::approx_constant)]
+static ENGLISH_BIGRAM_LOG_PROBS: [f32; 1019] = [
+  -5.12, -3.57, -5.90, -5.60, -3.56, -4.48, -2.96, -5.12, -3.01, -4.58, -4.24, -3.09, -3.62, -4.22,
+  -4.08, -4.18, -4.17, -4.09, -4.21, -4.18, -4.08, -4.58, -5.12, -1.72, -2.25, -1.93, -2.31, -2.35,
+  -2.17, -2.79, -2.46, -1.84, -3.57, -3.16, -2.33, -2.26, -2.45, -2.01, -2.24, -3.95, -2.23, -2.01,
+  -1.46, -2.45, -2.51, -1.89, -2.60, -4.56, -5.60, -3.48, -4.75, -5.30, -3.63, -5.20, -5.42, -5.12,
+  -5.42, -5.90, -5.05, -5.12, -4.58, -4.69, -5.05, -5.20, -5.90, -4.64, -4.69, -5.42, -5.05, -5.05,
+  -5.42, -4.86, -5.90, -4.99, -4.75, -4.50, -4.90, -5.30, -5.20, -5.90, -5.90, -4.72, -4.27, -3.09,
+  -5.60, -3.37, -2.86, -2.93, -3.44, -5.30, -4.50, -5.90, -5.90, -5.90, -5.42, -5.90, -4.42, -5.20,
+  -5.42, -5.12, -5.12, -5.42, -5.60, -4.78, -5.90, -5.42, -5.20, -5.30, -4.75, -5.60, -4.99, -4.58,
+  -4.42, -5.42, -4.45, -5.90, -3.84, -5.90, -4.41, -4.02, -5.42, -5.90, -5.42, -5.90, -5.05, -5.42,
+  -2.07, -4.82, -5.42, -5.90, -5.60, -3.46, -3.73, -4.11, -4.17, -4.25, -4.32, -4.34, -4.23, -4.41,
+  -4.45, -4.42, -4.58, -4.78, -4.60, -5.05, -5.20, -4.75, -4.50, -5.90, -4.13, -4.86, -4.86, -4.11,
+  -4.48, -5.60, -4.67, -4.35, -4.21, -5.60, -5.42, -4.48, -5.60, -2.09, -4.54, -4.58, -5.60, -4.39,
+  -4.78, -5.30, -5.42, -4.99, -5.05, -5.60, -5.30, -5.60, -4.99, -4.48, -5.90, -4.67, -5.60, -5.60,
+  -3.67, -4.11, -5.90, -5.90, -4.86, -5.42, -5.42, -5.12, -5.60, -5.90, -5.60, -4.72, -5.42, -5.90,
+  -5.12, -5.42, -5.60, -4.99, -5.90, -4.31, -3.92, -4.64, -5.90, -5.30, -4.64, -5.30, -5.90, -5.42,
+  -4.08, -5.60, -5.30, -4.31, -3.83, -3.93, -4.41, -5.90, -5.12, -5.90, -5.90, -5.30, -5.90, -5.90,
+  -5.90, -3.93, -5.90, -5.20, -4.34, -4.09, -4.33, -3.76, -4.19, -3.94, -4.01, -4.18, -3.88, -4.01,
+  -4.05, -4.08, -4.07, -4.60, -5.90, -4.03, -5.60, -4.52, -4.18, -4.26, -3.92, -4.09, -4.75, -4.75,
+  -4.78, -4.69, -5.30, -4.60, -5.05, -5.30, -5.12, -5.42, -4.16, -5.60, -4.47, -4.23, -4.26, -5.60,
+  -5.90, -4.99, -5.90, -5.90, -5.90, -5.90, -5.20, -5.30, -5.90, -5.20, -4.13, -5.42, -4.50, -4.13,
+  -4.37, -4.99, -5.60, -5.60, -5.05, -5.90, -4.23, -5.20, -4.44, -3.92, -4.33, -4.94, -5.90, -5.42,
+  -5.60, -5.90, -5.42, -4.27, -5.90, -5.42, -4.37, -4.14, -4.45, -5.42, -5.30, -5.90, -4.99, -5.90,
+  -4.35, -5.90, -4.60, -3.93, -4.31, -5.90, -5.42, -5.90, -4.52, -5.42, -5.60, -5.90, -4.14, -5.60,
+  -4.58, -4.07, -4.32, -5.20, -5.42, -4.82, -5.90, -4.33, -4.72, -4.03, -4.39, -5.90, -5.60, -5.90,
+  -4.78, -5.90, -2.83, -5.12, -5.60, -5.90, -5.90, -4.86, -3.52, -4.23, -5.60, -5.90, -5.90, -5.12,
+  -5.60, -5.20, -4.78, -5.12, -5.60, -2.24, -5.90, -5.90, -5.90, -4.60, -5.42, -4.28, -5.42, -5.05,
+  -2.79, -2.64, -2.71, -3.32, -3.04, -5.20, -2.71, -4.75, -3.07, -2.22, -2.53, -1.95, -2.89, -5.60,
+  -2.23, -2.41, -1.95, -3.01, -2.86, -3.62, -3.77, -3.10, -4.99, -4.03, -5.60, -5.42, -4.23, -5.90,
+  -3.69, -4.86, -5.05, -5.90, -2.54, -3.32, -3.75, -2.73, -5.90, -3.00, -5.90, -3.23, -3.92, -4.94,
+  -3.03, -5.30, -3.09, -3.15, -5.90, -5.90, -5.90, -5.90, -5.05, -4.37, -5.90, -4.27, -3.76, -5.60,
+  -5.60, -2.34, -3.46, -2.52, -2.47, -3.04, -3.00, -3.09, -5.12, -2.23, -4.54, -4.82, -2.81, -3.92,
+  -2.39, -2.99, -3.81, -1.96, -4.54, -5.20, -4.69, -4.94, -3.35, -4.47, -3.30, -5.30, -4.30, -4.99,
+  -5.30, -4.99, -3.08, -5.60, -4.72, -3.25, -2.31, -5.90, -4.86, -5.60, -2.69, -5.90, -3.59, -5.42,
+  -3.90, -2.83, -3.75, -2.99, -4.78, -3.20, -4.30, -5.05, -5.60, -3.58, -1.40, -4.23, -4.56, -2.84,
+  -4.60, -2.78, -3.83, -2.80, -4.94, -3.46, -4.23, -4.94, -2.44, -4.01, -2.44, -2.33, -2.78, -2.68,
+  -3.62, -3.66, -3.36, -5.12, -5.42, -2.78, -2.63, -2.11, -3.97, -3.12, -3.38, -1.94, -2.06, -2.36,
+  -4.56, -3.03, -3.28, -2.68, -3.25, -2.30, -5.90, -4.90, -4.52, -4.86, -5.90, -5.20, -3.28, -2.70,
+  -3.26, -2.59, -3.96, -2.59, -3.11, -4.72, -3.49, -2.69, -3.57, -2.24, -4.99, -5.12, -5.42, -5.90,
+  -3.86, -4.56, -3.74, -5.42, -4.25, -4.94, -5.60, -5.90, -5.60, -3.50, -5.90, -5.30, -2.66, -4.94,
+  -4.37, -3.16, -3.36, -3.82, -5.42, -3.43, -3.30, -5.60, -3.04, -3.89, -4.30, -3.11, -5.20, -2.45,
+  -5.42, -5.42, -5.90, -4.07, -4.62, -4.01, -4.99, -5.42, -2.11, -1.71, -2.42, -5.30, -4.82, -4.33,
+  -2.52, -5.30, -3.14, -4.31, -3.32, -4.09, -5.60, -3.97, -3.96, -5.90, -5.60, -5.05, -4.78, -4.78,
+  -4.75, -5.60, -2.96, -3.15, -2.60, -2.95, -3.02, -2.68, -3.01, -5.20, -5.90, -3.38, -2.45, -2.66,
+  -1.77, -2.34, -3.31, -4.24, -2.94, -2.17, -2.16, -3.12, -3.89, -3.69, -5.12, -3.40, -5.90, -5.90,
+  -4.16, -5.90, -3.66, -2.94, -5.30, -5.60, -5.90, -5.30, -4.00, -5.30, -3.95, -5.90, -4.62, -5.42,
+  -3.99, -5.60, -2.79, -5.12, -4.99, -3.38, -5.90, -4.62, -3.50, -5.90, -3.38, -4.90, -5.90, -5.12,
+  -2.36, -4.94, -5.42, -5.60, -5.60, -3.85, -4.58, -3.76, -5.90, -4.50, -5.42, -5.60, -2.82, -5.30,
+  -4.69, -2.83, -2.16, -4.18, -5.42, -2.37, -4.01, -2.29, -4.69, -5.20, -2.61, -3.99, -4.28, -3.06,
+  -3.17, -2.69, -4.13, -4.06, -2.72, -2.74, -5.12, -5.90, -4.33, -5.60, -3.83, -5.90, -3.79, -4.42,
+  -5.05, -2.59, -3.38, -5.12, -2.21, -5.60, -2.95, -3.96, -3.16, -5.90, -2.77, -2.60, -3.36, -3.08,
+  -5.60, -5.42, -1.83, -4.64, -4.90, -2.91, -4.99, -3.19, -4.24, -3.16, -5.20, -4.08, -4.72, -5.20,
+  -5.12, -2.79, -5.60, -2.42, -2.23, -2.38, -3.63, -2.15, -4.38, -2.89, -5.42, -3.94, -3.29, -4.11,
+  -3.24, -2.64, -4.13, -4.82, -2.48, -2.19, -3.17, -3.52, -4.90, -3.13, -5.42, -2.06, -4.99, -5.90,
+  -4.86, -5.90, -5.30, -3.86, -4.78, -3.68, -5.30, -4.54, -5.30, -5.90, -4.99, -3.96, -3.41, -3.04,
+  -2.50, -3.26, -2.37, -3.07, -5.30, -3.23, -3.68, -3.36, -2.97, -2.53, -2.03, -2.99, -2.87, -5.90,
+  -2.16, -2.90, -2.69, -2.24, -3.10, -2.51, -4.38, -4.90, -3.05, -5.60, -5.30, -4.99, -4.14, -4.86,
+  -4.09, -5.90, -4.99, -5.60, -5.90, -2.62, -4.16, -2.45, -4.78, -5.60, -4.13, -3.03, -2.62, -4.67,
+  -2.90, -3.28, -2.57, -3.77, -3.00, -3.19, -5.90, -4.37, -5.90, -3.21, -2.07, -4.56, -4.75, -4.50,
+  -5.05, -3.27, -4.19, -3.36, -5.05, -4.00, -4.78, -5.42, -2.31, -4.50, -3.38, -3.18, -1.90, -3.76,
+  -3.23, -4.78, -2.53, -3.25, -3.60, -3.23, -2.83, -2.38, -4.04, -2.92, -2.67, -2.94, -2.58, -3.88,
+  -4.25, -2.90, -1.70, -4.21, -4.20, -4.72, -4.56, -2.85, -4.67, -2.85, -4.72, -5.90, -3.42, -4.20,
+  -5.60, -2.94, -5.90, -3.19, -5.90, -2.22, -4.12, -5.90, -2.86, -2.55, -3.77, -3.53, -3.74, -3.40,
+  -2.68, -3.06, -5.12, -3.72, -2.68, -2.09, -2.92, -5.60, -4.38, -3.25, -1.77, -4.22, -5.12, -3.02,
+  -5.90, -5.20, -3.08, -4.08, -3.15, -4.99, -3.97, -4.60, -4.94, -5.90, -5.20, -2.39, -3.39, -4.94,
+  -2.07, -4.34, -1.56, -2.12, -3.41, -4.38, -5.20, -2.10, -3.63, -2.57, -2.62, -3.07, -2.73, -5.12,
+  -3.29, -4.86, -2.68, -2.83, -5.90, -3.41, -5.20, -5.60, -4.82, -5.90, -3.16, -3.63, -2.95, -3.81,
+  -2.65, -5.42, -3.38, -3.40, -5.90, -2.62, -3.01, -2.51, -4.82, -3.22, -2.43, -2.26, -2.52, -4.90,
+  -5.90, -5.20, -5.90, -5.90, -2.54, -5.90, -2.38, -3.10, -5.90, -4.10, -5.20, -2.76, -5.12, -5.60,
+  -5.90, -5.90, -4.08, -4.82, -4.48, -5.05, -5.60, -2.89, -2.27, -5.90, -2.57, -2.51, -4.62, -3.12,
+  -2.79, -3.39, -3.38, -4.72, -5.60, -3.44, -5.90, -5.30, -5.90, -4.35, -4.58, -5.12, -3.25, -4.39,
+  -3.74, -4.90, -3.74, -3.22, -3.35, -2.25, -4.75, -5.42, -4.03, -4.90, -3.40, -4.67, -3.41, -4.37,
+  -4.75, -5.90, -4.90, -5.30, -4.44, -4.01, -3.80, -4.58, -4.41, -3.40, -2.61, -2.79, -5.90, -3.41,
+  -3.81, -5.90, -3.82, -5.30, -4.38, -3.73, -4.82, -5.90, -5.12, -5.90, -5.60,
+];
+
+const ENGLISH_TRIGRAM_FLOOR: f32 = -6.97;
+
+static ENGLISH_TRIGRAM_GRAMS: &[u8] = b"\
+  \x20,  .  1  10 12 13 15 16 17 18 19 20 21 7- 8- 9- :  a  ab ac ad\
+  \x20af ag al am an ap ar as at au av aw ba be bi bl bo br bu by ca\
+  \x20ch cl co cr cu da de di do dr du ea ei el em en eq er ev ex fa\
+  \x20fe fi fl fo fr fu ga ge gi go gr gu ha he hi ho ht id if ig im\
+  \x20in is it jo ju ke ki kn la le li lo ma me mi mo mu na ne no nu\
+  \x20ob of on op or ot ou ov ow pa pe pi pl po pr pu qu ra re ri ro\
+  \x20ru sa sc se sh si sl sm so sp sr st su sy ta te th ti to tr tu\
+  \x20tw ty un up us va ve vi wa we wh wi wo wr ye yo! t! w\" s'd 'll\
+  're's 't 've). , ,, a, b, c, d, e, f, h, i, l, m, n, o, p, r, s\
+  , t, u, w, y-le. a. b. c. d. e. f. h. i. l. m. n. o. p. r. s. t\
+  . u. w. y.rs.to/li/ma0, 0-10. 1, 10-12-15-16-17-19-2. 20-21-3. \
+  5-15. 6, 7-1: a: f: i: n: o: r: s: t: w; i; t; wa ,a .a aa ba c\
+  a da fa ga ha ia la ma na oa pa ra sa ta ua va wa. abiablaboabs\
+  accaceachackacractad ad,ad.addadeadiadsadvadyafeaftagaageailain\
+  aitakeakial alialkallalralsaltalualwam am,am.ameamiammampamsan \
+  an'anaancandanganiannanoansantanyap apiappapsaptar araarcardare\
+  argariarkarlarmarnaroarrartaryas aseashasiaskasoassastasyat at'\
+  ataatcateathatiatoattatuaulausautavaaveaviaw awaawnax ay ay,ay.\
+  aysb.rbacbasbe becbeebefbehbeiberbesbetbilbinbitbjeblebliblobly\
+  bodbooborbotboubrabribrobstbugbuibutby bytc ac bc cc ic tc. c/l\
+  c/mcalcancapcarcascatcauccece ce,ce.cedceicencepcercesch chache\
+  chichnchociacidciecifcinciscitck ck,ck.ckackeckicksclaclecloclu\
+  codcolcomconcopcorcoucovcracrecricrocs ct ct,ct.ctectictlctocts\
+  ctuculcumcurcuscutcy d ,d .d ad bd cd dd ed fd gd hd id ld md n\
+  d od pd rd sd td ud vd wd yd, d. d: dardatdd ddeddiddrde de,de.\
+  deadecdeddefdeldemdendepderdesdetdevdexdiadicdiddifdindirdisdit\
+  dledlidn'do docdoedoidondowdredrods ds,ds.ducduldurdvady e \"e (\
+  e ,e .e 1e ae be ce de ee fe ge he ie ke le me ne oe pe re se t\
+  e ue ve we ye! e'le're'se've, e. e: e; eaceadeakealeameaneapear\
+  easeateb ecaeceechecieckeclecoectecued ed,ed.ediedseduee eedeen\
+  eepeesefaefeeffefiefoefuegeegrehaeineireiteivel elaeldeleelfeli\
+  elleloelpelselyem em,em.emaembemeemoempemsen en'en,enaencendene\
+  engeniensentenuenveopep epaepeepreptequer er,er.eraercereerferi\
+  ermernerrerserterveryes es,es.es:eseesiesnespessestesuet et'eta\
+  eteethetietsettetuetwetyevaeveeview ex exaexeexiexpextey ey'eyw\
+  f ,f .f af bf cf df ef ff hf if mf of pf rf sf tf vf wf yfacfai\
+  farfaufe feafecferfetfewffeffificfiefigfilfinfirfixflofolforfou\
+  froftefulfunfutfy fyig ,g .g 1g 2g 7g 8g 9g ag bg cg dg eg fg g\
+  g ig lg mg og pg rg sg tg ug vg wg yg, g. g: gaigamgange ge,ge.\
+  gengergesgetgh ghtgicgingivglegn gnagnogo go.goegragregrogs gth\
+  guaguegumgurh ,h .h ah ch dh eh fh gh ih mh oh ph rh sh th vh w\
+  h, h. hadhanhapharhashathavhe heahechedheihelhemhenherheshethey\
+  hichilhinhiphishnihodholhoohorhoshouhowhrehroht hy iabialianiat\
+  ib.ibiiblibribuic icaiceichiciickicoicsicticuid ideidiidniediel\
+  ienieriesif ifeiffifiifyighigniguikeil ilaildileiliillilsilyima\
+  imeimiimmimpin in,in.inaincindineinfinginiinkinninpinsintinuinv\
+  ionioriouip ipliquir ireiroirsis is,is:isciseishisnispistit it'\
+  it,it.itaiteithitiitlitsittituityivaiveiviix izejecjusk ak fk i\
+  k lk ok tk wk, k. kagke kedkeekerkesketkeykinknoks kspl al bl c\
+  l dl el fl gl hl il ll ml nl ol pl rl sl tl ul vl wl, l. lablac\
+  lailanlarlaslatlayld ldildnldsle le,le.le:lealecledlemlenlerles\
+  letlevlexlialibliclidlielifliklimlinlislitlk ll ll,ll.llellillo\
+  llsllyloalocloglonlooloplorloslotlowlp lrels ls,lselsolt ltilts\
+  ludlueluslutlvelwaly ly,ly.m am bm cm dm em fm im lm mm om sm t\
+  m wm'sm, m. macmadmaimakmalmanmapmarmatmaymbembime me,me.me:mea\
+  medmemmenmermesmetmicmigmilminmitml mmammemmimmommumodmonmormos\
+  moumovmpampimplmpomptmpums ms,ms.mucmulmusmutn \"n ,n .n an bn c\
+  n dn en fn gn hn in ln mn nn on pn rn sn tn un vn wn yn'tn, n. \
+  n.rn: nabnagnalnamnarnatnc ncenchncinclnconcrnctncuncynd nd,nd.\
+  ndandendindlndondsne ne,ne.necnedneenelnernesnevnewnexnfinfong \
+  ng,ng.ng:ngenglngsngtngunicnieninniqnisnitniznk nlynmennenninno\
+  no nonnornotnounownpuns ns,ns.nsansensinstnsunt nt,nt.ntantenti\
+  ntlntontrntsnuenulnumnvanvenviny nyto ,o .o ao bo co do eo fo g\
+  o ho io ko lo mo no oo po ro so to uo vo wo yo, o. o.toadobjobl\
+  ocaoceociockocuod od,od.odeodiodsoduodyoesof offoftogeogiogroin\
+  ojeok oksol oldoleolloloolsoluolvom omaomeomiomlommompon on'on,\
+  on-on.on:onaoncondoneonfongonlonmonnonoonsontonvoodookooloopoos\
+  ootop opeopioploppopsoptor or,or.or:ordoreorgoriorkormorrorsort\
+  oryos oseosiossostosuot otaoteothotiou ou'ougoulounourousoutove\
+  oviow ow,oweowiownowsp ap ip op rp tp wp, p. pacpanparpaspatpaw\
+  pdape pe,pe.peapecpedpenpeoperpespi piepilpinplaplepliploplypoi\
+  ponpooporpospotppepplppopprprepriprops pt pteptiptsptuptypubpur\
+  putquaquequir ,r 1r 2r ar br cr dr er fr gr hr ir lr mr nr or p\
+  r rr sr tr ur vr wr yr, r. r: racrairalramranraprarratrawrayrc/\
+  rcerchrd rderdsre re're,re.rearecredreerefregrelremrenrepreqres\
+  retrevrforgergorguriaribricrierigrinriorisritrivrk rkirksrlyrm \
+  rmarmirmsrn rn.rnarnernirnsro robrocrodrogrojrolromronrooropror\
+  rosrourovrowrporrarrerrirrors rs,rs.rsershrsirstrt rtarterthrti\
+  rtsrucrulrunrusrvery ry,ry.ryis (s ,s .s as bs cs ds es fs gs h\
+  s is ks ls ms ns os ps rs ss ts us vs ws ys! s\" s, s. s: s; saf\
+  sagsamsayscoscrscuse se,se.seasecsedseesefselsensepseqsersesset\
+  sh shasheshishosibsidsiesigsimsinsiositsivsizsk sksslismasn'so \
+  so,socsolsomsonsouspaspesplsposrcss ssassessissossust st'st,st.\
+  stastestistostrstssuasubsucsulsumsupsursynsyst .t at bt ct dt e\
+  t ft gt ht it kt lt mt nt ot pt rt st tt ut vt wt yt! t'st, t. \
+  t: ta tabtactaitaktaltantartastattaxtchte te,te.te:teatectedtee\
+  tegteltemtentertestexth thathethithothrthsthutiatictietiltimtin\
+  tiotiptirtittivtlyto togtomtootoptortputratretritrotrutryts ts,\
+  ts.ttettittrtuatupturtwetwoty typu au cu du hu mu nu ru su tu w\
+  u'lu'ru'vuagualuaruatubluccuceuchuctudeue ue,ue.uenuesughuiluir\
+  ul ulaulduleullultum umbumeumsun uncunduniunlunnunsuntup updupl\
+  uppur uraurcureuriurlurnurpurrursus useusiussustut utauteutiuto\
+  utputsutuvaivalvanvarvatve vecvedvelvenvervesvidvinviovirw aw c\
+  w hw iw lw mw ow pw rw sw tw vw ww, waiwanwarwaswaywe we'webwed\
+  weewelwerwevwhawhewhiwhowhywilwinwitwn wnewo wonworwouwrawriws \
+  wsex ix tx, xacxamxecxisxitxpexplxpoxprxt xt,xtextry ay by cy d\
+  y ey fy gy hy iy ly my ny oy py ry sy ty uy vy wy'ry, y. yetyin\
+  yncyntyouypeys ystyteythywoze ";
+
+#[allow(clippy::approx_constant)]
+static ENGLISH_TRIGRAM_LOG_PROBS: [f32; 2047] = [
+  -2.96, -3.01, -4.35, -3.85, -4.11, -4.19, -3.98, -4.13, -4.15, -4.22, -4.20, -4.01, -4.22, -4.26,
+  -4.34, -4.33, -4.08, -2.27, -3.28, -3.40, -3.22, -3.66, -3.85, -2.88, -4.28, -2.25, -3.48, -2.85,
+  -2.70, -3.13, -4.13, -4.02, -4.07, -3.79, -2.61, -3.63, -3.58, -3.20, -3.57, -3.08, -3.10, -2.48,
+  -2.87, -3.29, -2.29, -2.99, -3.70, -3.32, -2.77, -2.96, -2.86, -3.99, -4.11, -3.25, -4.21, -3.67,
+  -4.11, -3.07, -4.15, -3.26, -3.44, -2.81, -3.47, -3.68, -2.81, -4.17, -2.69, -3.12, -2.76, -4.16,
+  -3.11, -3.67, -3.61, -3.91, -3.75, -2.70, -3.29, -4.28, -3.07, -4.21, -4.05, -3.04, -4.23, -3.00,
+  -2.16, -2.54, -2.62, -4.30, -3.67, -3.55, -3.96, -3.53, -3.39, -3.09, -2.61, -3.01, -2.77, -2.81,
+  -3.41, -2.92, -3.18, -3.25, -2.90, -2.86, -3.47, -3.73, -2.38, -2.69, -3.35, -3.15, -3.30, -3.00,
+  -3.75, -3.45, -2.71, -3.65, -4.18, -3.70, -3.10, -2.66, -3.53, -3.95, -3.45, -2.47, -4.22, -4.08,
+  -2.71, -3.15, -3.45, -2.85, -3.05, -3.15, -3.61, -3.87, -2.87, -3.25, -3.75, -2.64, -3.33, -3.48,
+  -3.27, -3.03, -1.64, -3.48, -2.19, -2.95, -3.72, -3.48, -2.80, -3.27, -3.51, -2.57, -2.59, -3.32,
+  -4.13, -2.98, -2.32, -2.59, -2.56, -2.94, -3.41, -4.10, -2.62, -4.11, -4.23, -3.98, -4.27, -3.09,
+  -3.37, -2.86, -2.93, -3.44, -4.02, -4.01, -2.86, -3.24, -3.81, -4.26, -3.86, -4.01, -4.13, -3.24,
+  -3.68, -3.89, -3.99, -3.73, -4.20, -3.58, -3.22, -2.97, -4.20, -2.68, -3.39, -4.34, -3.31, -3.48,
+  -3.72, -4.27, -3.98, -3.42, -3.56, -2.97, -3.38, -3.99, -3.50, -3.78, -4.32, -3.59, -3.75, -2.66,
+  -3.94, -2.94, -3.72, -3.67, -4.19, -4.31, -3.94, -4.33, -4.26, -4.18, -4.35, -4.18, -4.32, -4.06,
+  -4.34, -4.21, -4.33, -4.26, -4.10, -4.26, -4.26, -4.34, -4.34, -4.37, -4.26, -3.97, -3.93, -3.76,
+  -4.28, -4.34, -4.18, -4.06, -3.40, -3.81, -4.23, -4.06, -4.35, -4.27, -4.27, -4.19, -3.69, -3.32,
+  -3.70, -3.36, -3.93, -4.26, -3.81, -3.57, -3.50, -3.49, -4.16, -3.33, -3.38, -3.14, -3.27, -4.23,
+  -3.29, -3.74, -4.30, -4.02, -2.95, -3.51, -4.11, -3.65, -3.41, -3.32, -3.43, -3.56, -3.34, -3.24,
+  -3.99, -4.12, -3.27, -3.90, -4.15, -3.69, -4.30, -4.03, -3.64, -3.65, -3.85, -3.14, -3.46, -3.12,
+  -3.12, -3.15, -4.07, -3.25, -3.41, -4.02, -2.64, -4.28, -3.32, -4.23, -2.76, -4.06, -3.41, -4.31,
+  -4.35, -2.82, -3.99, -3.73, -3.31, -4.03, -2.51, -3.86, -4.02, -3.36, -2.39, -3.19, -3.62, -3.57,
+  -3.64, -3.62, -3.03, -3.15, -4.02, -3.95, -3.42, -4.35, -3.25, -3.42, -3.19, -4.14, -3.48, -2.90,
+  -3.28, -3.09, -4.19, -4.02, -3.78, -3.88, -4.35, -4.02, -3.18, -3.34, -2.69, -3.36, -4.03, -3.95,
+  -3.86, -3.98, -3.27, -3.80, -3.68, -2.34, -4.05, -3.33, -3.47, -2.61, -3.51, -2.79, -3.48, -3.24,
+  -3.57, -3.88, -3.12, -4.13, -4.13, -3.00, -3.60, -4.05, -4.08, -4.20, -3.96, -3.34, -4.27, -4.35,
+  -3.70, -4.30, -4.02, -4.20, -3.03, -3.16, -4.11, -3.77, -3.72, -4.08, -3.46, -4.37, -3.75, -3.80,
+  -3.71, -4.07, -3.75, -2.85, -3.82, -3.61, -4.35, -3.85, -4.12, -3.80, -3.81, -3.41, -3.41, -4.07,
+  -4.10, -4.17, -4.05, -3.86, -3.16, -3.12, -4.27, -4.13, -4.17, -4.25, -4.25, -3.88, -4.30, -4.31,
+  -3.94, -2.92, -2.79, -3.97, -3.58, -3.47, -3.48, -3.14, -3.58, -2.90, -3.97, -3.81, -3.84, -3.98,
+  -4.23, -3.79, -4.26, -3.07, -2.79, -3.00, -3.44, -3.85, -4.35, -3.98, -3.73, -4.25, -4.23, -3.46,
+  -4.23, -4.32, -3.97, -3.37, -4.33, -4.25, -4.04, -3.94, -4.06, -3.95, -3.80, -3.86, -3.46, -3.83,
+  -2.79, -3.82, -2.84, -2.80, -3.48, -3.82, -3.45, -3.81, -3.30, -3.17, -4.25, -3.55, -4.10, -3.21,
+  -4.03, -4.14, -3.97, -2.70, -3.79, -3.46, -3.53, -3.63, -3.86, -3.88, -3.49, -3.53, -3.87, -4.07,
+  -4.00, -3.96, -2.94, -3.25, -3.38, -3.61, -3.63, -3.44, -4.01, -3.58, -3.00, -3.39, -3.55, -3.83,
+  -3.08, -3.48, -3.39, -3.35, -2.62, -3.64, -3.77, -3.18, -4.18, -3.35, -3.31, -4.30, -3.68, -3.26,
+  -3.64, -4.14, -3.85, -4.24, -2.77, -3.83, -3.81, -4.25, -3.75, -3.58, -3.10, -4.23, -4.34, -3.82,
+  -3.78, -3.36, -3.58, -3.76, -4.25, -3.93, -4.34, -3.92, -3.94, -3.33, -3.35, -3.65, -3.49, -3.74,
+  -3.75, -4.23, -3.90, -3.54, -3.85, -3.36, -4.26, -3.52, -3.82, -4.17, -4.17, -3.10, -3.98, -4.03,
+  -3.74, -3.50, -4.18, -4.33, -3.73, -3.92, -4.34, -3.83, -3.75, -4.34, -2.44, -2.99, -2.41, -2.88,
+  -2.99, -2.67, -3.41, -3.20, -2.52, -3.60, -3.07, -2.78, -3.02, -2.66, -2.76, -2.82, -2.56, -2.20,
+  -3.12, -2.93, -2.70, -3.57, -4.24, -3.19, -3.62, -3.63, -3.54, -2.78, -2.80, -3.46, -4.23, -3.38,
+  -2.97, -4.17, -4.07, -3.96, -3.46, -4.11, -3.51, -3.51, -3.13, -4.32, -3.15, -3.73, -4.26, -3.34,
+  -3.64, -3.92, -3.64, -2.84, -3.80, -2.42, -3.78, -3.63, -3.97, -3.94, -4.22, -3.35, -3.19, -3.52,
+  -3.90, -4.35, -3.83, -3.12, -4.34, -3.17, -3.65, -3.69, -4.16, -4.31, -3.75, -4.03, -3.81, -4.17,
+  -3.98, -3.86, -3.93, -3.65, -3.68, -4.35, -4.17, -3.57, -4.25, -4.00, -4.14, -3.56, -3.41, -4.27,
+  -4.05, -4.19, -4.28, -2.98, -3.60, -3.90, -3.71, -2.75, -4.00, -3.72, -3.77, -2.99, -3.26, -3.41,
+  -4.20, -4.23, -3.58, -2.54, -3.69, -4.17, -4.35, -4.00, -4.01, -3.72, -4.11, -3.75, -3.38, -2.41,
+  -3.38, -3.58, -3.08, -4.19, -2.66, -3.76, -3.40, -3.92, -3.27, -3.21, -2.93, -3.80, -3.89, -3.57,
+  -2.38, -3.30, -3.28, -4.01, -3.38, -4.16, -3.55, -3.77, -2.95, -2.98, -3.71, -3.22, -3.41, -3.83,
+  -3.27, -3.00, -3.48, -3.72, -3.96, -3.14, -3.77, -4.17, -4.24, -3.09, -4.19, -3.40, -3.88, -3.25,
+  -3.83, -3.79, -3.22, -3.37, -3.52, -4.14, -3.90, -4.07, -4.14, -3.31, -4.31, -3.60, -4.01, -4.02,
+  -4.16, -4.17, -3.58, -4.05, -3.88, -4.12, -3.82, -3.69, -2.69, -4.21, -3.48, -3.54, -4.04, -3.79,
+  -4.26, -3.88, -3.83, -3.92, -4.20, -2.92, -3.50, -4.23, -3.34, -4.28, -3.88, -3.46, -3.92, -3.26,
+  -3.05, -3.41, -4.08, -4.34, -3.65, -2.65, -4.26, -3.14, -3.56, -3.55, -2.84, -3.56, -3.69, -4.23,
+  -4.33, -4.25, -3.34, -3.86, -4.26, -4.34, -4.34, -3.10, -4.07, -3.67, -4.07, -3.88, -3.84, -4.24,
+  -3.42, -3.83, -4.01, -3.51, -3.96, -3.91, -3.55, -2.86, -4.07, -4.31, -3.69, -4.31, -3.86, -3.76,
+  -4.25, -3.85, -4.28, -4.34, -3.23, -4.17, -4.17, -3.48, -3.83, -3.43, -3.27, -3.71, -3.40, -4.17,
+  -3.84, -3.70, -3.99, -4.23, -3.84, -4.18, -3.73, -4.04, -4.22, -3.11, -4.15, -4.21, -4.11, -4.30,
+  -3.53, -4.12, -3.68, -3.92, -4.33, -4.30, -3.18, -3.88, -4.10, -3.92, -4.05, -4.33, -3.47, -3.72,
+  -3.60, -4.15, -4.16, -3.83, -3.11, -4.22, -3.75, -4.07, -4.02, -4.04, -2.98, -3.20, -3.73, -3.28,
+  -2.39, -2.92, -1.85, -3.93, -3.64, -4.11, -3.78, -3.77, -3.60, -2.89, -2.67, -3.37, -3.92, -3.49,
+  -3.14, -3.93, -3.24, -3.64, -2.69, -4.35, -3.12, -3.64, -4.17, -4.10, -3.65, -3.26, -2.98, -3.25,
+  -3.83, -3.46, -4.04, -3.34, -3.81, -3.72, -3.69, -4.30, -4.17, -3.70, -3.51, -4.27, -3.30, -3.45,
+  -3.47, -3.14, -3.92, -4.23, -4.34, -3.94, -4.35, -3.91, -3.63, -3.18, -4.30, -4.28, -3.84, -3.66,
+  -3.83, -3.94, -3.64, -3.04, -3.58, -3.39, -3.67, -3.58, -3.40, -3.44, -3.87, -3.38, -3.83, -3.62,
+  -3.82, -2.91, -3.66, -2.90, -4.01, -4.35, -4.31, -3.10, -3.57, -3.84, -3.04, -2.31, -4.28, -3.78,
+  -3.58, -3.65, -3.24, -3.04, -3.86, -2.21, -3.30, -4.16, -3.99, -4.16, -3.00, -2.82, -4.11, -3.98,
+  -2.37, -3.75, -4.17, -3.69, -3.69, -4.24, -3.77, -3.39, -4.17, -3.41, -2.33, -4.15, -3.98, -3.66,
+  -4.06, -3.86, -3.96, -4.32, -2.92, -2.74, -3.53, -4.02, -3.76, -4.35, -3.09, -2.75, -3.18, -4.18,
+  -3.27, -4.01, -3.94, -3.50, -4.02, -3.23, -4.19, -4.02, -3.82, -3.40, -3.68, -3.52, -4.15, -3.96,
+  -4.30, -3.78, -3.79, -4.09, -4.00, -3.95, -4.04, -3.07, -4.14, -4.07, -4.31, -3.68, -4.16, -3.71,
+  -3.41, -3.51, -3.57, -4.28, -3.50, -3.50, -3.50, -3.77, -3.86, -3.66, -3.93, -3.97, -3.56, -3.92,
+  -3.91, -3.86, -3.66, -3.66, -3.73, -3.60, -3.16, -4.04, -4.11, -3.72, -3.85, -3.76, -4.10, -3.74,
+  -4.33, -3.63, -3.29, -4.00, -3.55, -4.22, -2.98, -4.22, -4.14, -3.76, -2.61, -3.40, -3.61, -4.27,
+  -3.50, -4.08, -3.49, -3.01, -3.78, -3.49, -3.13, -3.23, -4.03, -4.14, -4.25, -3.44, -3.33, -3.67,
+  -4.01, -3.56, -3.38, -4.26, -3.15, -2.95, -3.44, -4.18, -2.51, -4.28, -4.13, -3.40, -3.68, -3.27,
+  -3.78, -3.32, -4.18, -3.37, -4.02, -3.68, -3.27, -4.32, -4.08, -3.48, -4.13, -3.27, -4.31, -4.28,
+  -3.50, -4.34, -4.33, -3.39, -3.69, -3.62, -4.22, -3.84, -2.76, -4.30, -4.20, -4.19, -4.06, -2.83,
+  -3.67, -3.86, -3.67, -4.30, -4.11, -4.24, -4.34, -4.34, -3.69, -4.35, -4.21, -3.97, -4.34, -3.33,
+  -3.90, -4.33, -3.83, -3.80, -3.54, -4.31, -3.55, -3.34, -4.05, -3.32, -4.33, -3.79, -3.25, -4.20,
+  -3.46, -4.27, -2.82, -3.94, -3.83, -4.04, -3.59, -3.61, -3.68, -2.80, -3.95, -3.25, -2.90, -4.08,
+  -3.58, -3.67, -3.45, -4.05, -4.05, -3.80, -3.81, -3.89, -3.98, -3.84, -3.38, -3.78, -3.18, -3.84,
+  -4.32, -3.70, -3.95, -3.20, -2.85, -3.90, -3.87, -4.35, -3.53, -4.31, -4.24, -4.08, -3.64, -3.87,
+  -3.47, -4.30, -3.86, -3.90, -2.75, -3.43, -3.10, -3.54, -3.22, -3.45, -4.13, -3.70, -2.86, -3.17,
+  -3.44, -3.93, -3.09, -3.55, -3.44, -3.31, -2.45, -3.40, -3.79, -3.08, -3.50, -2.93, -3.19, -3.25,
+  -3.88, -4.08, -4.25, -4.17, -3.36, -3.16, -3.91, -3.63, -3.83, -2.85, -4.23, -4.05, -3.88, -4.07,
+  -3.97, -2.84, -3.93, -3.98, -2.38, -4.18, -4.32, -3.65, -3.39, -3.43, -3.66, -4.35, -3.61, -2.96,
+  -4.23, -4.23, -4.01, -3.27, -3.24, -4.11, -3.18, -3.72, -4.13, -3.41, -3.76, -4.24, -3.92, -2.24,
+  -3.88, -3.76, -4.26, -3.36, -3.89, -4.01, -4.30, -3.67, -3.77, -4.31, -3.31, -4.25, -4.12, -3.45,
+  -4.35, -4.18, -3.37, -4.11, -3.63, -3.84, -3.72, -3.69, -4.22, -4.04, -2.95, -4.13, -3.17, -4.15,
+  -2.87, -3.91, -3.81, -4.06, -3.85, -3.47, -3.09, -3.86, -2.67, -3.80, -3.83, -3.09, -2.99, -3.18,
+  -4.05, -3.37, -3.78, -3.17, -4.31, -4.35, -3.29, -4.25, -3.86, -4.13, -3.20, -4.23, -4.13, -4.07,
+  -3.09, -3.51, -3.19, -3.41, -3.60, -3.56, -3.74, -3.54, -3.31, -3.99, -3.76, -3.40, -3.94, -3.69,
+  -3.54, -3.37, -3.20, -2.82, -3.33, -4.13, -3.37, -4.14, -3.86, -3.94, -4.18, -4.25, -3.75, -3.90,
+  -3.93, -3.87, -3.92, -3.49, -3.83, -3.30, -4.28, -4.31, -2.78, -4.07, -3.74, -3.33, -3.89, -3.29,
+  -2.39, -4.14, -4.03, -4.31, -4.17, -3.15, -3.27, -3.68, -3.57, -4.17, -3.83, -3.65, -4.20, -3.46,
+  -4.26, -4.35, -4.21, -4.23, -3.13, -4.05, -3.20, -4.17, -4.17, -3.47, -3.02, -2.45, -3.34, -3.48,
+  -4.34, -3.42, -4.26, -3.66, -3.39, -3.53, -3.11, -4.21, -3.72, -3.37, -4.17, -4.17, -4.25, -2.90,
+  -3.25, -3.88, -4.27, -3.39, -3.77, -3.69, -4.25, -4.34, -3.74, -3.18, -4.35, -4.35, -4.10, -4.33,
+  -4.08, -2.53, -4.05, -3.81, -4.28, -3.52, -2.99, -4.23, -3.85, -3.31, -3.52, -3.58, -3.60, -3.56,
+  -3.53, -4.20, -3.40, -4.34, -3.76, -3.61, -3.54, -3.24, -3.76, -3.64, -3.04, -4.31, -2.84, -3.41,
+  -3.53, -3.08, -3.47, -2.93, -3.80, -3.00, -3.25, -3.62, -2.91, -4.17, -3.49, -3.61, -3.16, -3.39,
+  -3.91, -3.96, -3.83, -4.35, -3.84, -4.20, -4.14, -4.10, -3.64, -3.87, -2.99, -3.58, -3.24, -4.21,
+  -4.18, -2.98, -3.85, -3.73, -4.23, -3.29, -4.10, -3.44, -4.37, -3.24, -3.36, -4.22, -4.21, -3.19,
+  -4.08, -3.50, -2.76, -3.64, -4.17, -4.25, -3.39, -3.86, -4.18, -3.79, -3.45, -4.35, -3.60, -4.01,
+  -4.18, -4.14, -3.26, -3.37, -2.76, -3.93, -3.88, -3.27, -3.90, -4.21, -4.32, -4.26, -3.85, -4.32,
+  -3.39, -4.10, -3.48, -3.71, -4.21, -3.75, -4.34, -3.17, -3.73, -3.19, -3.72, -3.34, -3.59, -4.19,
+  -3.93, -3.23, -3.75, -3.49, -3.77, -3.31, -3.39, -3.65, -3.46, -2.75, -3.86, -3.86, -3.35, -4.14,
+  -3.27, -3.37, -4.00, -3.44, -3.16, -3.68, -2.97, -3.59, -4.17, -3.46, -2.80, -4.23, -4.20, -3.76,
+  -4.02, -4.11, -3.41, -4.08, -4.14, -2.44, -3.84, -3.68, -3.92, -2.78, -3.24, -3.49, -3.77, -3.04,
+  -4.23, -3.68, -3.76, -2.85, -3.79, -3.53, -2.86, -3.07, -3.86, -3.98, -4.03, -3.62, -3.67, -3.15,
+  -4.07, -3.57, -3.75, -4.15, -3.07, -4.12, -4.34, -3.40, -3.92, -3.66, -4.19, -3.82, -3.92, -3.83,
+  -3.72, -4.05, -4.13, -3.17, -4.30, -4.03, -3.81, -4.03, -3.46, -3.83, -3.83, -3.87, -3.80, -3.15,
+  -3.68, -4.11, -3.15, -3.76, -4.30, -3.85, -3.26, -4.01, -3.64, -3.59, -3.62, -4.28, -4.17, -3.46,
+  -4.19, -3.15, -3.09, -3.81, -3.84, -4.31, -3.68, -3.69, -3.36, -3.33, -4.05, -4.25, -4.23, -3.66,
+  -3.77, -3.22, -3.81, -3.16, -2.94, -3.98, -3.03, -4.13, -3.95, -4.32, -4.34, -3.87, -3.99, -2.47,
+  -3.29, -2.91, -3.27, -3.39, -3.12, -3.86, -3.57, -2.75, -4.24, -3.40, -3.31, -3.36, -2.83, -3.27,
+  -3.40, -3.03, -2.44, -3.40, -3.79, -2.89, -3.63, -4.22, -4.23, -2.85, -2.87, -3.43, -4.20, -3.64,
+  -3.55, -3.38, -4.06, -3.52, -4.12, -3.69, -2.58, -3.87, -4.00, -4.19, -3.44, -3.28, -3.39, -3.85,
+  -4.13, -3.53, -4.02, -4.34, -3.36, -3.39, -3.81, -3.97, -3.90, -4.01, -3.56, -3.14, -3.68, -3.57,
+  -4.27, -3.53, -3.61, -3.05, -3.34, -3.79, -4.26, -4.10, -4.26, -4.23, -3.69, -3.86, -3.40, -2.97,
+  -4.21, -3.92, -3.99, -3.30, -3.84, -4.24, -3.70, -3.33, -4.28, -3.87, -3.73, -3.24, -3.53, -3.53,
+  -3.22, -3.90, -4.28, -2.62, -3.86, -3.68, -3.92, -2.86, -3.23, -2.92, -3.50, -2.91, -3.45, -4.35,
+  -4.25, -3.61, -3.68, -4.04, -4.19, -3.35, -3.44, -3.78, -4.26, -2.82, -3.26, -2.96, -3.25, -3.44,
+  -3.25, -3.82, -3.17, -2.81, -4.10, -3.49, -3.30, -3.40, -2.92, -3.22, -3.28, -3.20, -2.51, -3.50,
+  -3.69, -2.84, -3.54, -4.22, -3.02, -3.08, -3.17, -3.97, -3.41, -3.41, -4.04, -3.40, -3.49, -3.74,
+  -3.13, -3.61, -3.97, -3.04, -3.82, -3.42, -2.78, -4.08, -3.94, -4.30, -3.49, -4.18, -3.05, -4.23,
+  -3.92, -3.66, -3.27, -3.47, -2.55, -2.98, -3.79, -2.79, -2.39, -1.77, -2.60, -2.92, -3.17, -4.33,
+  -4.34, -4.12, -3.47, -4.30, -3.61, -3.09, -2.74, -2.42, -3.71, -4.25, -4.28, -3.77, -3.46, -2.19,
+  -4.33, -3.68, -3.83, -3.98, -3.05, -3.69, -2.97, -3.83, -3.34, -3.86, -3.21, -3.64, -2.71, -3.76,
+  -3.78, -3.20, -4.05, -4.35, -3.72, -3.86, -2.83, -3.79, -3.48, -3.40, -2.79, -4.22, -3.40, -4.18,
+  -3.99, -3.92, -4.32, -4.27, -4.05, -3.96, -3.76, -3.79, -3.90, -4.13, -3.69, -3.62, -3.97, -3.86,
+  -3.79, -4.08, -3.90, -3.61, -3.20, -3.95, -2.99, -3.91, -3.82, -4.27, -3.09, -3.52, -3.86, -3.89,
+  -3.71, -3.73, -3.08, -3.33, -3.94, -3.23, -3.92, -3.53, -3.40, -4.19, -3.50, -2.83, -3.50, -3.86,
+  -4.32, -3.88, -3.78, -3.40, -3.67, -4.18, -3.77, -4.20, -2.97, -4.00, -4.27, -2.91, -4.23, -4.18,
+  -3.10, -4.31, -3.68, -4.19, -3.40, -2.59, -3.19, -3.69, -2.80, -2.80, -3.41, -3.60, -4.07, -4.17,
+  -3.69, -4.31, -3.63, -4.17, -2.70, -4.17, -3.16, -4.18, -2.77, -3.72, -3.92, -3.79, -3.41, -2.88,
+  -3.83, -3.61, -3.80, -3.69, -4.17, -4.03, -4.02, -4.24, -3.92, -4.20, -4.19, -4.25, -4.07, -4.33,
+  -4.23, -3.27, -4.25, -3.86, -4.08, -3.90, -3.34, -4.07, -4.03, -3.31, -2.47, -2.92, -4.31, -3.97,
+  -3.80, -3.96, -3.78, -3.79, -3.39, -2.89, -3.09, -4.08, -4.05, -2.98, -3.56, -2.77, -3.42, -3.55,
+  -3.51, -3.75, -3.12, -3.42, -4.25, -3.49, -3.50, -4.27, -4.19, -4.08, -4.35, -4.17, -3.30, -3.91,
+  -4.12, -4.30, -4.01, -3.76, -4.33, -3.57, -3.69, -4.13, -4.22, -4.21, -3.31, -3.72, -3.36, -3.59,
+  -3.88, -3.78, -4.19, -3.91, -3.39, -3.89, -3.94, -3.97, -3.32, -3.70, -3.68, -3.48, -2.99, -3.68,
+  -4.07, -3.50, -4.21, -3.40, -3.41, -4.15, -3.80, -3.70, -3.81, -2.62, -2.80, -3.73, -3.78, -4.27,
+  -4.00, -3.90, -4.07,
+];
+
+const ENGLISH_QUADGRAM_FLOOR: f32 = -8.27;
+
+static ENGLISH_QUADGRAM_GRAMS: &[u8] = b"\
+  \x20, , , a , b , i , s , t , w . i . n . t . w 10- 12- 15- 16- 17-\
+  \x2018- 19- 20- 21- a , a . a b a c a d a f a g a h a i a l a m a n\
+  \x20a p a r a s a t a u a v a w abl abo abs acc act add adv aft aga\
+  \x20all alr als alt alw amo an  and ann ano any api app are arg arm\
+  \x20aro arr as  ass asy at  att aut ava awa bac bas be  bec bee bef\
+  \x20beh bei bet bin bit blo bod boo bor bot bou bra bri bro bug bui\
+  \x20but by  byt cal can cap car cas cau cha che chi cho cle clo cod\
+  \x20col com con cop cor cou cov cra cre cur cus dat dec def dem dep\
+  \x20der des det dev did dif dir dis do  doc doe doi don dow dro eac\
+  \x20ear eas eff eit ele els emp ena enc end ens ent enu env equ err\
+  \x20eve exa exc exe exi exp ext fai far fea few fie fig fil fin fir\
+  \x20fix fol for fou fro ful fun fut gam gen get giv go  goe goo gre\
+  \x20gro gua gue had han hap har has hav hea hel her hol how ide if \
+  \x20ign imm imp in  inc ind inf inn inp ins int inv is  isn it  it'\
+  \x20it, it. ite its jus kee key kin kno lan lar las lat lea len les\
+  \x20let lib lif lik lin lis lit loc log lon loo lot low mac mad mai\
+  \x20mak man map mar mat may mea mem men mes met mig mod mor mos mov\
+  \x20muc mul mus mut nam nee nev new nex no  non not now num obj of \
+  \x20off oft on  onc one onl ope opt or  ord oth our out ove own pac\
+  \x20pan par pas pat peo per pla poi poo pos pre pri pro pub pur put\
+  \x20que qui ran rat raw rea rec ref reg rel rem rep req res ret roo\
+  \x20rul run rus saf sam say sco sea sec see sen sep ser set sha sho\
+  \x20sig sim sin sit siz sli sma so  so, sol som spa spe src sta sti\
+  \x20sto str sub suc sup sur syn sys tak tal tas tec tel ter tes tex\
+  \x20tha the thi tho thr tim to  tog too top tra tre tri try tup tur\
+  \x20two typ und uni unl uns unt up  upd us  use usi val var vec ver\
+  \x20wai wan was way we  we' web wel wer wha whe whi who why wil wit\
+  \x20won wor wou wra wri yet you! th! we\" se'll 're 's a's b's c's d\
+  's e's i's l's m's n's o's p's r's s's t't a't b't c't h't i't m\
+  't n't r't s't t't u't w've , , , a , al, an, as, be, bu, ca, co\
+  , ev, fo, if, in, is, it, le, li, ma, me, no, or, re, ru, so, st\
+  , su, th, to, us, we, wh, wi, yo-lev. a . al. an. as. at. be. bu\
+  . by. ch. co. ea. fi. fo. he. ho. if. in. it. le. li. ma. ne. no\
+  . on. re. ru. so. th. to. un. us. we. wh. wi. yo.rs .tom/lib/mai\
+  : a : fi: if: in: it: ru: sr: th: we; th; wea , a . a bia caa ch\
+  a cla coa cra dea dia fea fia fua gea ina lia loa maa mea moa mu\
+  a nea nua paa poa pra raa rea rua sea sia sla sta tea tha toa tr\
+  a tua tya vaa vea waabilableabouabstacceace acesach ack ackaacke\
+  acroact acteactiactlactuad oad pad tad, ad. add addeaddiaddrade \
+  adinads advaady afe afetafteagaiage age.agesail ailaailsain ain.\
+  ainiainsait ait,ait.aitsake akesakinal cal mal pal sal tal valid\
+  alitalk all alleallialloallsallyalreals alsoaluealwaam iam tam w\
+  am, am. ame ame:amedamesametammeammiamplams an aan ban can dan e\
+  an fan han ian man oan pan ran san tan uan van wan'tanaganceand \
+  andaandlandsangeanglanguanicaninanneannoanotans ant anteantsany \
+  anytapi appeapplapprapteaptuar taracaramaranaratarchard are ared\
+  arenargeargoarguariaariearioarlyarm arnearniarouarraart artiarts\
+  ary ary.as aas bas das fas ias las mas oas pas sas tas was yase \
+  ase,asedasesash asieask asksasonass asseassiassoast asynat aat b\
+  at cat dat eat fat hat iat lat mat nat oat pat rat sat tat uat v\
+  at wat yat'sata ata.atchate ate,ate.atedatelatemateratesath athe\
+  aticatinatioativatoratteattraturaultauseautoavaiave avenavinavio\
+  aw pawaiawneay tay, ay. ays b.rsbackbe abe cbe dbe ibe mbe pbe r\
+  be sbe tbe ube vbecabecobeenbefobehabeinber bersbetwbilibinabind\
+  bit bjecble ble,ble.blemblesblicblisblocbodybookborrbothbounbout\
+  bracbrarbrinbrowbstrbuilbut buteby aby cby dby iby rby sby tby u\
+  bytec blc coc tyc/lic/macal callcan can'captcargcasecatecaticaus\
+  ccepccesce ace bce cce ice oce tce wce, ce. ced ceduceivceptces \
+  ces,ces.cessch ach cch ech fch ich mch och pch sch tch wchanchap\
+  charcheccheschinchnichoocialciatcidecificifycingcitlck ack ick o\
+  ck tck, ck. ckagcketckincks clarcleacloscludcodecollcolocombcome\
+  commcompconccondconfconnconscontconvcopecorecorrcoulcouncovecrat\
+  creacretcro crosct act ict oct tct wct, ct. ctedcterctioctivctly\
+  ctorcts cts,ctuacturculacumecurlcurrcurscusscustcuted , d . d a \
+  d ald and ard asd atd bed byd cad chd cod ded did dod end exd fi\
+  d fod frd fud ged had hod imd ind isd itd lid lod mad med mod na\
+  d nod ofd ond oud pad pod prd red rud sed sid sod std ted thd to\
+  d trd tyd upd usd vad wed whd wid wod yod, ad, td, wd. id. td. w\
+  darddatadatedd add tddedddindditddrede ade cde fde ide mde ode s\
+  de tde ude wde, de. decideclded defadefidemodencdentdepeder dere\
+  dersdes descdesidetadetedevedex dicadid didndiffdifydingdiredisc\
+  dispditidle dlindn'tdo tdocudoesdoindon'donedowndresdropds ads i\
+  ds ods tds, ds. duceduleduradvandy oe , e . e a e abe ace ade al\
+  e ane ape are ase ate bae bee bie ble boe bre bue bye cae che cl\
+  e coe cre cue dae dee die doe eae ele ene ere exe fae fie foe fr\
+  e fue gee goe gue hae hee hoe ife ime ine ise ite kee kne lae le\
+  e lie loe mae mee mie moe mue nae nee noe nue ofe one ope ore ot\
+  e oue owe pae pee ple poe pre pue que rae ree roe rue sae sce se\
+  e she sie sle soe spe ste sue sye tae tee the tie toe tre tue tw\
+  e tye une upe use vae vee wae wee whe wie woe wre yoe'lle'ree's \
+  e'vee, ae, be, ie, le, re, se, te, we, ye. ae. be. fe. he. ie. l\
+  e. ne. re. te. we: ie: se: teachead ead,ead.eadieadseadyeam ean \
+  eanieanseap ear earcearlearneaseeasieasoeateeatieatuecalecauecei\
+  ecesechneciaecidecifeck eckieckseclaecomeconect ect,ecteectiectl\
+  ectoectsecuted .ed aed bed ced ded eed fed ied led oed ped red s\
+  ed ted ued ved wed, ed. eds eduree iee tee weed eedseen eep efau\
+  eferefineforefulefutegeregraehaveingeir eitheiveelateld eldselem\
+  ell elloelopelp elseely ely,em aem iem tem, em. emaiembeemenemon\
+  emoremptems en aen cen den ien men ren sen ten uen wen yen'ten, \
+  enabenamenceenciencoencyend endeendiendsenerengtenieens enseensi\
+  ensuent ent,ent.entaenteentientlentsenumenvieopleparepenepreept \
+  eptsequaequeequier 1er 2er aer ber cer der eer fer ger her ier l\
+  er mer ner oer per rer ser ter ver wer, er. eraleratere ere'ere,\
+  eredereferenerfoericermiern ernaernserrierroers ers,ers.ershersi\
+  erstert ertierveery erytes aes bes ces des ees fes hes ies les m\
+  es nes oes pes res ses tes ues wes yes, es. es: escrese esenesig\
+  esn'espoess essaesseessiest esteestiestrestsesulet aet oet tet u\
+  et'setaiete eteretheethiethoetimets etteettieturetweety eveleven\
+  everew tew vexacexamexecexisexitexpeexplexpoexprext ext,exteextr\
+  ey cey'reywof , f . f a f anf cof inf isf itf ouf ref ruf scf th\
+  f tyf vaf wef whf yofactfailfaulfeatfectfer fereferrfetifetyfew \
+  ffecfferfficficafiedfielfigufilefinafindfinefinifirsfix follfor \
+  foreformfromftenfterful fullfuncfutufy tfying , g . g 10g 12g 15\
+  g 16g 17g 18g 19g 20g 21g 7-g 8-g 9-g a g ang asg cog fog ing is\
+  g itg lag lig ofg ong oug reg rug slg sog syg thg tog vag whg wi\
+  g yog. tgaingamege age ige oge tge, ge. geneger ges ges,ges.get \
+  gethgetsgh tght gic ginggivegle gnatgnorgo.tgoesgoodgramgratgrea\
+  grouguagguarguesgumegureh , h . h a h anh arh ash cah coh eah in\
+  h ish ith mah meh ofh onh oth sth thh toh vah weh wihad han hand\
+  hanghannhapphaptharaharehas hashhat hat'havehavihe \"he ,he ahe b\
+  he che dhe ehe fhe ghe hhe ihe khe lhe mhe nhe ohe phe rhe she t\
+  he uhe vhe wheapheckhed heirhellhelphem hem.hen hen,her her.here\
+  hes hesehethhey hey'hichhilehin hinghinkhip his his:hod hod,hod.\
+  hodsholdhooshorthosehoughoulhouthow howehownhowshreahreehrouht b\
+  htmliablial ialliantiateib.ribilibleibraibutic aic iic ticalicat\
+  ice icesich icitics iculide idedidenideridesidn'ied ieldientier \
+  ies if aif iif tif wif yifetiffeificifieify ifyiightign ignaigno\
+  igurike il iilabilarild ile ile,ile.iledilenilerilesilitill ils \
+  ime ime,ime.imesimilimitimmuimplimpoin \"in ,in .in ain bin cin d\
+  in ein fin iin lin min oin pin rin sin tin win yin, in. in.rinal\
+  inarinatinclincrind indeindiindsine inedinesinfoing ing,ing.ing:\
+  inglingsinininisinitink inneinpuins insiinstint inteintiintointr\
+  intsinueinvaion ion,ion.ion:ionaionsior iousip aip oipleiqueire \
+  irecironirstis ,is ais bis cis dis eis fis gis his iis lis mis n\
+  is ois pis ris sis tis uis vis wis, is: iscuish isheisn'ist isti\
+  istsit ait bit cit dit fit hit iit mit nit oit pit rit sit tit w\
+  it'sit, it. ite itemiterith itheithiithoitinitioitivitlyits itte\
+  ituaity ivative iveniverivesize jectjustk abk atk fok ink lik of\
+  k thk tok wikageke ake ike oke ske tked keepkes ketskey keywkind\
+  kingknowks aks lks. kspal all anl asl bel cal col crl del dil ex\
+  l fil fol gel hal inl itl lol mal nel nol ofl onl pal prl rel ru\
+  l sel stl tal thl tol usl val whl wil. tlabllacelainlanglar lare\
+  larglastlatelatild ald bld cld hld ild mld nld old pld rld sld t\
+  ld wldinldn'lds le ale ble cle ele fle ile nle ole ple rle sle t\
+  le vle wle, le. le: leanlearleaslectled lem lemelemslenalengler \
+  les les,les.lesslet let'leteletslevelib.librlic licalicelicilid \
+  lifelikelimilinelinglishlistlitelitylk all all bll cll dll ell f\
+  ll gll hll ill lll mll nll oll pll rll sll tll ull wll, ll. llec\
+  lledllinllocllowlls lly lly,loadlocalocklogilonglooklooplorelosu\
+  lot low lowelowilowslreals ols tls, lso lt ilt olt, ltiplts lude\
+  lue lue,lue.lueslwayly aly bly cly dly ely fly hly ily nly oly r\
+  ly sly tly uly wly, ly. m a m anm inm ism thm tom wim's macrmade\
+  mainmakemakimallmanamancmandmanymarkmartmatcmatimattmay mbermbin\
+  me ame cme eme fme ime nme ome pme rme sme tme wme, me. me: mean\
+  med memomentmersmes messmetemethmetimighmilaminaminemingml fmman\
+  mmenmmermminmmonmmutmodimodumon monsmoremorymostmovemparmpilmple\
+  mplimpormptymputms ams ims, ms. muchmultmustmutan , n . n a n ab\
+  n acn adn aln ann arn asn atn ben bon byn can chn con crn den di\
+  n don ean enn ern exn fan fin fon frn fun gen han hen hon imn in\
+  n isn itn lin man men mon mun ofn onn opn orn otn oun pan prn re\
+  n run sen sin son stn tan ten thn ton tyn unn usn van wen whn wi\
+  n wrn yon't n, an, in, sn, tn, wn. an. in. tn. wn.rsnablnagenal \
+  nalinallnamenaminarynatenatunc bnce nce,nce.ncepncesncluncrencti\
+  ncurncy nd ,nd .nd and bnd cnd dnd end fnd gnd hnd ind lnd mnd o\
+  nd pnd rnd snd tnd und vnd wnd ynd, nd. ndarndennderndexndicndin\
+  nditndlendlinds ne ane cne ene fne ine mne one pne sne tne wne, \
+  ne. nectned neednel ner neranerinersnes nevenew nextnforng ,ng .\
+  ng 1ng 2ng 7ng 8ng 9ng ang bng cng dng eng fng gng ing lng mng o\
+  ng png rng sng tng ung vng wng yng, ng. ng: nge ngerngesnginngle\
+  ngs ngthnguanic nienningniqunishnitinly nmennnecnnelnnernninnnot\
+  no lnorenot notanotenothnotinow now,nownnowsnputns ans cns fns i\
+  ns ons tns wns, ns. nsafnse nsidnstanstenstrnsurnt ant bnt cnt d\
+  nt fnt int mnt ont pnt snt tnt vnt wnt, nt. ntaintatntaxntedntee\
+  ntegntennterntexntilntimntinntiontirntlynto ntronts nts,nts.num \
+  numbnumsnvalnvennvernvirny cny ony pny tny vnytho , o . o a o ac\
+  o ado alo ano aso beo bro cao cho coo cro deo dio doo eao eno ex\
+  o fao fio foo geo hao hoo imo ino iso ito kno lio loo mao moo ne\
+  o noo ono ouo pao pro reo ruo sco seo sho soo spo sto tao teo th\
+  o too tro uno uso vao wao weo wio woo wro yoo, wo.toobjeobleocat\
+  ocedocesociaock ocksocumod iod ood tod wod, od. ode ode,ode.odif\
+  ods oducodulody oes oesnof ,of .of aof bof cof dof eof fof hof i\
+  of mof oof pof rof sof tof uof vof wof yofteogetogicograoingoint\
+  ojecok aok loks old oldsolleolloolutolveom aom oom tomatombiome \
+  omesometoml ommaommeommoompaompiomplompuon aon bon con don eon f\
+  on hon ion mon non oon pon ron son ton won yon'ton, on. on: onal\
+  onceoncroncuond ondione onedonfiong ongeonlyonmeonneons ons,ons.\
+  onseonsionstontaonteontiontronveood ook ooksool oop ooseope ope,\
+  ope.openoperopleoppeoptior ,or aor bor cor dor eor for hor ior m\
+  or nor oor por ror sor tor uor wor yor, or. or: ord ordeordsore \
+  ore,oredorieork orkiorksorm ormaorreorroors ort ortaory ory.ose \
+  ossiost osurot aot cot iot oot totatote ote:oth otheothiou aou c\
+  ou dou hou mou nou rou sou tou wou'lou'rou'voughouldoundountour \
+  ourcous out outpove ovedoverovidow aow cow how iow low mow oow t\
+  ow wow yow, owedowerowevowinown owneows owsep inp ofp thp wipace\
+  packpaniparaparepartpasspathpattpawnpdatpe ape ipe ope ppe spe t\
+  pe wpe, pe. pecipectped pen pendpenspeopperaperfperspes pes,pes.\
+  pilepingplacplaiplayple ple,plemplespletplexplicplorply poinpons\
+  poolportposeposspostppedppenpplipplypporppropresprevprinprivprob\
+  procprodprogprojpropprovpterptiopts pturpty publpurpput putequal\
+  que quenquesquirr , r a r alr anr asr ber car cor crr der dor ea\
+  r enr err exr for fur har inr isr itr lar lir mar mer mor mur no\
+  r ofr onr orr owr par prr rer rur ser shr sir sor str ter thr to\
+  r tyr usr var war wer whr wir wor yor, ar, ir, sr, tr, wr. ir. t\
+  r. wracerackractraitral rallram ram,ramerammramsrangransrantrary\
+  raterathratiratoraw rc/lrc/mrce rd lrderrds,re are bre cre dre e\
+  re fre gre hre ire lre mre nre ore pre rre sre tre ure vre wre y\
+  re'sre, re. readrealreamreasreatrecarecerecorectred ree referefo\
+  refurelareleremaremeremoren'rencrentreprrequres res,reserespress\
+  restresureteretureverevirforrgo rgo.rgumriabrianriatriburic rics\
+  rienriesringrintriteritirittrivark wrkinrks rksprly rmanrmatrmin\
+  rms rn arn irn trn vrn. rnalrnedrninrns roblrocerodurogrrojerol \
+  rom ronmrootroppror ror.ror:rorsros rougrounroverovirow rowirows\
+  rposrrayrrecrrenrrorrrowrs ars crs frs irs ors trs wrs, rs. rshi\
+  rsiorst rst,rstart ort prt trtanrticrtinrts ructrulerun runnruns\
+  runtrustrverry ary cry iry ory pry sry try, ry. ryinryths , s . \
+  s a s abs acs ads als ans ars ass ats bes bos bus bys cas chs co\
+  s crs des dis dos eas ens ers exs fis fos frs fus ges has hos if\
+  s ims ins iss its les lis los mas mes mos mus nas nes nos ofs on\
+  s ors ous ows pas pos prs res rus sas ses shs sis sos sps sts su\
+  s tes ths tos trs tws tys uns ups uss vas ves was wes whs wis wo\
+  s wrs yos\" ss, as, bs, is, rs, ss, ts, ws, ys. as. bs. cs. fs. h\
+  s. is. ls. ns. ts. ws: ts: wsafesagesamesay scopscriscusse ase b\
+  se cse dse fse ise mse ose pse rse sse tse wse yse, se. searseco\
+  sectsed see seensefuselfsendsenssentsepasequser serssertservses \
+  ses,set sh msharshedshipshorshoushowsibisiblsidesiersignsimisimp\
+  singsionsitisitusivesizeslicsmalsmarsn'tso aso cso fso hso iso t\
+  so uso wso, socisolusomeson sourspacspawspecsponsrc/ss ass iss o\
+  ss tssagssedsserssesssibssigssinssiossocst ast bst cst dst est f\
+  st hst ist lst mst ost pst rst sst tst wst'sst, st. stacstalstan\
+  starstatsteastedstemstilstinstomstopstorstrastrestristrusts sual\
+  succsuchsultsumesuppsuresyncsyntsystt , t . t a t act alt ant ar\
+  t ast att bet bot byt cat cht cot crt dat det dit dot eat ent ev\
+  t ext fat fit fot frt fut get got hat hot ift imt int ist itt kn\
+  t let lit lot mat met mit mot mut nat net not nut obt oft ont or\
+  t out pat pot prt ret rut set sht sot stt sut tat tet tht tot tr\
+  t tyt ust vat vet wat wet wht wit wot yot's t, at, bt, it, st, t\
+  t, wt. it. tt. wta ita sta tta. tabltacktailtaintaketalktalltanc\
+  tandtanttarttasktatetatitax tch tchete ate cte fte ite mte ote p\
+  te rte ste tte wte, te. te: teadtechted ted.tegetegrtelltelytem \
+  temetemptemsten tentter ter,ter.terateritermternterstes tes.test\
+  textth .th ath cth eth ith oth sth tthanthatthe theithemthenther\
+  thestheythinthisthodthosthouthrethrotialtic ticaticeticutiestil \
+  tilltimetingtinutiontipltiretivetly tly.to ,to .to ato bto cto d\
+  to eto fto gto hto ito kto lto mto oto pto rto sto tto uto wto y\
+  togetom tomatomltooltop tor tor,tor.toretorstorytputtractraitran\
+  trattreatribtrintrodtroltructry tryits ats bts cts dts fts its o\
+  ts pts sts tts uts wts, ts. ttemttentterttinttritualtuattuplture\
+  turntweetwo ty oty tty. typeu cau dou hau miu neu tou wau wou'll\
+  u'reu'veuageual ualluarauatiubliucceuce uch uct uctsuctuude ue a\
+  ue fue iue oue tue wue, ue. uencues ues.uessuestugh uilduireular\
+  ulatuld uldnule ulesull ullyult ult,ultiultsumbeumenun aun tunct\
+  und undeunitunniunsaunt untiup tupdaupleuppour cur fur lur our p\
+  ur sur turalurceure ure,ure.uresurinurlyurn urneurniurnsurpourre\
+  us aus tuse usedusefuserusesusinuss usseust ust'ust,ust.ustout a\
+  ut dut fut hut iut nut out sut tut wutabute uterutioutomutpuutur\
+  vailvalivaluvantvarivateve ave bve cve dve ive mve nve ove pve s\
+  ve tve uvectved vel veloven ven'veniventver ver,veraversvertvery\
+  ves videvingviorvirow cow inw pow thw tow vaw wew whw yowaitwant\
+  was way wayswe awe cwe dwe gwe hwe iwe kwe mwe nwe pwe rwe swe t\
+  we uwe wwe'lwe'rwe'vweb wed weenwellwerewevewhatwhenwherwhetwhic\
+  whilwhy willwingwithwn awn iwn twnedwnerwon'wordworkwoulwrapwrit\
+  ws aws twserx thxactxampxecuxistxpecxplaxplixploxprext, xtray a \
+  y ady any ary asy bey bry cay coy cry dey diy doy exy foy hay im\
+  y iny isy ity liy ney ofy ony oty pay pry rey ruy shy soy spy st\
+  y thy toy tyy usy vay way wey why wiy woy'rey, ay, ty, wy. iy. t\
+  y. wyingync yntayou you'yourype ype,ype.ypesys tysteythiywor";
+
+#[allow(clippy::approx_constant)]
+static ENGLISH_QUADGRAM_LOG_PROBS: [f32; 4095] = [
+  -4.17, -3.64, -4.09, -4.30, -4.20, -3.88, -3.52, -3.95, -4.35, -3.44, -3.79, -4.18, -4.32, -4.06,
+  -4.34, -4.21, -4.39, -4.33, -4.10, -4.26, -4.28, -4.30, -3.73, -3.34, -3.71, -3.38, -3.93, -4.28,
+  -4.13, -3.57, -3.50, -3.49, -3.34, -3.40, -3.18, -3.35, -4.24, -3.30, -3.79, -4.03, -3.51, -4.11,
+  -3.65, -3.88, -3.28, -4.32, -3.71, -3.88, -3.20, -4.28, -3.39, -4.34, -4.06, -4.39, -2.90, -2.47,
+  -3.79, -3.65, -3.28, -3.98, -3.65, -3.06, -3.67, -3.78, -4.38, -4.07, -2.83, -3.59, -3.75, -3.20,
+  -3.99, -4.15, -4.17, -4.08, -4.03, -4.30, -3.03, -3.16, -4.11, -3.77, -3.72, -4.08, -3.75, -3.85,
+  -4.20, -3.61, -3.85, -4.13, -3.82, -3.81, -4.15, -4.13, -4.07, -4.10, -4.22, -3.88, -3.20, -3.13,
+  -4.28, -3.02, -2.80, -4.10, -3.62, -3.49, -4.12, -3.01, -3.64, -4.39, -3.98, -4.00, -3.46, -2.81,
+  -3.94, -2.87, -2.85, -4.26, -3.97, -3.50, -3.92, -3.31, -3.28, -3.88, -4.17, -3.34, -3.75, -3.11,
+  -4.35, -3.83, -4.10, -3.86, -3.76, -4.25, -3.94, -3.39, -3.69, -3.50, -3.55, -3.86, -3.36, -4.26,
+  -3.53, -4.04, -4.18, -3.39, -4.23, -4.03, -4.38, -4.21, -3.86, -4.35, -4.26, -4.37, -4.19, -3.72,
+  -4.02, -3.90, -3.69, -4.17, -4.15, -3.26, -3.49, -3.25, -4.39, -3.87, -3.97, -3.24, -3.92, -3.79,
+  -4.26, -3.92, -4.23, -3.67, -4.03, -3.29, -3.66, -3.42, -4.11, -3.65, -2.76, -4.30, -3.15, -4.35,
+  -2.84, -3.63, -4.31, -3.49, -3.34, -3.70, -4.18, -4.22, -4.39, -4.35, -4.28, -4.05, -4.16, -4.21,
+  -3.62, -3.96, -4.38, -3.28, -2.99, -4.00, -3.91, -3.56, -3.80, -3.19, -4.24, -3.04, -4.23, -3.84,
+  -3.08, -2.36, -3.71, -3.58, -3.86, -4.11, -4.17, -3.09, -3.11, -3.98, -2.57, -3.96, -2.93, -3.54,
+  -4.38, -4.01, -3.35, -3.45, -3.68, -4.08, -3.71, -3.98, -3.53, -3.69, -4.30, -4.15, -4.21, -3.80,
+  -4.35, -4.34, -3.28, -3.51, -3.58, -3.43, -3.46, -2.99, -4.15, -3.99, -4.14, -3.89, -3.27, -4.14,
+  -4.39, -3.55, -4.31, -3.96, -3.34, -3.53, -4.33, -4.27, -3.50, -4.20, -3.59, -3.75, -4.31, -3.58,
+  -3.11, -3.58, -3.38, -3.34, -3.89, -3.79, -4.08, -3.67, -3.87, -3.65, -3.26, -3.24, -4.27, -3.41,
+  -3.78, -3.69, -4.34, -3.15, -3.43, -3.51, -3.76, -2.39, -4.33, -4.09, -3.03, -4.04, -3.25, -3.39,
+  -3.46, -4.10, -3.25, -4.16, -3.30, -3.30, -3.29, -3.75, -3.45, -4.04, -3.92, -3.07, -3.59, -3.27,
+  -4.37, -3.78, -3.73, -3.40, -4.18, -3.64, -3.72, -3.42, -2.80, -3.87, -4.33, -4.05, -4.34, -4.38,
+  -4.05, -3.75, -4.30, -3.45, -3.63, -3.14, -4.28, -3.80, -3.92, -3.80, -3.53, -3.35, -3.13, -4.30,
+  -3.81, -3.17, -2.95, -3.88, -3.38, -4.07, -3.56, -4.21, -3.46, -3.40, -3.79, -4.05, -3.92, -3.87,
+  -3.91, -3.14, -3.76, -3.61, -4.11, -3.98, -4.11, -3.70, -3.87, -3.18, -4.26, -4.27, -3.31, -3.90,
+  -3.39, -3.75, -3.06, -3.80, -3.65, -3.02, -4.26, -3.62, -4.20, -4.23, -3.75, -3.84, -3.50, -4.09,
+  -3.99, -4.37, -4.03, -4.17, -3.25, -4.02, -2.40, -1.80, -2.67, -3.59, -3.18, -3.50, -2.22, -4.33,
+  -3.85, -4.27, -3.11, -4.23, -4.34, -3.67, -3.88, -4.24, -3.50, -2.80, -3.91, -4.00, -4.32, -3.97,
+  -4.08, -3.72, -4.18, -3.65, -2.75, -3.22, -2.73, -3.16, -3.72, -3.54, -4.27, -3.34, -4.03, -3.42,
+  -2.47, -2.93, -4.31, -3.96, -4.10, -3.40, -2.91, -3.10, -4.08, -4.05, -2.98, -2.78, -3.75, -3.23,
+  -3.42, -4.32, -3.51, -4.15, -2.62, -4.19, -4.38, -4.00, -3.09, -3.37, -3.76, -4.28, -3.95, -4.07,
+  -4.33, -4.27, -4.05, -4.17, -4.08, -4.28, -4.12, -4.21, -3.92, -3.85, -4.07, -4.14, -3.86, -3.80,
+  -4.30, -4.19, -4.06, -4.30, -4.27, -4.39, -4.39, -4.16, -3.44, -4.01, -4.11, -4.30, -3.05, -3.54,
+  -3.84, -3.42, -4.23, -4.30, -4.28, -4.27, -3.97, -3.76, -4.20, -3.65, -3.97, -4.02, -4.33, -4.38,
+  -4.07, -4.07, -4.16, -3.79, -3.45, -4.35, -4.01, -3.02, -4.38, -4.31, -2.88, -3.19, -4.13, -3.39,
+  -4.34, -4.02, -4.27, -4.13, -3.88, -4.30, -3.81, -4.02, -4.27, -4.37, -4.13, -4.39, -4.01, -3.60,
+  -3.92, -3.86, -3.43, -3.26, -3.90, -3.64, -3.77, -4.39, -4.20, -3.61, -4.06, -4.07, -3.81, -4.05,
+  -2.71, -3.75, -4.32, -4.23, -3.10, -3.57, -4.16, -3.72, -3.77, -4.19, -4.31, -3.94, -4.38, -4.08,
+  -4.24, -4.32, -4.23, -4.39, -4.23, -3.43, -3.97, -4.08, -4.38, -4.27, -4.31, -4.11, -4.13, -4.32,
+  -4.19, -3.66, -4.38, -4.17, -4.14, -4.23, -4.09, -3.62, -4.32, -4.01, -4.01, -3.86, -4.35, -3.96,
+  -4.17, -4.10, -3.66, -4.21, -3.68, -4.11, -3.90, -4.23, -3.52, -4.28, -3.92, -4.03, -4.30, -3.53,
+  -4.21, -4.02, -4.38, -3.98, -4.33, -3.83, -3.39, -4.06, -4.22, -4.06, -2.97, -3.52, -4.31, -3.74,
+  -3.68, -4.20, -3.37, -3.86, -4.04, -4.18, -3.56, -4.03, -4.22, -3.92, -4.28, -4.13, -3.79, -4.27,
+  -4.00, -3.99, -4.12, -3.64, -4.21, -3.85, -4.24, -4.32, -4.23, -3.83, -4.33, -4.11, -3.84, -4.32,
+  -3.71, -3.88, -3.48, -4.28, -3.59, -4.20, -4.17, -4.01, -3.57, -3.82, -4.08, -3.80, -3.34, -4.30,
+  -4.23, -3.88, -3.32, -3.68, -4.07, -4.23, -4.30, -4.34, -4.32, -4.17, -4.33, -3.69, -3.93, -4.18,
+  -3.03, -3.54, -3.78, -3.57, -3.92, -3.36, -4.28, -4.35, -3.39, -2.77, -4.06, -4.37, -4.18, -4.20,
+  -4.31, -4.35, -3.16, -4.08, -3.72, -4.21, -3.43, -4.23, -3.90, -3.32, -4.28, -3.37, -3.72, -3.75,
+  -4.11, -3.37, -4.24, -4.13, -3.40, -4.18, -3.84, -4.11, -4.08, -3.87, -3.72, -3.58, -4.22, -4.27,
+  -3.86, -4.17, -3.38, -2.45, -3.69, -3.68, -4.38, -3.44, -4.32, -3.69, -3.93, -4.16, -4.10, -3.72,
+  -3.67, -3.82, -3.21, -4.08, -4.01, -3.21, -4.23, -4.22, -3.71, -4.06, -4.15, -3.28, -4.35, -3.83,
+  -4.24, -3.43, -4.23, -3.88, -4.14, -3.59, -3.02, -4.17, -3.85, -4.13, -3.63, -3.67, -3.19, -4.38,
+  -4.30, -4.26, -4.15, -4.37, -4.39, -4.35, -4.17, -3.48, -3.82, -3.85, -3.48, -4.26, -3.33, -4.26,
+  -4.24, -4.26, -3.84, -4.38, -4.23, -4.18, -4.27, -3.70, -3.50, -3.61, -4.24, -3.79, -4.15, -4.35,
+  -3.95, -4.23, -4.27, -4.38, -4.24, -3.98, -3.93, -3.84, -3.96, -3.92, -3.95, -3.74, -3.48, -4.21,
+  -3.45, -3.87, -3.98, -4.13, -3.62, -3.31, -4.15, -3.79, -4.27, -4.02, -4.03, -3.67, -3.74, -3.02,
+  -4.11, -4.26, -3.24, -3.74, -4.05, -3.41, -4.37, -3.47, -2.95, -4.23, -4.03, -3.36, -4.06, -3.98,
+  -4.16, -3.41, -4.25, -3.73, -3.92, -3.61, -2.93, -4.25, -3.49, -3.29, -4.35, -3.61, -3.88, -3.14,
+  -4.21, -4.17, -3.05, -4.34, -4.08, -3.80, -4.39, -4.15, -4.38, -3.77, -4.27, -4.35, -3.76, -4.30,
+  -4.02, -3.79, -4.08, -4.23, -4.13, -4.23, -4.37, -4.34, -4.28, -4.09, -4.31, -4.39, -3.19, -4.38,
+  -4.11, -3.77, -3.75, -4.08, -3.62, -4.31, -3.81, -3.83, -3.98, -4.31, -4.28, -3.75, -3.02, -4.21,
+  -4.05, -3.90, -3.78, -4.05, -4.27, -3.63, -3.89, -4.23, -3.82, -3.83, -4.08, -3.52, -4.19, -3.51,
+  -4.15, -4.26, -4.28, -3.86, -3.20, -4.30, -4.20, -4.20, -4.20, -4.35, -4.38, -4.16, -3.83, -4.07,
+  -4.27, -4.37, -4.35, -4.02, -4.31, -3.94, -4.33, -2.95, -2.85, -3.86, -4.35, -3.63, -3.48, -3.79,
+  -3.90, -3.15, -4.37, -3.70, -3.93, -4.23, -3.97, -3.81, -3.67, -3.48, -4.17, -3.97, -3.82, -4.09,
+  -4.35, -3.98, -3.79, -3.40, -4.30, -4.23, -3.50, -3.57, -4.10, -4.23, -4.20, -3.64, -3.99, -3.84,
+  -4.31, -4.17, -3.76, -3.89, -3.38, -3.30, -4.23, -3.64, -4.07, -4.06, -4.35, -4.26, -4.18, -3.92,
+  -4.28, -3.81, -3.72, -4.23, -4.22, -4.37, -4.27, -3.99, -4.02, -4.33, -4.25, -4.04, -4.18, -4.11,
+  -4.07, -3.89, -3.99, -3.49, -3.88, -2.80, -4.08, -4.28, -4.39, -4.01, -3.47, -3.02, -3.50, -3.65,
+  -4.21, -4.17, -3.60, -3.28, -3.88, -3.57, -4.35, -4.01, -3.67, -3.90, -3.81, -3.32, -3.26, -4.07,
+  -3.89, -4.01, -4.23, -4.17, -4.37, -3.94, -4.17, -4.03, -4.14, -4.30, -4.24, -2.73, -4.34, -3.79,
+  -3.46, -3.68, -4.35, -4.13, -3.79, -3.94, -3.90, -4.33, -3.69, -4.38, -3.69, -4.17, -4.25, -4.00,
+  -3.96, -3.48, -4.13, -3.53, -4.05, -3.86, -4.23, -3.59, -3.67, -3.92, -4.19, -3.78, -4.04, -4.38,
+  -4.16, -4.22, -4.24, -4.26, -3.84, -4.17, -4.13, -4.27, -3.83, -4.11, -4.37, -3.19, -3.91, -3.84,
+  -3.53, -4.18, -3.99, -4.16, -4.08, -4.33, -4.24, -3.50, -3.47, -4.09, -4.02, -4.14, -3.92, -3.57,
+  -3.92, -4.17, -4.38, -4.01, -3.95, -4.37, -2.89, -3.06, -4.21, -4.08, -4.30, -3.89, -3.86, -3.88,
+  -3.86, -3.54, -4.34, -4.21, -4.15, -4.23, -3.98, -4.11, -3.95, -4.11, -3.69, -3.33, -4.17, -4.11,
+  -4.15, -4.23, -4.13, -4.18, -4.24, -3.75, -4.06, -4.28, -3.47, -4.35, -4.25, -4.21, -3.41, -4.23,
+  -3.73, -3.83, -3.82, -4.31, -3.92, -3.63, -3.89, -3.18, -4.38, -4.15, -4.18, -3.82, -3.77, -4.02,
+  -4.01, -3.95, -4.39, -4.21, -3.90, -4.34, -4.32, -4.08, -4.01, -4.22, -4.28, -3.39, -4.37, -3.37,
+  -3.65, -3.66, -4.35, -3.78, -3.94, -4.23, -3.90, -4.08, -3.90, -3.36, -4.26, -3.60, -4.31, -3.99,
+  -4.24, -4.17, -3.83, -4.18, -3.82, -3.75, -3.98, -4.03, -3.90, -3.51, -4.33, -4.33, -4.13, -3.83,
+  -3.77, -3.00, -3.81, -4.08, -3.83, -3.62, -2.98, -4.08, -3.48, -3.50, -3.92, -4.32, -3.53, -4.33,
+  -4.05, -3.60, -4.12, -4.06, -3.88, -2.92, -3.64, -3.64, -2.76, -3.49, -4.08, -3.77, -3.31, -3.51,
+  -3.51, -4.17, -4.18, -3.56, -3.73, -3.51, -4.23, -3.26, -3.33, -3.83, -3.08, -3.72, -4.20, -4.35,
+  -3.47, -3.87, -3.96, -4.14, -3.65, -2.84, -3.14, -3.33, -3.77, -4.34, -3.84, -4.07, -3.40, -3.64,
+  -3.39, -3.21, -3.99, -3.43, -3.79, -3.59, -3.41, -3.75, -3.91, -2.98, -3.54, -3.73, -3.81, -3.97,
+  -3.64, -4.04, -3.12, -4.20, -4.21, -3.86, -3.20, -4.16, -4.38, -4.05, -3.00, -4.27, -3.46, -3.33,
+  -4.02, -3.45, -3.97, -3.75, -4.26, -3.74, -3.77, -3.09, -3.91, -3.85, -4.08, -3.51, -2.49, -3.79,
+  -2.90, -3.28, -4.28, -3.88, -3.24, -3.97, -4.23, -3.24, -3.01, -3.79, -3.36, -3.36, -3.53, -3.29,
+  -3.57, -4.16, -3.62, -3.19, -3.62, -3.63, -3.54, -3.64, -3.95, -3.82, -4.25, -4.38, -3.94, -3.65,
+  -3.41, -4.11, -4.01, -4.13, -4.09, -4.20, -3.68, -4.02, -4.22, -4.24, -3.43, -3.63, -4.38, -4.18,
+  -4.11, -3.38, -3.32, -4.00, -4.13, -4.30, -3.70, -4.03, -4.23, -4.28, -4.16, -3.79, -4.24, -4.35,
+  -4.23, -4.15, -4.03, -4.24, -4.11, -3.98, -3.32, -4.04, -3.94, -4.25, -3.19, -3.98, -4.28, -4.35,
+  -4.23, -4.38, -3.46, -3.99, -4.37, -4.38, -3.92, -4.33, -3.86, -3.56, -4.31, -4.33, -3.43, -3.96,
+  -3.50, -3.83, -3.91, -4.33, -3.34, -3.56, -4.31, -4.27, -4.31, -3.70, -3.28, -4.38, -3.64, -4.16,
+  -4.28, -3.92, -2.95, -4.23, -4.14, -3.60, -3.78, -3.64, -4.03, -4.35, -4.37, -3.83, -4.13, -3.34,
+  -3.96, -3.55, -4.07, -3.89, -3.12, -3.18, -3.66, -3.79, -4.39, -4.17, -4.35, -3.75, -4.03, -3.81,
+  -4.17, -4.04, -3.97, -3.97, -4.03, -3.87, -3.96, -4.37, -4.32, -4.31, -4.33, -3.74, -4.31, -4.31,
+  -4.03, -4.28, -4.27, -4.06, -4.31, -4.32, -2.99, -4.37, -3.77, -3.96, -3.83, -3.72, -4.05, -4.34,
+  -3.88, -4.34, -4.26, -4.17, -3.43, -4.15, -3.50, -3.79, -4.00, -3.72, -4.35, -4.05, -3.10, -4.24,
+  -4.31, -3.99, -3.68, -3.93, -3.94, -4.17, -3.48, -4.30, -4.34, -4.19, -4.37, -4.32, -4.02, -2.93,
+  -4.05, -4.03, -3.41, -3.71, -3.55, -4.08, -3.34, -3.69, -4.17, -4.37, -4.01, -3.82, -4.16, -4.07,
+  -4.31, -4.28, -3.64, -3.86, -3.75, -4.34, -3.57, -4.15, -3.70, -4.13, -3.94, -3.92, -4.38, -4.22,
+  -3.50, -3.96, -4.15, -4.26, -3.61, -3.94, -4.06, -3.87, -3.01, -4.17, -3.57, -3.38, -3.59, -3.87,
+  -3.20, -3.20, -3.86, -4.03, -4.16, -3.90, -2.98, -3.98, -3.65, -4.08, -3.62, -4.04, -3.94, -4.39,
+  -3.26, -3.33, -4.05, -4.08, -3.68, -3.77, -4.24, -4.26, -4.34, -3.98, -3.69, -4.39, -3.09, -4.04,
+  -3.91, -4.07, -4.32, -3.83, -4.23, -3.35, -4.28, -4.15, -4.01, -3.35, -4.32, -4.24, -3.97, -3.04,
+  -4.04, -3.53, -4.17, -3.30, -3.31, -4.01, -4.39, -3.54, -4.06, -4.25, -3.55, -3.92, -3.45, -3.53,
+  -4.27, -3.51, -3.33, -4.37, -4.11, -4.23, -3.55, -3.72, -3.83, -4.20, -3.84, -4.38, -3.41, -3.89,
+  -4.10, -3.38, -3.78, -4.13, -3.12, -3.53, -3.80, -4.34, -4.25, -3.14, -3.81, -4.26, -3.92, -3.69,
+  -3.32, -4.13, -4.26, -4.17, -3.30, -3.91, -4.17, -4.30, -4.01, -3.76, -4.33, -3.57, -3.72, -4.13,
+  -4.22, -4.21, -4.34, -4.21, -3.90, -4.07, -4.14, -3.56, -3.88, -3.86, -4.23, -4.27, -3.88, -4.17,
+  -4.32, -4.05, -4.23, -2.74, -4.07, -4.25, -3.65, -4.27, -3.54, -4.30, -3.83, -3.88, -3.94, -4.20,
+  -4.06, -2.98, -4.39, -3.59, -4.32, -4.34, -4.31, -3.40, -4.32, -4.39, -4.03, -3.67, -3.92, -3.29,
+  -4.26, -4.10, -3.39, -3.46, -3.42, -4.23, -3.65, -2.80, -3.63, -3.55, -3.16, -4.09, -3.71, -3.71,
+  -4.16, -2.84, -3.63, -4.01, -4.23, -4.33, -4.25, -4.22, -4.30, -4.11, -4.35, -4.30, -4.39, -4.33,
+  -4.11, -4.32, -4.26, -4.34, -4.34, -3.40, -3.75, -4.10, -3.81, -4.21, -3.72, -4.11, -4.02, -4.17,
+  -4.17, -4.33, -3.91, -4.28, -4.23, -4.24, -4.10, -4.28, -4.23, -3.01, -3.51, -4.37, -4.23, -4.00,
+  -4.32, -4.39, -3.85, -4.30, -4.21, -4.24, -4.12, -3.82, -4.17, -4.17, -3.48, -3.99, -3.63, -4.30,
+  -4.39, -3.43, -4.33, -4.23, -4.19, -3.46, -4.28, -4.20, -3.72, -4.07, -3.89, -4.19, -4.20, -4.22,
+  -4.39, -3.16, -4.30, -4.39, -4.39, -3.69, -4.05, -4.13, -3.68, -4.03, -4.33, -4.30, -3.69, -3.75,
+  -4.16, -3.80, -4.37, -4.32, -4.35, -4.00, -3.81, -4.20, -4.16, -4.13, -3.98, -4.25, -4.26, -4.31,
+  -3.21, -4.23, -4.27, -4.08, -4.37, -4.23, -3.46, -3.57, -3.49, -4.15, -3.95, -3.30, -4.24, -4.14,
+  -3.35, -4.21, -2.41, -4.05, -3.02, -3.63, -3.99, -4.38, -3.27, -3.38, -2.78, -3.37, -3.27, -2.87,
+  -3.84, -3.78, -3.27, -3.78, -3.26, -3.05, -3.45, -3.34, -3.06, -3.14, -2.79, -2.91, -3.85, -3.07,
+  -3.75, -4.11, -3.64, -4.26, -3.81, -4.27, -4.00, -3.77, -4.39, -2.95, -3.83, -2.98, -4.37, -3.04,
+  -4.03, -3.50, -3.93, -3.60, -4.15, -3.14, -4.11, -3.86, -3.48, -4.27, -3.76, -2.72, -4.01, -3.33,
+  -4.32, -4.39, -3.74, -3.72, -4.26, -4.20, -3.66, -3.91, -3.62, -3.77, -3.28, -3.79, -3.75, -3.75,
+  -3.30, -4.16, -3.97, -4.26, -4.38, -3.34, -4.11, -4.31, -3.72, -3.71, -4.30, -4.21, -3.75, -3.51,
+  -4.27, -4.23, -4.27, -3.90, -3.84, -3.71, -3.76, -4.18, -3.14, -4.02, -4.13, -3.91, -3.50, -4.26,
+  -4.32, -4.13, -4.05, -4.28, -3.89, -3.67, -3.92, -4.11, -3.78, -4.33, -4.20, -3.58, -3.67, -3.63,
+  -3.59, -3.41, -4.08, -3.88, -3.70, -4.23, -3.43, -4.23, -3.84, -4.19, -3.92, -3.43, -4.34, -4.17,
+  -3.78, -4.05, -3.31, -4.20, -4.12, -4.25, -4.07, -3.56, -3.83, -3.81, -2.92, -4.23, -3.41, -4.13,
+  -3.96, -3.74, -3.78, -4.23, -3.95, -3.10, -4.08, -4.38, -4.20, -4.37, -3.23, -4.33, -3.45, -4.28,
+  -4.26, -4.08, -4.07, -3.23, -3.84, -3.72, -4.14, -3.83, -3.90, -2.74, -3.88, -4.01, -4.30, -4.39,
+  -3.91, -3.96, -4.01, -4.26, -3.89, -4.38, -3.79, -3.84, -3.84, -4.07, -3.33, -3.64, -3.90, -4.07,
+  -2.25, -3.90, -3.79, -4.26, -4.10, -4.02, -3.87, -4.15, -3.55, -4.28, -4.08, -4.17, -3.86, -3.87,
+  -3.17, -3.67, -3.23, -4.22, -3.39, -4.26, -3.95, -4.31, -4.25, -2.62, -3.52, -3.48, -4.30, -3.67,
+  -3.07, -3.88, -4.17, -4.39, -4.06, -3.71, -4.24, -4.17, -3.66, -4.17, -3.42, -4.21, -3.21, -3.93,
+  -3.18, -3.86, -3.73, -3.78, -4.38, -4.35, -3.43, -4.05, -3.84, -3.79, -3.90, -3.68, -3.89, -3.54,
+  -3.22, -4.02, -4.17, -3.72, -4.15, -3.98, -3.69, -4.35, -4.23, -3.96, -3.94, -3.03, -4.31, -3.82,
+  -4.17, -3.95, -3.93, -4.19, -4.21, -3.70, -4.11, -4.32, -3.81, -4.19, -4.25, -4.15, -3.69, -3.65,
+  -3.53, -4.02, -3.76, -3.64, -3.69, -3.53, -2.86, -4.17, -3.86, -3.83, -3.92, -3.39, -4.24, -4.22,
+  -3.35, -4.18, -4.01, -3.58, -4.23, -3.53, -4.17, -4.31, -4.12, -4.07, -3.40, -3.68, -4.16, -3.82,
+  -4.34, -4.16, -4.31, -4.08, -3.98, -4.30, -4.33, -4.04, -3.74, -4.30, -4.31, -4.12, -3.58, -4.17,
+  -4.07, -3.69, -4.23, -4.34, -3.90, -3.98, -3.56, -3.51, -4.33, -4.34, -4.39, -4.28, -4.18, -4.17,
+  -4.32, -3.60, -4.23, -3.75, -4.38, -4.25, -4.11, -4.13, -4.15, -4.32, -4.06, -4.10, -3.86, -4.32,
+  -4.27, -4.08, -4.22, -4.21, -4.09, -4.15, -4.34, -3.89, -3.90, -4.25, -4.03, -4.12, -4.23, -3.39,
+  -3.76, -4.10, -4.17, -4.31, -4.33, -4.33, -4.17, -3.77, -4.33, -3.68, -3.54, -4.12, -4.30, -4.14,
+  -3.76, -4.00, -4.03, -3.80, -4.19, -4.18, -4.33, -4.32, -4.26, -4.30, -4.39, -4.34, -4.25, -4.09,
+  -4.32, -4.22, -4.14, -3.84, -3.74, -3.92, -4.07, -4.39, -3.94, -3.67, -4.08, -3.82, -3.92, -3.81,
+  -3.95, -3.26, -4.17, -3.79, -3.40, -3.61, -4.27, -4.12, -3.87, -4.28, -4.08, -3.56, -4.38, -3.07,
+  -4.39, -4.07, -4.26, -3.57, -3.35, -4.07, -4.14, -4.09, -4.27, -3.41, -4.28, -4.27, -4.13, -4.30,
+  -3.51, -4.17, -4.15, -3.70, -4.02, -3.85, -3.58, -3.38, -4.27, -3.47, -3.48, -4.23, -2.99, -4.28,
+  -3.62, -4.35, -3.63, -3.55, -3.64, -3.89, -3.98, -4.05, -3.96, -4.03, -3.88, -4.05, -4.23, -3.91,
+  -3.76, -3.80, -3.84, -3.75, -3.29, -4.11, -4.06, -4.28, -4.13, -4.11, -3.63, -3.73, -4.23, -3.36,
+  -3.81, -3.44, -4.10, -4.27, -3.93, -3.52, -4.17, -3.83, -3.47, -3.71, -4.23, -3.54, -4.27, -3.83,
+  -3.91, -3.81, -4.10, -4.28, -4.38, -4.02, -4.34, -3.39, -4.30, -4.31, -4.38, -3.71, -4.27, -3.96,
+  -3.03, -3.94, -3.84, -3.28, -4.06, -3.87, -3.96, -4.02, -4.15, -4.35, -4.34, -4.35, -3.88, -4.31,
+  -3.98, -4.21, -4.05, -3.81, -4.17, -3.93, -3.67, -3.86, -4.24, -4.01, -3.94, -4.19, -3.45, -4.02,
+  -4.14, -4.33, -3.59, -4.31, -3.56, -3.39, -4.26, -4.17, -4.19, -4.33, -3.90, -3.72, -4.19, -4.13,
+  -3.54, -3.75, -4.38, -4.21, -3.48, -4.39, -3.78, -3.98, -4.23, -4.15, -3.98, -4.37, -3.72, -3.99,
+  -4.38, -4.23, -3.72, -3.90, -3.94, -3.83, -4.04, -3.60, -3.68, -3.77, -2.80, -4.37, -3.64, -3.57,
+  -3.43, -3.08, -4.38, -3.58, -3.78, -4.24, -4.28, -3.73, -4.37, -3.91, -4.20, -4.26, -3.90, -3.98,
+  -3.95, -4.21, -3.51, -4.12, -4.37, -3.32, -3.77, -3.84, -3.77, -4.11, -3.20, -2.87, -4.24, -4.13,
+  -4.26, -4.35, -4.34, -4.37, -4.31, -4.24, -4.08, -3.65, -3.87, -3.51, -3.86, -3.90, -3.18, -4.23,
+  -4.23, -4.13, -3.87, -3.40, -4.01, -3.80, -4.25, -3.70, -4.31, -4.28, -3.75, -3.48, -3.71, -4.28,
+  -3.84, -4.31, -4.15, -4.13, -4.04, -3.93, -3.64, -4.37, -4.23, -3.86, -4.22, -4.34, -4.39, -3.99,
+  -4.39, -4.30, -3.95, -3.16, -3.58, -3.63, -3.22, -3.86, -4.20, -4.04, -4.39, -3.50, -3.77, -4.38,
+  -4.21, -4.07, -4.06, -4.02, -4.01, -3.84, -3.69, -4.07, -4.13, -4.17, -4.03, -4.22, -4.09, -2.53,
+  -3.50, -4.05, -4.26, -3.49, -3.83, -3.50, -3.70, -3.69, -4.35, -3.50, -2.93, -4.06, -4.34, -4.27,
+  -4.27, -3.68, -4.35, -4.07, -3.86, -4.13, -3.88, -4.26, -4.25, -3.72, -3.97, -4.15, -3.20, -4.33,
+  -4.01, -4.17, -3.89, -4.32, -3.12, -4.16, -4.05, -4.01, -3.49, -3.89, -3.97, -2.84, -3.93, -4.13,
+  -4.23, -4.24, -3.41, -4.05, -3.62, -4.01, -3.89, -4.04, -4.34, -3.87, -3.57, -3.83, -3.81, -3.59,
+  -3.88, -3.61, -3.71, -3.05, -4.03, -4.28, -3.60, -4.39, -4.18, -4.32, -3.68, -4.11, -3.84, -3.93,
+  -4.00, -3.86, -4.07, -3.80, -4.24, -3.69, -3.82, -4.33, -4.39, -4.30, -4.17, -4.31, -3.88, -4.19,
+  -4.28, -3.76, -4.08, -4.23, -4.24, -4.17, -3.31, -3.24, -4.39, -4.00, -3.88, -3.69, -3.66, -3.95,
+  -4.13, -3.48, -3.78, -3.92, -4.33, -4.25, -3.34, -3.86, -4.26, -4.34, -4.34, -3.10, -4.09, -3.68,
+  -4.11, -3.90, -3.85, -4.24, -3.44, -3.83, -4.02, -3.52, -4.02, -3.92, -3.55, -2.86, -4.07, -4.31,
+  -3.69, -4.31, -3.88, -3.78, -4.26, -3.66, -4.21, -4.12, -4.39, -3.99, -4.21, -4.30, -3.69, -4.21,
+  -4.34, -3.31, -4.25, -4.18, -3.59, -3.38, -4.11, -4.16, -4.15, -4.05, -3.84, -3.75, -4.38, -4.26,
+  -3.34, -3.80, -3.77, -3.59, -4.34, -3.33, -4.22, -4.25, -4.39, -4.17, -3.48, -4.38, -4.32, -3.81,
+  -3.95, -3.57, -3.95, -3.91, -3.81, -4.07, -4.24, -3.66, -3.40, -3.55, -3.96, -4.01, -3.75, -4.27,
+  -4.21, -4.39, -4.03, -3.80, -4.28, -3.71, -4.11, -4.18, -3.11, -4.17, -4.13, -3.80, -3.83, -3.61,
+  -3.44, -3.82, -3.69, -4.23, -3.95, -3.89, -3.32, -4.38, -4.08, -3.82, -3.63, -4.12, -4.26, -4.05,
+  -3.39, -3.93, -3.26, -4.37, -4.25, -3.94, -3.53, -4.24, -4.32, -4.14, -4.30, -4.17, -4.24, -3.96,
+  -4.30, -4.08, -4.37, -4.24, -4.13, -4.08, -3.55, -4.16, -4.11, -4.21, -3.74, -4.34, -3.67, -4.35,
+  -3.81, -3.88, -3.71, -3.86, -3.75, -4.23, -3.91, -4.34, -4.19, -4.02, -4.23, -3.95, -4.27, -3.94,
+  -3.70, -4.30, -3.99, -3.77, -4.32, -3.92, -4.21, -4.33, -4.09, -3.60, -4.11, -4.38, -4.33, -4.33,
+  -4.19, -4.07, -3.91, -3.53, -3.91, -4.20, -3.83, -4.30, -4.18, -4.11, -3.83, -4.20, -4.23, -2.90,
+  -4.22, -4.32, -4.34, -3.41, -4.25, -4.30, -3.95, -4.21, -4.14, -4.02, -4.19, -4.34, -4.20, -3.75,
+  -3.90, -4.02, -4.35, -4.07, -3.92, -3.79, -4.22, -3.90, -4.33, -4.01, -4.09, -4.39, -4.28, -4.37,
+  -2.89, -3.88, -3.86, -4.21, -3.82, -3.80, -3.51, -3.99, -3.71, -3.55, -4.07, -4.14, -3.36, -4.33,
+  -3.65, -4.07, -4.08, -4.23, -4.21, -3.72, -4.08, -3.92, -4.13, -3.89, -3.73, -2.76, -4.38, -4.21,
+  -3.96, -4.27, -4.09, -4.33, -4.17, -3.16, -4.05, -3.39, -3.68, -3.80, -4.34, -4.17, -4.02, -4.16,
+  -4.07, -3.65, -4.22, -4.30, -4.07, -4.39, -3.49, -4.08, -4.39, -3.39, -4.38, -3.95, -4.26, -3.87,
+  -4.20, -3.98, -3.97, -3.20, -3.89, -4.35, -3.46, -4.03, -3.85, -4.02, -4.14, -3.76, -4.24, -3.32,
+  -4.20, -4.24, -3.42, -4.05, -4.17, -3.92, -3.09, -3.57, -4.34, -3.38, -3.48, -3.43, -4.26, -3.67,
+  -3.69, -4.19, -3.94, -3.86, -3.98, -3.20, -4.38, -4.39, -4.02, -4.23, -3.38, -4.17, -4.17, -3.19,
+  -4.08, -3.95, -4.22, -3.91, -3.88, -3.64, -3.87, -4.21, -4.00, -3.89, -4.38, -3.57, -4.17, -4.05,
+  -3.95, -4.35, -3.84, -4.32, -4.23, -4.30, -3.48, -4.37, -4.33, -4.11, -4.38, -3.46, -4.15, -3.94,
+  -4.23, -3.52, -4.25, -4.39, -3.69, -3.66, -4.00, -3.78, -4.19, -4.10, -3.91, -3.25, -4.06, -3.90,
+  -4.32, -4.05, -3.81, -4.28, -3.99, -4.17, -4.19, -3.08, -4.26, -4.17, -4.30, -3.71, -4.23, -3.83,
+  -4.18, -3.72, -4.02, -3.82, -3.78, -4.13, -4.22, -3.68, -4.31, -3.53, -3.82, -3.72, -3.54, -4.22,
+  -4.39, -4.39, -4.21, -4.22, -3.79, -3.87, -4.37, -3.84, -3.14, -4.39, -4.23, -3.40, -4.18, -4.00,
+  -3.92, -4.32, -4.27, -4.05, -3.97, -3.76, -3.79, -3.90, -4.13, -3.53, -3.08, -3.75, -3.79, -2.97,
+  -4.27, -4.03, -3.16, -3.69, -3.93, -4.23, -3.47, -3.68, -4.09, -4.11, -4.27, -4.16, -4.34, -4.27,
+  -4.39, -3.34, -3.90, -4.39, -4.17, -3.98, -4.22, -3.79, -3.61, -3.42, -3.62, -3.51, -4.27, -4.18,
+  -3.91, -4.06, -4.37, -3.92, -4.04, -3.94, -3.30, -3.83, -3.47, -3.59, -3.93, -3.37, -4.21, -4.18,
+  -3.91, -3.84, -3.83, -4.14, -4.24, -3.87, -4.10, -3.85, -3.73, -3.40, -3.96, -4.22, -4.22, -3.72,
+  -4.11, -4.37, -3.53, -3.93, -4.38, -3.49, -4.38, -4.25, -3.22, -4.17, -3.74, -4.33, -4.28, -3.31,
+  -3.62, -3.14, -4.02, -4.28, -4.33, -3.78, -4.21, -4.39, -3.39, -4.08, -4.18, -3.82, -4.04, -3.82,
+  -4.09, -4.17, -3.87, -4.27, -4.34, -4.35, -4.15, -3.43, -4.08, -3.56, -4.17, -3.85, -3.87, -3.98,
+  -3.15, -3.68, -4.21, -3.59, -3.28, -4.07, -4.26, -4.35, -4.27, -3.85, -4.33, -3.59, -4.38, -4.22,
+  -4.37, -4.33, -3.70, -3.89, -4.21, -3.72, -4.28, -3.66, -4.17, -4.08, -3.93, -3.43, -4.08, -4.25,
+  -4.31, -4.33, -4.33, -4.24, -3.59, -4.17, -4.05, -4.09, -3.52, -3.84, -4.15, -4.35, -4.09, -4.38,
+  -3.88, -4.12, -4.23, -4.03, -3.64, -4.28, -4.34, -4.25, -4.08, -3.61, -3.80, -4.28, -4.30, -4.38,
+  -4.39, -4.38, -4.11, -4.14, -2.97, -3.38, -3.84, -3.95, -3.92, -4.35, -4.08, -4.06, -3.88, -4.07,
+  -4.14, -4.05, -4.37, -4.39, -4.02, -3.94, -4.24, -3.92, -4.22, -4.35, -3.95, -3.69, -3.19, -4.01,
+  -4.20, -3.49, -4.37, -3.42, -3.73, -4.17, -4.23, -4.19, -4.09, -3.51, -3.12, -3.74, -3.46, -3.51,
+  -4.24, -4.31, -3.94, -4.21, -3.79, -4.11, -4.37, -3.34, -4.08, -3.59, -3.79, -4.03, -4.08, -4.26,
+  -4.31, -3.41, -4.32, -3.92, -4.01, -3.77, -3.96, -3.89, -3.75, -3.22, -3.94, -4.30, -3.65, -4.23,
+  -3.84, -3.68, -3.93, -3.13, -4.26, -4.14, -3.84, -3.23, -4.20, -3.92, -4.39, -3.51, -3.60, -3.95,
+  -3.12, -4.28, -4.26, -3.96, -4.32, -4.33, -4.11, -4.39, -4.26, -3.09, -3.28, -4.11, -3.53, -3.45,
+  -4.32, -4.01, -3.86, -3.45, -3.95, -3.71, -4.17, -3.14, -4.12, -4.23, -3.98, -3.92, -4.05, -3.68,
+  -3.34, -3.72, -4.37, -4.27, -3.79, -4.28, -4.19, -4.23, -3.25, -3.57, -3.67, -4.10, -4.31, -4.13,
+  -4.37, -4.20, -4.23, -4.28, -4.04, -4.31, -3.90, -4.07, -4.27, -3.84, -4.11, -3.77, -4.23, -4.30,
+  -4.18, -3.87, -4.03, -3.52, -3.90, -3.87, -3.80, -3.15, -3.68, -4.27, -3.17, -4.17, -4.35, -4.35,
+  -3.55, -4.26, -4.35, -3.94, -4.26, -3.88, -4.23, -4.39, -3.67, -4.08, -4.24, -4.08, -4.34, -4.20,
+  -4.13, -3.69, -3.26, -3.81, -3.81, -4.35, -4.10, -3.96, -4.32, -3.87, -4.16, -3.81, -3.84, -3.70,
+  -3.77, -3.53, -4.19, -4.25, -3.83, -4.13, -4.20, -4.23, -3.98, -4.14, -3.81, -3.22, -3.81, -3.50,
+  -3.88, -4.23, -3.83, -2.94, -4.06, -4.01, -3.87, -4.11, -4.39, -4.26, -4.12, -3.73, -4.13, -3.96,
+  -4.32, -4.39, -3.87, -3.99, -2.94, -4.24, -4.38, -4.38, -3.74, -2.99, -3.32, -3.75, -4.01, -3.54,
+  -4.19, -4.38, -4.12, -3.46, -3.77, -3.21, -4.31, -3.70, -3.84, -3.83, -4.37, -4.23, -4.33, -3.74,
+  -3.83, -3.54, -3.83, -3.93, -4.25, -3.87, -4.02, -4.26, -3.94, -2.97, -3.47, -3.75, -4.37, -3.71,
+  -3.83, -3.86, -3.89, -3.90, -4.14, -4.35, -4.14, -3.51, -3.14, -3.48, -3.96, -3.88, -4.09, -3.81,
+  -4.03, -3.68, -3.62, -3.90, -4.28, -4.01, -3.72, -4.01, -3.93, -4.39, -3.78, -4.15, -4.22, -2.63,
+  -3.04, -4.06, -4.34, -4.22, -4.17, -4.34, -3.55, -3.95, -4.37, -4.30, -3.41, -3.56, -3.41, -3.88,
+  -4.38, -3.64, -4.38, -3.53, -4.00, -4.10, -4.26, -3.92, -3.72, -3.61, -4.07, -4.01, -4.37, -4.31,
+  -4.14, -4.28, -3.76, -4.11, -4.33, -3.48, -3.76, -4.02, -4.39, -3.64, -3.55, -3.38, -4.27, -3.57,
+  -4.28, -3.69, -3.52, -4.30, -3.88, -4.27, -4.14, -3.49, -4.20, -3.84, -4.28, -4.08, -4.15, -3.08,
+  -3.69, -4.39, -3.87, -4.03, -4.23, -3.86, -3.71, -3.32, -3.50, -4.28, -3.85, -4.35, -3.96, -4.35,
+  -3.93, -4.02, -4.34, -3.92, -4.26, -4.17, -3.93, -3.54, -4.23, -3.93, -4.33, -4.07, -4.33, -3.64,
+  -4.20, -3.62, -3.38, -4.35, -3.78, -3.57, -4.28, -3.53, -3.78, -4.18, -3.05, -3.34, -4.37, -4.01,
+  -4.26, -4.11, -3.73, -4.22, -4.13, -3.40, -4.37, -4.39, -4.18, -4.28, -4.02, -3.52, -4.35, -3.91,
+  -4.21, -3.92, -4.23, -3.30, -4.05, -4.27, -3.91, -4.21, -3.35, -3.92, -3.76, -4.17, -4.14, -4.24,
+  -3.75, -3.58, -3.77, -4.33, -4.16, -3.80, -4.26, -3.88, -3.61, -3.92, -3.55, -4.13, -3.57, -3.96,
+  -4.12, -3.88, -4.09, -3.76, -3.98, -4.35, -3.80, -3.75, -4.14, -3.98, -3.59, -3.83, -3.86, -3.68,
+  -3.95, -4.11, -4.23, -3.21, -3.65, -3.46, -3.55, -4.24, -3.78, -3.80, -3.02, -4.17, -4.31, -3.72,
+  -3.79, -4.11, -3.45, -3.22, -3.60, -4.35, -4.08, -3.79, -3.71, -4.39, -4.25, -3.36, -3.70, -3.81,
+  -3.78, -4.39, -4.26, -3.42, -4.28, -3.70, -3.42, -3.82, -3.79, -4.32, -3.53, -4.11, -4.13, -3.51,
+  -4.03, -3.21, -4.31, -4.39, -3.83, -4.00, -3.58, -4.23, -4.16, -4.17, -4.04, -4.31, -3.92, -3.73,
+  -3.97, -4.07, -4.23, -4.39, -3.29, -3.88, -4.39, -3.76, -3.24, -3.32, -3.56, -4.24, -4.14, -3.81,
+  -4.13, -3.74, -3.96, -4.28, -3.96, -4.37, -4.26, -3.75, -3.98, -4.23, -4.17, -3.14, -3.75, -4.34,
+  -4.08, -3.76, -3.80, -3.66, -3.49, -3.78, -3.99, -4.02, -4.03, -3.78, -4.37, -4.01, -4.19, -2.71,
+  -3.11, -4.39, -3.94, -3.59, -3.80, -4.39, -3.85, -3.33, -3.74, -3.37, -3.66, -3.58, -3.02, -3.95,
+  -4.24, -4.22, -4.28, -4.04, -3.64, -3.99, -3.73, -4.03, -4.14, -4.22, -4.08, -4.37, -3.45, -4.17,
+  -3.90, -3.56, -3.52, -4.09, -4.08, -3.48, -3.56, -3.92, -3.66, -3.97, -3.42, -3.28, -3.96, -3.70,
+  -4.01, -3.47, -4.08, -4.30, -3.98, -4.39, -4.06, -4.39, -4.34, -4.20, -3.29, -4.25, -4.09, -3.94,
+  -4.30, -3.55, -4.35, -3.11, -4.25, -4.17, -4.35, -4.03, -3.91, -3.79, -3.98, -4.16, -3.83, -3.96,
+  -3.86, -2.95, -3.92, -4.09, -3.50, -4.23, -3.95, -3.29, -3.48, -3.43, -4.27, -3.23, -3.86, -4.32,
+  -3.45, -4.33, -4.32, -4.07, -3.98, -4.17, -3.29, -3.44, -2.44, -1.85, -3.81, -3.61, -3.38, -2.83,
+  -3.50, -3.49, -3.36, -2.69, -3.12, -3.81, -3.57, -3.25, -3.95, -4.15, -4.13, -4.12, -4.19, -3.99,
+  -4.37, -4.08, -3.80, -3.11, -2.76, -4.23, -2.42, -3.71, -4.25, -3.78, -3.61, -4.30, -4.13, -4.07,
+  -3.17, -3.58, -3.27, -3.51, -3.69, -3.78, -3.78, -3.67, -3.54, -4.08, -4.13, -3.51, -3.86, -3.68,
+  -3.43, -3.28, -2.99, -3.40, -3.70, -4.33, -4.33, -4.39, -4.21, -4.18, -4.08, -4.31, -3.50, -4.39,
+  -4.13, -3.81, -3.93, -3.89, -3.69, -3.81, -3.17, -4.17, -4.10, -4.01, -4.27, -3.50, -4.27, -4.19,
+  -3.22, -3.75, -4.35, -3.52, -4.33, -4.08, -4.18, -4.09, -3.73, -3.59, -4.22, -4.26, -3.44, -4.16,
+  -3.94, -3.77, -3.78, -4.31, -4.27, -3.28, -4.06, -4.35, -4.05, -4.01, -3.88, -3.18, -3.11, -3.81,
+  -3.51, -4.17, -4.10, -4.39, -2.80, -3.47, -4.28, -4.02, -4.13, -4.39, -4.20, -4.04, -4.38, -3.79,
+  -3.90, -4.13, -3.69, -4.08, -3.92, -4.23, -3.99, -3.85, -4.08, -4.28, -3.63, -3.63, -3.91, -3.81,
+  -4.27, -4.02, -4.24, -3.67, -3.76, -3.78, -4.10, -3.91, -3.82, -4.37, -3.37, -4.15, -4.13, -3.72,
+  -3.72, -3.96, -3.92, -3.86, -4.35, -3.13, -4.14, -3.70, -3.69, -4.33, -4.37, -3.74, -4.38, -3.63,
+  -4.22, -3.53, -3.47, -4.39, -3.84, -2.84, -3.97, -3.89, -4.14, -3.90, -4.07, -4.03, -3.61, -4.31,
+  -4.18, -3.86, -4.37, -3.53, -4.32, -4.39, -4.25, -3.69, -4.19, -3.99, -4.35, -4.27, -3.15, -4.26,
+  -4.24, -3.45, -4.34, -4.33, -3.41, -4.01, -4.28, -3.64, -4.34, -3.69, -4.37, -3.97, -2.75, -3.57,
+  -3.85, -3.78, -3.82, -3.20, -4.05, -4.03, -2.92, -3.89, -4.21, -4.33, -4.14, -3.84, -4.31, -4.28,
+  -4.24, -3.70, -4.30, -3.81, -4.33, -3.43, -3.77, -3.46, -4.15, -4.17, -4.18, -4.21, -3.69, -3.63,
+  -4.17, -3.69, -2.76, -4.30, -3.16, -4.23, -3.40, -4.30, -4.13, -4.10, -3.98, -4.08, -4.28, -4.22,
+  -4.31, -3.96, -3.39, -4.32, -3.72, -4.03, -4.20, -4.32, -3.72, -4.34, -4.34, -4.02, -3.42, -3.75,
+  -4.28, -3.66, -4.35, -3.61, -3.90, -3.66, -3.80, -3.80, -4.17, -4.39, -4.17, -4.34, -3.52, -3.74,
+  -4.37, -4.17, -4.28, -4.39, -3.90, -3.34, -4.06, -3.58, -3.82, -3.79, -3.01, -3.50, -3.99, -3.70,
+  -4.19, -4.37, -3.89, -3.70, -3.99, -4.05, -3.77, -3.95, -3.71, -3.40, -3.19, -3.62, -3.54, -4.32,
+  -4.02, -3.81, -3.98, -4.10, -3.79, -3.39, -3.07, -3.53, -3.93, -3.14, -4.11, -4.08, -2.99, -3.61,
+  -2.78, -4.33, -3.77, -4.30, -4.21, -3.66, -3.77, -3.68, -3.31, -3.42, -4.25, -3.49, -4.20, -3.93,
+  -4.27, -4.24, -4.17, -3.32, -3.91, -4.17, -4.17, -4.38, -4.15, -4.21, -3.57, -4.13, -4.26, -4.19,
+  -4.27, -3.91, -4.39, -4.17, -4.09, -4.30, -3.91, -3.86, -3.90, -4.05, -4.26, -4.08, -4.25, -4.11,
+  -4.01, -4.18, -3.75, -4.07, -4.24, -4.33, -4.25, -3.62, -4.03, -4.23, -4.32, -4.05, -3.93, -4.12,
+  -4.31, -4.38, -4.32, -4.23, -3.34, -3.43, -4.16, -3.75, -4.22, -4.38, -4.21, -4.08, -4.17, -4.34,
+  -4.21, -4.25, -4.19, -4.13, -4.38, -3.97, -4.31, -3.80, -3.83, -3.81, -2.84, -3.41, -3.25, -3.05,
+  -4.03, -3.90, -3.37, -4.30, -3.78, -4.01, -3.90,
+];